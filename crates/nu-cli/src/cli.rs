@@ -258,6 +258,8 @@ pub fn create_default_context(
             whole_stream_command(Touch),
             whole_stream_command(Cpy),
             whole_stream_command(Date),
+            whole_stream_command(DateFormat),
+            whole_stream_command(DateHumanize),
             whole_stream_command(Cal),
             whole_stream_command(Calc),
             whole_stream_command(Mkdir),
@@ -291,6 +293,20 @@ pub fn create_default_context(
             whole_stream_command(Lines),
             whole_stream_command(Trim),
             whole_stream_command(Echo),
+            whole_stream_command(Ansi),
+            whole_stream_command(Char),
+            whole_stream_command(CharRange),
+            whole_stream_command(Base64),
+            whole_stream_command(Url),
+            whole_stream_command(UrlParse),
+            whole_stream_command(UrlJoin),
+            whole_stream_command(PathCommand),
+            whole_stream_command(PathBasename),
+            whole_stream_command(PathDirname),
+            whole_stream_command(PathExtension),
+            whole_stream_command(PathJoin),
+            whole_stream_command(PathExists),
+            whole_stream_command(PathType),
             whole_stream_command(Parse),
             whole_stream_command(Str),
             whole_stream_command(StrToDecimal),
@@ -298,12 +314,33 @@ pub fn create_default_context(
             whole_stream_command(StrDowncase),
             whole_stream_command(StrUpcase),
             whole_stream_command(StrCapitalize),
+            whole_stream_command(StrCapture),
+            whole_stream_command(StrContains),
+            whole_stream_command(StrEncode),
+            whole_stream_command(StrDecode),
             whole_stream_command(StrFindReplace),
+            whole_stream_command(StrIndexOf),
+            whole_stream_command(StrLength),
+            whole_stream_command(StrStartsWith),
+            whole_stream_command(StrEndsWith),
+            whole_stream_command(StrExpand),
+            whole_stream_command(StrCollect),
+            whole_stream_command(StrJoin),
+            whole_stream_command(StrPad),
+            whole_stream_command(StrReverse),
             whole_stream_command(StrSubstring),
+            whole_stream_command(StrTitleCase),
+            whole_stream_command(StrCamelCase),
+            whole_stream_command(StrSnakeCase),
+            whole_stream_command(StrKebabCase),
             whole_stream_command(StrSet),
             whole_stream_command(StrToDatetime),
             whole_stream_command(StrTrim),
+            whole_stream_command(StrTranslate),
             whole_stream_command(BuildString),
+            whole_stream_command(Hash),
+            whole_stream_command(HashMd5),
+            whole_stream_command(HashSha256),
             // Column manipulation
             whole_stream_command(Reject),
             whole_stream_command(Select),
@@ -321,44 +358,82 @@ pub fn create_default_context(
             whole_stream_command(First),
             whole_stream_command(Last),
             whole_stream_command(Nth),
+            whole_stream_command(Every),
             whole_stream_command(Drop),
+            whole_stream_command(DropColumn),
             whole_stream_command(Format),
+            whole_stream_command(FormatFilesize),
             whole_stream_command(Where),
             whole_stream_command(Compact),
             whole_stream_command(Default),
             whole_stream_command(Skip),
             whole_stream_command(SkipUntil),
             whole_stream_command(SkipWhile),
+            whole_stream_command(SkipWhileHyphenated),
             whole_stream_command(Keep),
             whole_stream_command(KeepUntil),
             whole_stream_command(KeepWhile),
+            whole_stream_command(TakeWhile),
             whole_stream_command(Range),
             whole_stream_command(Rename),
             whole_stream_command(Uniq),
             whole_stream_command(Each),
+            whole_stream_command(Reduce),
             whole_stream_command(IsEmpty),
             // Table manipulation
             whole_stream_command(Merge),
             whole_stream_command(Shuffle),
+            whole_stream_command(Random),
+            whole_stream_command(RandomInteger),
+            whole_stream_command(RandomDecimal),
+            whole_stream_command(RandomUUID),
+            whole_stream_command(Seq),
             whole_stream_command(Wrap),
+            whole_stream_command(Collect),
+            whole_stream_command(Flatten),
             whole_stream_command(Pivot),
+            whole_stream_command(MoveColumn),
             whole_stream_command(Headers),
+            whole_stream_command(Window),
+            whole_stream_command(Chunks),
+            whole_stream_command(Zip),
+            whole_stream_command(Roll),
+            whole_stream_command(RollDown),
+            whole_stream_command(RollUp),
+            whole_stream_command(RollLeft),
+            whole_stream_command(RollRight),
+            whole_stream_command(Rotate),
             // Data processing
             whole_stream_command(Histogram),
             whole_stream_command(Average),
             whole_stream_command(Sum),
+            whole_stream_command(Math),
+            whole_stream_command(MathAbs),
+            whole_stream_command(MathCeil),
+            whole_stream_command(MathFloor),
+            whole_stream_command(MathMedian),
+            whole_stream_command(MathMode),
+            whole_stream_command(MathPow),
+            whole_stream_command(MathProduct),
+            whole_stream_command(MathRound),
+            whole_stream_command(MathSqrt),
+            whole_stream_command(MathStddev),
+            whole_stream_command(MathVariance),
             // File format output
             whole_stream_command(To),
             whole_stream_command(ToBSON),
             whole_stream_command(ToCSV),
             whole_stream_command(ToHTML),
+            whole_stream_command(ToINI),
             whole_stream_command(ToJSON),
+            whole_stream_command(ToJSONL),
             whole_stream_command(ToSQLite),
             whole_stream_command(ToDB),
             whole_stream_command(ToMarkdown),
             whole_stream_command(ToTOML),
             whole_stream_command(ToTSV),
             whole_stream_command(ToURL),
+            whole_stream_command(ToXML),
             whole_stream_command(ToYAML),
             // File format input
             whole_stream_command(From),
@@ -369,6 +444,8 @@ pub fn create_default_context(
             whole_stream_command(FromINI),
             whole_stream_command(FromBSON),
             whole_stream_command(FromJSON),
+            whole_stream_command(FromJSONL),
+            whole_stream_command(FromNDJSON),
             whole_stream_command(FromODS),
             whole_stream_command(FromDB),
             whole_stream_command(FromSQLite),
@@ -5,17 +5,22 @@ mod from_delimited_data;
 mod to_delimited_data;
 
 pub(crate) mod alias;
+pub(crate) mod ansi;
 pub(crate) mod append;
 pub(crate) mod args;
 pub(crate) mod autoview;
 pub(crate) mod average;
+pub(crate) mod base64_;
 pub(crate) mod build_string;
 pub(crate) mod cal;
 pub(crate) mod calc;
 pub(crate) mod cd;
+pub(crate) mod char_;
+pub(crate) mod chunks;
 pub(crate) mod classified;
 #[cfg(feature = "clipboard")]
 pub(crate) mod clip;
+pub(crate) mod collect;
 pub(crate) mod command;
 pub(crate) mod compact;
 pub(crate) mod config;
@@ -31,9 +36,12 @@ pub(crate) mod echo;
 pub(crate) mod enter;
 #[allow(unused)]
 pub(crate) mod evaluate_by;
+pub(crate) mod every;
 pub(crate) mod exit;
 pub(crate) mod first;
+pub(crate) mod flatten;
 pub(crate) mod format;
+pub(crate) mod format_filesize;
 pub(crate) mod from;
 pub(crate) mod from_bson;
 pub(crate) mod from_csv;
@@ -54,6 +62,7 @@ pub(crate) mod from_yaml;
 pub(crate) mod get;
 pub(crate) mod group_by;
 pub(crate) mod group_by_date;
+pub(crate) mod hash;
 pub(crate) mod headers;
 pub(crate) mod help;
 pub(crate) mod histogram;
@@ -68,29 +77,37 @@ pub(crate) mod lines;
 pub(crate) mod ls;
 #[allow(unused)]
 pub(crate) mod map_max_by;
+pub(crate) mod math;
 pub(crate) mod merge;
 pub(crate) mod mkdir;
+pub(crate) mod move_;
 pub(crate) mod mv;
 pub(crate) mod next;
 pub(crate) mod nth;
 pub(crate) mod open;
 pub(crate) mod parse;
+pub(crate) mod path;
 pub(crate) mod pivot;
 pub(crate) mod plugin;
 pub(crate) mod prepend;
 pub(crate) mod prev;
 pub(crate) mod pwd;
+pub(crate) mod random;
 pub(crate) mod range;
+pub(crate) mod reduce;
 #[allow(unused)]
 pub(crate) mod reduce_by;
 pub(crate) mod reject;
 pub(crate) mod rename;
 pub(crate) mod reverse;
 pub(crate) mod rm;
+pub(crate) mod roll;
+pub(crate) mod rotate;
 pub(crate) mod run_alias;
 pub(crate) mod run_external;
 pub(crate) mod save;
 pub(crate) mod select;
+pub(crate) mod seq;
 pub(crate) mod shells;
 pub(crate) mod shuffle;
 pub(crate) mod size;
@@ -110,43 +127,53 @@ pub(crate) mod to;
 pub(crate) mod to_bson;
 pub(crate) mod to_csv;
 pub(crate) mod to_html;
+pub(crate) mod to_ini;
 pub(crate) mod to_json;
 pub(crate) mod to_md;
 pub(crate) mod to_sqlite;
 pub(crate) mod to_toml;
 pub(crate) mod to_tsv;
 pub(crate) mod to_url;
+pub(crate) mod to_xml;
 pub(crate) mod to_yaml;
 pub(crate) mod trim;
 pub(crate) mod uniq;
 pub(crate) mod update;
+pub(crate) mod url_;
 pub(crate) mod version;
 pub(crate) mod what;
 pub(crate) mod where_;
 pub(crate) mod which_;
+pub(crate) mod window;
 pub(crate) mod with_env;
 pub(crate) mod wrap;
+pub(crate) mod zip;
 
 pub(crate) use autoview::Autoview;
 pub(crate) use cd::Cd;
+pub(crate) use char_::{Char, CharRange};
 pub(crate) use command::{
     whole_stream_command, Command, Example, UnevaluatedCallInfo, WholeStreamCommand,
 };
 
 pub(crate) use alias::Alias;
+pub(crate) use ansi::Ansi;
 pub(crate) use append::Append;
 pub(crate) use average::Average;
+pub(crate) use base64_::Base64;
 pub(crate) use build_string::BuildString;
 pub(crate) use cal::Cal;
 pub(crate) use calc::Calc;
+pub(crate) use chunks::Chunks;
+pub(crate) use collect::Collect;
 pub(crate) use compact::Compact;
 pub(crate) use config::Config;
 pub(crate) use count::Count;
 pub(crate) use cp::Cpy;
-pub(crate) use date::Date;
+pub(crate) use date::{Date, DateFormat, DateHumanize};
 pub(crate) use debug::Debug;
 pub(crate) use default::Default;
-pub(crate) use drop::Drop;
+pub(crate) use drop::{Drop, DropColumn};
 pub(crate) use du::Du;
 pub(crate) use each::Each;
 pub(crate) use echo::Echo;
@@ -160,16 +187,19 @@ pub(crate) mod touch;
 pub(crate) use enter::Enter;
 #[allow(unused_imports)]
 pub(crate) use evaluate_by::EvaluateBy;
+pub(crate) use every::Every;
 pub(crate) use exit::Exit;
 pub(crate) use first::First;
+pub(crate) use flatten::Flatten;
 pub(crate) use format::Format;
+pub(crate) use format_filesize::FormatFilesize;
 pub(crate) use from::From;
 pub(crate) use from_bson::FromBSON;
 pub(crate) use from_csv::FromCSV;
 pub(crate) use from_eml::FromEML;
 pub(crate) use from_ics::FromIcs;
 pub(crate) use from_ini::FromINI;
-pub(crate) use from_json::FromJSON;
+pub(crate) use from_json::{FromJSON, FromJSONL, FromNDJSON};
 pub(crate) use from_ods::FromODS;
 pub(crate) use from_sqlite::FromDB;
 pub(crate) use from_sqlite::FromSQLite;
@@ -185,6 +215,7 @@ pub(crate) use from_yaml::FromYML;
 pub(crate) use get::Get;
 pub(crate) use group_by::GroupBy;
 pub(crate) use group_by_date::GroupByDate;
+pub(crate) use hash::{Hash, HashMd5, HashSha256};
 pub(crate) use headers::Headers;
 pub(crate) use help::Help;
 pub(crate) use histogram::Histogram;
@@ -192,47 +223,62 @@ pub(crate) use history::History;
 pub(crate) use insert::Insert;
 pub(crate) use keep::Keep;
 pub(crate) use keep_until::KeepUntil;
-pub(crate) use keep_while::KeepWhile;
+pub(crate) use keep_while::{KeepWhile, TakeWhile};
 pub(crate) use last::Last;
 pub(crate) use lines::Lines;
 pub(crate) use ls::Ls;
 #[allow(unused_imports)]
 pub(crate) use map_max_by::MapMaxBy;
+pub(crate) use math::{
+    Math, MathAbs, MathCeil, MathFloor, MathMedian, MathMode, MathPow, MathProduct, MathRound,
+    MathSqrt, MathStddev, MathVariance,
+};
 pub(crate) use merge::Merge;
 pub(crate) use mkdir::Mkdir;
+pub(crate) use move_::MoveColumn;
 pub(crate) use mv::Move;
 pub(crate) use next::Next;
 pub(crate) use nth::Nth;
 pub(crate) use open::Open;
 pub(crate) use parse::Parse;
+pub(crate) use path::{
+    PathBasename, PathCommand, PathDirname, PathExists, PathExtension, PathJoin, PathType,
+};
 pub(crate) use pivot::Pivot;
 pub(crate) use prepend::Prepend;
 pub(crate) use prev::Previous;
 pub(crate) use pwd::Pwd;
+pub(crate) use random::{Random, RandomDecimal, RandomInteger, RandomUUID};
 pub(crate) use range::Range;
+pub(crate) use reduce::Reduce;
 #[allow(unused_imports)]
 pub(crate) use reduce_by::ReduceBy;
 pub(crate) use reject::Reject;
 pub(crate) use rename::Rename;
 pub(crate) use reverse::Reverse;
 pub(crate) use rm::Remove;
+pub(crate) use roll::{Roll, RollDown, RollLeft, RollRight, RollUp};
+pub(crate) use rotate::Rotate;
 pub(crate) use run_external::RunExternalCommand;
 pub(crate) use save::Save;
 pub(crate) use select::Select;
+pub(crate) use seq::Seq;
 pub(crate) use shells::Shells;
 pub(crate) use shuffle::Shuffle;
 pub(crate) use size::Size;
 pub(crate) use skip::Skip;
 pub(crate) use skip_until::SkipUntil;
-pub(crate) use skip_while::SkipWhile;
+pub(crate) use skip_while::{SkipWhile, SkipWhileHyphenated};
 pub(crate) use sort_by::SortBy;
 pub(crate) use split::Split;
 pub(crate) use split::SplitColumn;
 pub(crate) use split::SplitRow;
 pub(crate) use split_by::SplitBy;
 pub(crate) use str_::{
-    Str, StrCapitalize, StrDowncase, StrFindReplace, StrSet, StrSubstring, StrToDatetime,
-    StrToDecimal, StrToInteger, StrTrim, StrUpcase,
+    Str, StrCamelCase, StrCapitalize, StrCapture, StrCollect, StrContains, StrDecode, StrDowncase,
+    StrEncode, StrEndsWith, StrExpand, StrFindReplace, StrIndexOf, StrJoin, StrKebabCase,
+    StrLength, StrPad, StrReverse, StrSet, StrSnakeCase, StrStartsWith, StrSubstring, StrTitleCase,
+    StrToDatetime, StrToDecimal, StrToInteger, StrTranslate, StrTrim, StrUpcase,
 };
 pub(crate) use sum::Sum;
 #[allow(unused_imports)]
@@ -243,20 +289,25 @@ pub(crate) use to::To;
 pub(crate) use to_bson::ToBSON;
 pub(crate) use to_csv::ToCSV;
 pub(crate) use to_html::ToHTML;
-pub(crate) use to_json::ToJSON;
+pub(crate) use to_ini::ToINI;
+pub(crate) use to_json::{ToJSON, ToJSONL};
 pub(crate) use to_md::ToMarkdown;
 pub(crate) use to_sqlite::ToDB;
 pub(crate) use to_sqlite::ToSQLite;
 pub(crate) use to_toml::ToTOML;
 pub(crate) use to_tsv::ToTSV;
 pub(crate) use to_url::ToURL;
+pub(crate) use to_xml::ToXML;
 pub(crate) use to_yaml::ToYAML;
 pub(crate) use touch::Touch;
 pub(crate) use trim::Trim;
 pub(crate) use uniq::Uniq;
+pub(crate) use url_::{Url, UrlJoin, UrlParse};
 pub(crate) use version::Version;
 pub(crate) use what::What;
 pub(crate) use where_::Where;
 pub(crate) use which_::Which;
+pub(crate) use window::Window;
 pub(crate) use with_env::WithEnv;
 pub(crate) use wrap::Wrap;
+pub(crate) use zip::Zip;
@@ -0,0 +1,159 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+
+/// The names `ansi` knows about, along with the escape sequence each one emits
+const CODES: &[(&str, &str)] = &[
+    ("reset", "\x1b[0m"),
+    ("bold", "\x1b[1m"),
+    ("dimmed", "\x1b[2m"),
+    ("italic", "\x1b[3m"),
+    ("underline", "\x1b[4m"),
+    ("blink", "\x1b[5m"),
+    ("reverse", "\x1b[7m"),
+    ("hidden", "\x1b[8m"),
+    ("strike", "\x1b[9m"),
+    ("black", "\x1b[30m"),
+    ("red", "\x1b[31m"),
+    ("green", "\x1b[32m"),
+    ("yellow", "\x1b[33m"),
+    ("blue", "\x1b[34m"),
+    ("purple", "\x1b[35m"),
+    ("cyan", "\x1b[36m"),
+    ("white", "\x1b[37m"),
+    ("light_black", "\x1b[90m"),
+    ("light_red", "\x1b[91m"),
+    ("light_green", "\x1b[92m"),
+    ("light_yellow", "\x1b[93m"),
+    ("light_blue", "\x1b[94m"),
+    ("light_purple", "\x1b[95m"),
+    ("light_cyan", "\x1b[96m"),
+    ("light_white", "\x1b[97m"),
+];
+
+pub struct Ansi;
+
+#[derive(Deserialize)]
+struct AnsiArgs {
+    code: Option<Tagged<String>>,
+    list: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Ansi {
+    fn name(&self) -> &str {
+        "ansi"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ansi")
+            .optional(
+                "code",
+                SyntaxShape::String,
+                "the name of the color or style, eg) red, bold or reset",
+            )
+            .switch("list", "list the names of all the known codes", Some('l'))
+    }
+
+    fn usage(&self) -> &str {
+        "Output ANSI escape codes to color and style text"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        ansi(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the escape code to turn text red",
+                example: "ansi red",
+                result: Some(vec![Value::from("\x1b[31m")]),
+            },
+            Example {
+                description: "Get the escape code to reset the color and style",
+                example: "ansi reset",
+                result: Some(vec![Value::from("\x1b[0m")]),
+            },
+            Example {
+                description: "List the names of all the known codes",
+                example: "ansi --list",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn ansi(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (AnsiArgs { code, list }, _) = args.process(&registry).await?;
+
+    if list {
+        let rows: Vec<_> = CODES
+            .iter()
+            .map(|(code_name, code)| {
+                let mut row = TaggedDictBuilder::new(&name);
+                row.insert_untagged("name", UntaggedValue::string(*code_name));
+                row.insert_untagged("code", UntaggedValue::string(code.replace('\x1b', "\\e")));
+                ReturnSuccess::value(row.into_value())
+            })
+            .collect();
+
+        return Ok(futures::stream::iter(rows).to_output_stream());
+    }
+
+    let code = code.ok_or_else(|| {
+        ShellError::labeled_error(
+            "Expected the name of a code",
+            "requires a name like red, bold or reset",
+            &name,
+        )
+    })?;
+
+    match lookup(&code.item) {
+        Some(escape) => Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(escape).into_value(&name),
+        ))),
+        None => Err(ShellError::labeled_error(
+            "Unknown ansi code",
+            "see ansi --list for the known names",
+            code.tag,
+        )),
+    }
+}
+
+fn lookup(code_name: &str) -> Option<&'static str> {
+    CODES
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(code_name))
+        .map(|(_, escape)| *escape)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lookup, Ansi};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Ansi {})
+    }
+
+    #[test]
+    fn looks_up_codes_ignoring_case() {
+        assert_eq!(lookup("Bold"), Some("\x1b[1m"));
+    }
+
+    #[test]
+    fn does_not_know_made_up_codes() {
+        assert_eq!(lookup("ultraviolet"), None);
+    }
+}
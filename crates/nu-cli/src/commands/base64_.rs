@@ -0,0 +1,255 @@
+use crate::commands::str_::Encoding;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+pub struct Base64;
+
+#[derive(Deserialize)]
+pub struct Base64Args {
+    rest: Vec<ColumnPath>,
+    encode: bool,
+    decode: bool,
+    #[serde(rename(deserialize = "url-safe"))]
+    url_safe: bool,
+    #[serde(rename(deserialize = "to-string"))]
+    to_string: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Encode,
+    Decode { to_string: bool },
+}
+
+#[async_trait]
+impl WholeStreamCommand for Base64 {
+    fn name(&self) -> &str {
+        "base64"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("base64")
+            .switch("encode", "encode text or binary as base64", Some('e'))
+            .switch("decode", "decode base64 into binary", Some('d'))
+            .switch(
+                "url-safe",
+                "use the URL-safe alphabet, with - and _ instead of + and /",
+                Some('u'),
+            )
+            .switch(
+                "to-string",
+                "decode into UTF-8 text instead of binary",
+                Some('s'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally encode or decode by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Encodes or decodes base64."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        base64(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Encode text as base64",
+                example: "echo 'hello' | base64 --encode",
+                result: Some(vec![Value::from("aGVsbG8=")]),
+            },
+            Example {
+                description: "Decode base64 into binary",
+                example: "echo 'aGVsbG8=' | base64 --decode",
+                result: Some(vec![UntaggedValue::Primitive(Primitive::Binary(
+                    b"hello".to_vec(),
+                ))
+                .into()]),
+            },
+            Example {
+                description: "Decode base64 into text",
+                example: "echo 'aGVsbG8=' | base64 --decode --to-string",
+                result: Some(vec![Value::from("hello")]),
+            },
+            Example {
+                description: "Encode with the URL-safe alphabet",
+                example: "echo '???' | base64 --encode --url-safe",
+                result: Some(vec![Value::from("Pz8_")]),
+            },
+        ]
+    }
+}
+
+fn base64(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+
+    let stream = async_stream! {
+        let (Base64Args { rest, encode, decode, url_safe, to_string }, mut input) = args.process(&registry).await?;
+
+        let direction = match (encode, decode) {
+            (true, false) if to_string => {
+                yield Err(ShellError::labeled_error(
+                    "Can only convert to text when decoding",
+                    "remove --to-string or use --decode",
+                    name,
+                ));
+                return;
+            }
+            (true, false) => Direction::Encode,
+            (false, true) => Direction::Decode { to_string },
+            _ => {
+                yield Err(ShellError::labeled_error(
+                    "Needs one of --encode or --decode",
+                    "use either --encode or --decode",
+                    name,
+                ));
+                return;
+            }
+        };
+
+        let encoding = Encoding::Base64(if url_safe {
+            ::base64::URL_SAFE
+        } else {
+            ::base64::STANDARD
+        });
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match action(&v, direction, encoding, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, direction, encoding, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn action(
+    input: &Value,
+    direction: Direction,
+    encoding: Encoding,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
+    match direction {
+        Direction::Encode => encoding.encode(input, tag),
+        Direction::Decode { to_string } => {
+            let decoded = encoding.decode_bytes(input, &tag)?;
+
+            if to_string {
+                let text = String::from_utf8(decoded).map_err(|_| {
+                    ShellError::labeled_error(
+                        "Could not decode base64 into text",
+                        "the decoded data isn't valid UTF-8",
+                        tag.span,
+                    )
+                })?;
+
+                Ok(UntaggedValue::string(text).into_value(tag))
+            } else {
+                Ok(UntaggedValue::Primitive(Primitive::Binary(decoded)).into_value(tag))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, Base64, Direction};
+    use crate::commands::str_::Encoding;
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::{Primitive, UntaggedValue};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Base64 {})
+    }
+
+    #[test]
+    fn encodes_binary() {
+        let bytes =
+            UntaggedValue::Primitive(Primitive::Binary(vec![0xFB, 0xFF])).into_untagged_value();
+
+        let standard = Encoding::Base64(::base64::STANDARD);
+        let url_safe = Encoding::Base64(::base64::URL_SAFE);
+
+        assert_eq!(
+            action(&bytes, Direction::Encode, standard, Tag::unknown()).unwrap(),
+            string("+/8=")
+        );
+        assert_eq!(
+            action(&bytes, Direction::Encode, url_safe, Tag::unknown()).unwrap(),
+            string("-_8=")
+        );
+    }
+
+    #[test]
+    fn decodes_into_binary_ignoring_surrounding_whitespace() {
+        let direction = Direction::Decode { to_string: false };
+        let standard = Encoding::Base64(::base64::STANDARD);
+
+        assert_eq!(
+            action(&string(" +/8=\n"), direction, standard, Tag::unknown()).unwrap(),
+            UntaggedValue::Primitive(Primitive::Binary(vec![0xFB, 0xFF])).into_untagged_value()
+        );
+    }
+
+    #[test]
+    fn errors_decoding_invalid_base64() {
+        let direction = Direction::Decode { to_string: false };
+        let standard = Encoding::Base64(::base64::STANDARD);
+
+        assert!(action(&string("aGVsbG8=="), direction, standard, Tag::unknown()).is_err());
+        assert!(action(&string("a!b="), direction, standard, Tag::unknown()).is_err());
+    }
+
+    #[test]
+    fn errors_decoding_invalid_text() {
+        let direction = Direction::Decode { to_string: true };
+        let standard = Encoding::Base64(::base64::STANDARD);
+
+        assert!(action(&string("+/8="), direction, standard, Tag::unknown()).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+
+/// The names `char` knows about, along with the character each one outputs
+const CHARACTERS: &[(&str, &str)] = &[
+    ("newline", "\n"),
+    ("carriage_return", "\r"),
+    ("tab", "\t"),
+    ("space", " "),
+    ("null", "\0"),
+    ("escape", "\x1b"),
+    ("pipe", "|"),
+    ("left_brace", "{"),
+    ("right_brace", "}"),
+    ("left_bracket", "["),
+    ("right_bracket", "]"),
+    ("double_quote", "\""),
+    ("single_quote", "'"),
+    ("backtick", "`"),
+    ("horizontal_line", "─"),
+    ("vertical_line", "│"),
+    ("top_left_corner", "┌"),
+    ("top_right_corner", "┐"),
+    ("bottom_left_corner", "└"),
+    ("bottom_right_corner", "┘"),
+    ("cross", "┼"),
+    ("left_arrow", "←"),
+    ("right_arrow", "→"),
+    ("up_arrow", "↑"),
+    ("down_arrow", "↓"),
+    ("bullet", "•"),
+];
+
+pub struct Command;
+
+#[derive(Deserialize)]
+struct CharArgs {
+    name: Option<Tagged<String>>,
+    list: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Command {
+    fn name(&self) -> &str {
+        "char"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("char")
+            .optional(
+                "name",
+                SyntaxShape::String,
+                "the name of the character, eg) newline, tab or right_arrow",
+            )
+            .switch(
+                "list",
+                "list the names of all the known characters",
+                Some('l'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Output special characters by name"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        output_char(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Output a newline",
+                example: "char newline",
+                result: Some(vec![Value::from("\n")]),
+            },
+            Example {
+                description: "Output a tab",
+                example: "char tab",
+                result: Some(vec![Value::from("\t")]),
+            },
+            Example {
+                description: "List the names of all the known characters",
+                example: "char --list",
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn output_char(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let tag = args.call_info.name_tag.clone();
+    let (CharArgs { name, list }, _) = args.process(&registry).await?;
+
+    if list {
+        let rows: Vec<_> = CHARACTERS
+            .iter()
+            .map(|(name, character)| {
+                let mut row = TaggedDictBuilder::new(&tag);
+                row.insert_untagged("name", UntaggedValue::string(*name));
+                row.insert_untagged("character", UntaggedValue::string(*character));
+                ReturnSuccess::value(row.into_value())
+            })
+            .collect();
+
+        return Ok(futures::stream::iter(rows).to_output_stream());
+    }
+
+    let name = name.ok_or_else(|| {
+        ShellError::labeled_error(
+            "Expected the name of a character",
+            "requires a name like newline, tab or space",
+            &tag,
+        )
+    })?;
+
+    match lookup(&name.item) {
+        Some(character) => Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(character).into_value(&tag),
+        ))),
+        None => {
+            let known: Vec<_> = CHARACTERS.iter().map(|(name, _)| *name).collect();
+
+            Err(ShellError::labeled_error(
+                "Unknown character name",
+                format!("expected one of {}", known.join(", ")),
+                name.tag,
+            ))
+        }
+    }
+}
+
+fn lookup(name: &str) -> Option<&'static str> {
+    CHARACTERS
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, character)| *character)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lookup, Command};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Command {})
+    }
+
+    #[test]
+    fn looks_up_box_drawing_characters() {
+        assert_eq!(lookup("horizontal_line"), Some("─"));
+    }
+
+    #[test]
+    fn does_not_know_made_up_names() {
+        assert_eq!(lookup("snowman"), None);
+    }
+}
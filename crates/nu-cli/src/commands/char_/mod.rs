@@ -0,0 +1,5 @@
+mod command;
+mod range;
+
+pub use command::Command as Char;
+pub use range::SubCommand as CharRange;
@@ -0,0 +1,144 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use std::convert::TryFrom;
+
+#[derive(Deserialize)]
+struct Arguments {
+    start: Tagged<String>,
+    end: Tagged<String>,
+    step: Option<Tagged<u64>>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "char range"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("char range")
+            .required("start", SyntaxShape::String, "the first character")
+            .required("end", SyntaxShape::String, "the last character (included)")
+            .named(
+                "step",
+                SyntaxShape::Int,
+                "the number of code points between the characters (default: 1)",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Generates the characters from start to end by walking their Unicode code points."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let tag = args.call_info.name_tag.clone();
+        let (Arguments { start, end, step }, _) = args.process(&registry).await?;
+
+        let step = match step {
+            Some(step) if step.item == 0 => {
+                return Err(ShellError::labeled_error(
+                    "Step can not be zero",
+                    "step must not be zero",
+                    step.tag,
+                ));
+            }
+            Some(step) => u32::try_from(step.item).unwrap_or(u32::MAX),
+            None => 1,
+        };
+
+        let characters = code_points(single_char(&start)?, single_char(&end)?, step)
+            .map(move |c| UntaggedValue::string(c.to_string()).into_value(&tag));
+
+        Ok(futures::stream::iter(characters).to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate the letters from a to e",
+                example: "char range a e",
+                result: Some(vec![
+                    Value::from("a"),
+                    Value::from("b"),
+                    Value::from("c"),
+                    Value::from("d"),
+                    Value::from("e"),
+                ]),
+            },
+            Example {
+                description: "Generate every other letter from e down to a",
+                example: "char range e a --step 2",
+                result: Some(vec![Value::from("e"), Value::from("c"), Value::from("a")]),
+            },
+        ]
+    }
+}
+
+fn single_char(value: &Tagged<String>) -> Result<char, ShellError> {
+    let mut chars = value.item.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(ShellError::labeled_error(
+            "Expected a single character",
+            "must be exactly one character",
+            &value.tag,
+        )),
+    }
+}
+
+/// Lazily walks the code points from start to end, counting down if start comes after end.
+/// Code points that aren't characters (the surrogates) are skipped.
+fn code_points(start: char, end: char, step: u32) -> impl Iterator<Item = char> {
+    let (start, end) = (start as u32, end as u32);
+    let descending = start > end;
+    let distance = if descending { start - end } else { end - start };
+    let steps = distance / step;
+
+    (0..=steps)
+        .map(move |i| {
+            if descending {
+                start - i * step
+            } else {
+                start + i * step
+            }
+        })
+        .filter_map(std::char::from_u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{code_points, SubCommand};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn walks_non_ascii_code_points() {
+        let greek: String = code_points('α', 'ε', 1).collect();
+
+        assert_eq!(greek, "αβγδε");
+    }
+
+    #[test]
+    fn skips_the_surrogates() {
+        let around: Vec<char> = code_points('\u{d7ff}', '\u{e000}', 1).collect();
+
+        assert_eq!(around, vec!['\u{d7ff}', '\u{e000}']);
+    }
+}
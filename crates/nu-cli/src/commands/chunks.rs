@@ -0,0 +1,103 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct ChunksArgs {
+    size: Tagged<u64>,
+}
+
+pub struct Chunks;
+
+#[async_trait]
+impl WholeStreamCommand for Chunks {
+    fn name(&self) -> &str {
+        "chunks"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chunks").required(
+            "size",
+            SyntaxShape::Int,
+            "the number of rows in each chunk",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Splits the input into tables of a fixed number of rows, the last one may be shorter"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        chunks(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Split the input into chunks of two rows",
+            example: "echo [1 2 3 4 5] | chunks 2",
+            result: Some(vec![
+                UntaggedValue::table(&[UntaggedValue::int(1).into(), UntaggedValue::int(2).into()])
+                    .into(),
+                UntaggedValue::table(&[UntaggedValue::int(3).into(), UntaggedValue::int(4).into()])
+                    .into(),
+                UntaggedValue::table(&[UntaggedValue::int(5).into()]).into(),
+            ]),
+        }]
+    }
+}
+
+fn chunks(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+
+    let stream = async_stream! {
+        let (ChunksArgs { size }, mut input) = args.process(&registry).await?;
+
+        if size.item == 0 {
+            yield Err(ShellError::labeled_error(
+                "Chunk size must be positive",
+                "expected a size greater than zero",
+                size.tag,
+            ));
+            return;
+        }
+
+        let size = size.item as usize;
+
+        let mut chunk: Vec<Value> = vec![];
+
+        while let Some(item) = input.next().await {
+            chunk.push(item);
+
+            if chunk.len() == size {
+                let rows = std::mem::take(&mut chunk);
+                yield ReturnSuccess::value(UntaggedValue::table(&rows).into_value(&name));
+            }
+        }
+
+        if !chunk.is_empty() {
+            yield ReturnSuccess::value(UntaggedValue::table(&chunk).into_value(&name));
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Chunks;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Chunks {})
+    }
+}
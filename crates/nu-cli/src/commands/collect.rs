@@ -0,0 +1,105 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use indexmap::{indexmap, IndexMap};
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct Collect;
+
+#[derive(Deserialize)]
+struct CollectArgs {
+    into: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Collect {
+    fn name(&self) -> &str {
+        "collect"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("collect").named(
+            "into",
+            SyntaxShape::String,
+            "put the list in a row under this column",
+            Some('i'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Collects all the rows of the input into a single list value."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        collect(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Collect the values into one list",
+                example: "echo [1 2 3] | collect",
+                result: Some(vec![UntaggedValue::Table(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                ])
+                .into()]),
+            },
+            Example {
+                description: "Collect the values into a list under a column",
+                example: "echo [1 2 3] | collect --into numbers",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "numbers".to_string() => UntaggedValue::Table(vec![
+                        UntaggedValue::int(1).into(),
+                        UntaggedValue::int(2).into(),
+                        UntaggedValue::int(3).into(),
+                    ])
+                    .into(),
+                })
+                .into()]),
+            },
+        ]
+    }
+}
+
+async fn collect(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let tag = args.call_info.name_tag.clone();
+    let (CollectArgs { into }, input) = args.process(&registry).await?;
+    let rows: Vec<Value> = input.collect().await;
+
+    let list = UntaggedValue::Table(rows).into_value(&tag);
+
+    let value = match into {
+        Some(column) => {
+            let mut row = IndexMap::new();
+            row.insert(column.item, list);
+            UntaggedValue::row(row).into_value(&tag)
+        }
+        None => list,
+    };
+
+    Ok(OutputStream::one(ReturnSuccess::value(value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Collect;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Collect {})
+    }
+}
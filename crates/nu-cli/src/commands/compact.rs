@@ -4,7 +4,7 @@ use crate::prelude::*;
 use futures::future;
 use futures::stream::StreamExt;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
 
 pub struct Compact;
@@ -12,6 +12,7 @@ pub struct Compact;
 #[derive(Deserialize)]
 pub struct CompactArgs {
     rest: Vec<Tagged<String>>,
+    empty: bool,
 }
 
 #[async_trait]
@@ -21,11 +22,17 @@ impl WholeStreamCommand for Compact {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("compact").rest(SyntaxShape::Any, "the columns to compact from the table")
+        Signature::build("compact")
+            .rest(SyntaxShape::Any, "the columns to compact from the table")
+            .switch(
+                "empty",
+                "also treat empty strings as missing values",
+                Some('e'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Creates a table with non-empty rows"
+        "Creates a table without the rows missing a value in the given columns"
     }
 
     async fn run(
@@ -52,6 +59,11 @@ impl WholeStreamCommand for Compact {
                 example: "ls -af | compact target",
                 result: None,
             },
+            Example {
+                description: "Filter out null entries and empty strings",
+                example: "echo [a '' b $null] | compact --empty",
+                result: Some(vec![Value::from("a"), Value::from("b")]),
+            },
         ]
     }
 }
@@ -61,11 +73,17 @@ pub async fn compact(
     registry: &CommandRegistry,
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
-    let (CompactArgs { rest: columns }, input) = args.process(&registry).await?;
+    let (
+        CompactArgs {
+            rest: columns,
+            empty,
+        },
+        input,
+    ) = args.process(&registry).await?;
     Ok(input
         .filter_map(move |item| {
             future::ready(if columns.is_empty() {
-                if !item.is_empty() {
+                if !is_missing(&item, empty) {
                     Some(ReturnSuccess::value(item))
                 } else {
                     None
@@ -78,7 +96,7 @@ pub async fn compact(
                     } => {
                         if columns
                             .iter()
-                            .all(|field| r.get_data(field).borrow().is_some())
+                            .all(|field| !is_missing(r.get_data(field).borrow(), empty))
                         {
                             Some(ReturnSuccess::value(item))
                         } else {
@@ -92,6 +110,18 @@ pub async fn compact(
         .to_output_stream())
 }
 
+/// Only nothing counts as missing unless empty strings are asked for, a row or a table is missing when all of its values are
+fn is_missing(value: &Value, empty: bool) -> bool {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Nothing) => true,
+        UntaggedValue::Primitive(Primitive::String(s))
+        | UntaggedValue::Primitive(Primitive::Line(s)) => empty && s.is_empty(),
+        UntaggedValue::Row(row) => row.entries.values().all(|value| is_missing(value, empty)),
+        UntaggedValue::Table(values) => values.iter().all(|value| is_missing(value, empty)),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Compact;
@@ -0,0 +1,159 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::format::{Item, StrftimeItems};
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    format: Tagged<String>,
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "date format"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date format")
+            .required(
+                "format",
+                SyntaxShape::String,
+                "the strftime format, eg. \"%Y-%m-%d %H:%M:%S\"",
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally format dates by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "format a date using a strftime format string (dates are rendered in UTC)"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Format a date",
+            example: "echo '2020-01-01T00:00:00+00:00' | str to-datetime | date format '%Y/%m/%d'",
+            result: None,
+        }]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { format, rest }, mut input) = args.process(&registry).await?;
+
+        if StrftimeItems::new(&format.item).any(|item| item == Item::Error) {
+            yield Err(ShellError::labeled_error(
+                "invalid date format",
+                "not a valid strftime format",
+                format.tag.span,
+            ));
+            return;
+        }
+
+        let format = format.item;
+        let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
+
+        while let Some(v) = input.next().await {
+            if column_paths.is_empty() {
+                match action(&v, &format, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+
+                let mut ret = v.clone();
+
+                for path in &column_paths {
+                    let format = format.clone();
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, &format, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn action(input: &Value, format: &str, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Date(dt)) => {
+            Ok(UntaggedValue::string(dt.format(format).to_string()).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not a date",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use chrono::{TimeZone, Utc};
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn formats_a_date() {
+        let date = UntaggedValue::date(Utc.ymd(2020, 1, 2).and_hms(3, 4, 5)).into_untagged_value();
+
+        let actual = action(&date, "%Y/%m/%d", Tag::unknown()).unwrap();
+        assert_eq!(actual, string("2020/01/02"));
+    }
+
+    #[test]
+    fn errors_given_a_value_that_is_not_a_date() {
+        let word = string("2020/01/02");
+
+        assert!(action(&word, "%Y/%m/%d", Tag::unknown()).is_err());
+    }
+}
@@ -0,0 +1,243 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use chrono::{DateTime, Duration, Utc};
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    reference: Option<Value>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "date humanize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date humanize")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally humanize dates by column paths",
+            )
+            .named(
+                "reference",
+                SyntaxShape::Any,
+                "the date to compare against instead of now (a date or RFC 3339 text)",
+                Some('r'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "describe how long ago or how far ahead a date is, eg. \"3 hours ago\" or \"in 2 days\""
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Describe a date relative to another one",
+            example: "echo '2020-01-01T00:00:00+00:00' | str to-datetime | date humanize --reference '2020-01-01T01:00:00+00:00'",
+            result: None,
+        }]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest, reference }, mut input) = args.process(&registry).await?;
+
+        let reference = match reference {
+            Some(value) => match to_date(&value) {
+                Ok(date) => date,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            },
+            None => Utc::now(),
+        };
+
+        let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
+
+        while let Some(v) = input.next().await {
+            if column_paths.is_empty() {
+                match action(&v, reference, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+
+                let mut ret = v.clone();
+
+                for path in &column_paths {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, reference, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn to_date(value: &Value) -> Result<DateTime<Utc>, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Date(dt)) => Ok(*dt),
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => DateTime::parse_from_rfc3339(s)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|_| {
+                ShellError::labeled_error(
+                    format!("Could not parse '{}' as datetime", s),
+                    "expected an RFC 3339 datetime",
+                    value.tag.span,
+                )
+            }),
+        other => Err(ShellError::labeled_error(
+            "reference is not a date",
+            format!("got {}", other.type_name()),
+            value.tag.span,
+        )),
+    }
+}
+
+fn action(
+    input: &Value,
+    reference: DateTime<Utc>,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Date(dt)) => Ok(UntaggedValue::string(humanize(
+            reference.signed_duration_since(*dt),
+        ))
+        .into_value(tag)),
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not a date",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+/// Phrases the elapsed time in the largest unit that fits at least once, positive means the past
+fn humanize(elapsed: Duration) -> String {
+    const UNITS: [(&str, i64); 6] = [
+        ("year", 365 * 24 * 60 * 60),
+        ("month", 30 * 24 * 60 * 60),
+        ("day", 24 * 60 * 60),
+        ("hour", 60 * 60),
+        ("minute", 60),
+        ("second", 1),
+    ];
+
+    let seconds = elapsed.num_seconds();
+
+    if seconds == 0 {
+        return String::from("just now");
+    }
+
+    let magnitude = seconds.abs();
+    let (unit, size) = UNITS
+        .iter()
+        .find(|(_, size)| magnitude >= *size)
+        .copied()
+        .unwrap_or(("second", 1));
+
+    let count = magnitude / size;
+    let amount = if count == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", count, unit)
+    };
+
+    if seconds > 0 {
+        format!("{} ago", amount)
+    } else {
+        format!("in {}", amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, humanize, SubCommand};
+    use chrono::{Duration, TimeZone, Utc};
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn describes_a_date_an_hour_before_the_reference() {
+        let reference = Utc.ymd(2020, 1, 1).and_hms(13, 0, 0);
+        let date = UntaggedValue::date(Utc.ymd(2020, 1, 1).and_hms(12, 0, 0)).into_untagged_value();
+
+        let actual = action(&date, reference, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("1 hour ago"));
+    }
+
+    #[test]
+    fn describes_dates_in_the_future() {
+        assert_eq!(humanize(Duration::days(-2)), "in 2 days");
+    }
+
+    #[test]
+    fn picks_the_largest_unit_that_fits() {
+        assert_eq!(humanize(Duration::minutes(150)), "2 hours ago");
+        assert_eq!(humanize(Duration::days(400)), "1 year ago");
+        assert_eq!(humanize(Duration::seconds(45)), "45 seconds ago");
+    }
+
+    #[test]
+    fn describes_sub_second_differences_as_just_now() {
+        assert_eq!(humanize(Duration::milliseconds(999)), "just now");
+        assert_eq!(humanize(Duration::milliseconds(-999)), "just now");
+    }
+
+    #[test]
+    fn errors_given_a_value_that_is_not_a_date() {
+        let word = string("yesterday");
+
+        assert!(action(&word, Utc::now(), Tag::unknown()).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod command;
+pub mod format;
+pub mod humanize;
+
+pub use command::Date;
+pub use format::SubCommand as DateFormat;
+pub use humanize::SubCommand as DateHumanize;
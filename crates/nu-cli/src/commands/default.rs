@@ -10,6 +10,8 @@ use nu_value_ext::ValueExt;
 struct DefaultArgs {
     column: Tagged<String>,
     value: Value,
+    #[serde(rename(deserialize = "only-null"))]
+    only_null: bool,
 }
 
 pub struct Default;
@@ -28,10 +30,15 @@ impl WholeStreamCommand for Default {
                 SyntaxShape::Any,
                 "the value of the column to default",
             )
+            .switch(
+                "only-null",
+                "only fill in columns that are present but null, not missing ones",
+                Some('n'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Sets a default row's column if missing."
+        "Sets a default row's column if missing or null."
     }
 
     async fn run(
@@ -43,11 +50,19 @@ impl WholeStreamCommand for Default {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Give a default 'target' to all file entries",
-            example: "ls -af | default target 'nothing'",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Give a default 'target' to all file entries",
+                example: "ls -af | default target 'nothing'",
+                result: None,
+            },
+            Example {
+                description:
+                    "Replace only the null 'target' of file entries, leaving rows without one alone",
+                example: "ls -af | default target 'nothing' --only-null",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -56,11 +71,25 @@ async fn default(
     registry: &CommandRegistry,
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
-    let (DefaultArgs { column, value }, input) = args.process(&registry).await?;
+    let (
+        DefaultArgs {
+            column,
+            value,
+            only_null,
+        },
+        input,
+    ) = args.process(&registry).await?;
 
     Ok(input
         .map(move |item| {
             let should_add = match item {
+                Value {
+                    value: UntaggedValue::Row(ref r),
+                    ..
+                } if only_null => match r.entries.get(&column.item) {
+                    Some(existing) => existing.value.is_none(),
+                    None => false,
+                },
                 Value {
                     value: UntaggedValue::Row(ref r),
                     ..
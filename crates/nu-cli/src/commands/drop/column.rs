@@ -0,0 +1,105 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct Arguments {
+    columns: Option<Tagged<u64>>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "drop column"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("drop column").optional(
+            "columns",
+            SyntaxShape::Int,
+            "starting from the end, the number of columns to remove",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Remove the last number of columns. If you want to remove columns by name, try 'reject'."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+
+        let (Arguments { columns }, input) = args.process(&registry).await?;
+        let columns_to_drop = columns.map(|columns| columns.item as usize).unwrap_or(1);
+
+        Ok(input
+            .map(move |item| ReturnSuccess::value(drop_columns(item, columns_to_drop)))
+            .to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Remove the last column of a table",
+            example: r#"echo '{"a": 1, "b": 2, "c": 3}' | from json | drop column"#,
+            result: None,
+        }]
+    }
+}
+
+fn drop_columns(value: Value, columns_to_drop: usize) -> Value {
+    match &value.value {
+        UntaggedValue::Row(dict) => {
+            let keep = dict.entries.len().saturating_sub(columns_to_drop);
+            let mut out = TaggedDictBuilder::new(&value.tag);
+
+            for (column, cell) in dict.entries.iter().take(keep) {
+                out.insert_value(column, cell.clone());
+            }
+
+            out.into_value()
+        }
+        _ => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{drop_columns, SubCommand};
+    use indexmap::indexmap;
+    use nu_plugin::test_helpers::value::int;
+    use nu_protocol::UntaggedValue;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn removes_trailing_columns() {
+        let row = UntaggedValue::row(
+            indexmap! {"a".into() => int(1), "b".into() => int(2), "c".into() => int(3)},
+        )
+        .into_untagged_value();
+
+        assert_eq!(
+            drop_columns(row, 2).data_descriptors(),
+            vec!["a".to_string()]
+        );
+    }
+
+    #[test]
+    fn removes_every_column_when_dropping_more_than_there_are() {
+        let row = UntaggedValue::row(indexmap! {"a".into() => int(1)}).into_untagged_value();
+
+        assert!(drop_columns(row, 3).data_descriptors().is_empty());
+    }
+}
@@ -2,18 +2,19 @@ use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape, UntaggedValue};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
 use nu_source::Tagged;
+use std::collections::VecDeque;
 
-pub struct Drop;
+pub struct Command;
 
 #[derive(Deserialize)]
-pub struct DropArgs {
+pub struct Arguments {
     rows: Option<Tagged<u64>>,
 }
 
 #[async_trait]
-impl WholeStreamCommand for Drop {
+impl WholeStreamCommand for Command {
     fn name(&self) -> &str {
         "drop"
     }
@@ -35,20 +36,7 @@ impl WholeStreamCommand for Drop {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        let (DropArgs { rows }, input) = args.process(&registry).await?;
-        let mut v: Vec<_> = input.into_vec().await;
-
-        let rows_to_drop = if let Some(quantity) = rows {
-            *quantity as usize
-        } else {
-            1
-        };
-
-        for _ in 0..rows_to_drop {
-            v.pop();
-        }
-
-        Ok(futures::stream::iter(v).to_output_stream())
+        drop(args, registry)
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -66,18 +54,52 @@ impl WholeStreamCommand for Drop {
                 example: "echo [1 2 3] | drop 2",
                 result: Some(vec![UntaggedValue::int(1).into()]),
             },
+            Example {
+                description: "Remove more items than the list/table has",
+                example: "echo [1 2 3] | drop 5",
+                result: Some(vec![]),
+            },
         ]
     }
 }
 
+fn drop(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let stream = async_stream! {
+        let (Arguments { rows }, mut input) = args.process(&registry).await?;
+
+        let rows_to_drop = if let Some(quantity) = rows {
+            *quantity as usize
+        } else {
+            1
+        };
+
+        // Only the last `rows_to_drop` rows are held back, everything before them
+        // is passed along as soon as it is known not to be part of the tail.
+        let mut tail = VecDeque::new();
+
+        while let Some(item) = input.next().await {
+            tail.push_back(item);
+
+            if tail.len() > rows_to_drop {
+                if let Some(item) = tail.pop_front() {
+                    yield ReturnSuccess::value(item);
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Drop;
+    use super::Command;
 
     #[test]
     fn examples_work_as_expected() {
         use crate::examples::test as test_examples;
 
-        test_examples(Drop {})
+        test_examples(Command {})
     }
 }
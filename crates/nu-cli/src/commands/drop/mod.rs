@@ -0,0 +1,5 @@
+mod column;
+mod command;
+
+pub use column::SubCommand as DropColumn;
+pub use command::Command as Drop;
@@ -7,7 +7,7 @@ use futures::stream::once;
 use nu_errors::ShellError;
 use nu_protocol::{
     hir::Block, hir::Expression, hir::SpannedExpression, hir::Synthetic, Scope, Signature,
-    SyntaxShape, UntaggedValue, Value,
+    SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
 };
 
 pub struct Each;
@@ -15,6 +15,7 @@ pub struct Each;
 #[derive(Deserialize)]
 pub struct EachArgs {
     block: Block,
+    numbered: bool,
 }
 
 #[async_trait]
@@ -24,11 +25,13 @@ impl WholeStreamCommand for Each {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("each").required(
-            "block",
-            SyntaxShape::Block,
-            "the block to run on each row",
-        )
+        Signature::build("each")
+            .required("block", SyntaxShape::Block, "the block to run on each row")
+            .switch(
+                "numbered",
+                "pass a row with the zero-based index and the item to the block",
+                Some('n'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -62,6 +65,15 @@ impl WholeStreamCommand for Each {
                     UntaggedValue::int(7).into(),
                 ]),
             },
+            Example {
+                description: "Echo the index of each item",
+                example: "echo [a b c] | each --numbered { echo $it.index }",
+                result: Some(vec![
+                    UntaggedValue::int(0).into(),
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                ]),
+            },
         ]
     }
 }
@@ -101,6 +113,13 @@ async fn process_row(
     .to_output_stream())
 }
 
+fn numbered(index: usize, item: Value) -> Value {
+    let mut dict = TaggedDictBuilder::new(&item.tag);
+    dict.insert_untagged("index", UntaggedValue::int(index));
+    dict.insert_value("item", item);
+    dict.into_value()
+}
+
 async fn each(
     raw_args: CommandArgs,
     registry: &CommandRegistry,
@@ -111,8 +130,15 @@ async fn each(
     let context = Arc::new(Context::from_raw(&raw_args, &registry));
     let (each_args, input): (EachArgs, _) = raw_args.process(&registry).await?;
     let block = Arc::new(each_args.block);
+    let is_numbered = each_args.numbered;
     Ok(input
-        .then(move |input| {
+        .enumerate()
+        .then(move |(index, input)| {
+            let input = if is_numbered {
+                numbered(index, input)
+            } else {
+                input
+            };
             let block = block.clone();
             let scope = scope.clone();
             let head = head.clone();
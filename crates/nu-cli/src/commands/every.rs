@@ -0,0 +1,110 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct EveryArgs {
+    stride: Tagged<u64>,
+    skip: bool,
+}
+
+pub struct Every;
+
+#[async_trait]
+impl WholeStreamCommand for Every {
+    fn name(&self) -> &str {
+        "every"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("every")
+            .required(
+                "stride",
+                SyntaxShape::Int,
+                "how many rows to step over between each kept row",
+            )
+            .switch(
+                "skip",
+                "drop every nth row instead of keeping it",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Keep (or skip) every nth row, starting with the first"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        every(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get every second row",
+                example: "echo [1 2 3 4 5] | every 2",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(5).into(),
+                ]),
+            },
+            Example {
+                description: "Skip every second row",
+                example: "echo [1 2 3 4 5] | every 2 --skip",
+                result: Some(vec![
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(4).into(),
+                ]),
+            },
+        ]
+    }
+}
+
+fn every(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let stream = async_stream! {
+        let (EveryArgs { stride, skip }, input) = args.process(&registry).await?;
+
+        if stride.item == 0 {
+            yield Err(ShellError::labeled_error(
+                "Stride must be a positive number",
+                "expected a number greater than zero",
+                stride.tag,
+            ));
+            return;
+        }
+
+        let stride = stride.item;
+        let mut inp = input.enumerate();
+
+        while let Some((idx, item)) = inp.next().await {
+            let nth = (idx as u64) % stride == 0;
+
+            if nth != skip {
+                yield ReturnSuccess::value(item);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Every;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Every {})
+    }
+}
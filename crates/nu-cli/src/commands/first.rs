@@ -53,6 +53,14 @@ impl WholeStreamCommand for First {
                     UntaggedValue::int(2).into(),
                 ]),
             },
+            Example {
+                description: "Return all the items when asking for more than there are",
+                example: "echo [1 2] | first 5",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                ]),
+            },
         ]
     }
 }
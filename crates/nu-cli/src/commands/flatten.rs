@@ -0,0 +1,355 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use indexmap::map::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{Dictionary, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct FlattenArgs {
+    rest: Vec<Tagged<String>>,
+    depth: Option<Tagged<usize>>,
+}
+
+pub struct Flatten;
+
+#[async_trait]
+impl WholeStreamCommand for Flatten {
+    fn name(&self) -> &str {
+        "flatten"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("flatten")
+            .rest(
+                SyntaxShape::String,
+                "optionally flatten only these columns (defaults to every nested row)",
+            )
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "only lift this many levels of nested rows (defaults to all of them)",
+                Some('d'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Lifts the fields of nested rows into the parent row, all the way down unless a depth is given"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        flatten(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Flatten every nested row",
+                example: r#"echo '{"a": 1, "b": {"c": 2}}' | from json | flatten"#,
+                result: None,
+            },
+            Example {
+                description: "Flatten a table column into one row per nested row",
+                example: r#"echo '{"name": "nu", "tags": [{"id": 1}, {"id": 2}]}' | from json | flatten tags"#,
+                result: None,
+            },
+            Example {
+                description: "Flatten only the first level of nested rows",
+                example: r#"echo '{"a": {"b": {"c": 1}}}' | from json | flatten --depth 1"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn flatten(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let stream = async_stream! {
+        let (FlattenArgs { rest, depth }, mut input) = args.process(&registry).await?;
+
+        let columns: Vec<String> = rest.into_iter().map(|column| column.item).collect();
+
+        if let Some(Tagged { item: 0, tag }) = depth {
+            yield Err(ShellError::labeled_error(
+                "Depth must be at least 1",
+                "flatten at least one level",
+                tag,
+            ));
+            return;
+        }
+        let depth = depth.map(|depth| depth.item);
+
+        while let Some(item) = input.next().await {
+            for row in flatten_value(&item, &columns, depth) {
+                yield ReturnSuccess::value(row);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Flattens the columns, or every nested row when none are given. A depth of None lifts all the
+/// levels, otherwise each level lifted uses up one.
+fn flatten_value(value: &Value, columns: &[String], depth: Option<usize>) -> Vec<Value> {
+    let dict = match &value.value {
+        UntaggedValue::Row(dict) => dict,
+        _ => return vec![value.clone()],
+    };
+
+    let targets: Vec<String> = if columns.is_empty() {
+        dict.entries
+            .iter()
+            .filter(|(_, cell)| matches!(cell.value, UntaggedValue::Row(_)))
+            .map(|(column, _)| column.clone())
+            .collect()
+    } else {
+        columns
+            .iter()
+            .filter(|column| dict.contains_key(column))
+            .cloned()
+            .collect()
+    };
+
+    let mut rows = vec![dict.entries.clone()];
+
+    for column in &targets {
+        rows = rows
+            .into_iter()
+            .flat_map(|row| expand(row, column, depth))
+            .collect();
+    }
+
+    rows.into_iter()
+        .map(|row| UntaggedValue::row(row).into_value(&value.tag))
+        .collect()
+}
+
+fn expand(
+    row: IndexMap<String, Value>,
+    column: &str,
+    depth: Option<usize>,
+) -> Vec<IndexMap<String, Value>> {
+    let nested = match row.get(column) {
+        Some(nested) => nested.clone(),
+        None => return vec![row],
+    };
+
+    match &nested.value {
+        UntaggedValue::Row(inner) => deeper(inner, depth)
+            .iter()
+            .map(|inner| lift(&row, column, Some(inner)))
+            .collect(),
+        UntaggedValue::Table(items) if items.is_empty() => vec![lift(&row, column, None)],
+        UntaggedValue::Table(items) => items
+            .iter()
+            .flat_map(|item| match &item.value {
+                UntaggedValue::Row(inner) => deeper(inner, depth)
+                    .iter()
+                    .map(|inner| lift(&row, column, Some(inner)))
+                    .collect(),
+                _ => {
+                    let mut out = row.clone();
+                    out.insert(column.to_string(), item.clone());
+                    vec![out]
+                }
+            })
+            .collect(),
+        _ => vec![row],
+    }
+}
+
+/// Flattens a nested row by the levels left before it gets lifted into its parent
+fn deeper(inner: &Dictionary, depth: Option<usize>) -> Vec<Dictionary> {
+    let remaining = depth.map(|depth| depth.saturating_sub(1));
+
+    if remaining == Some(0) {
+        return vec![inner.clone()];
+    }
+
+    flatten_value(
+        &UntaggedValue::Row(inner.clone()).into_untagged_value(),
+        &[],
+        remaining,
+    )
+    .into_iter()
+    .filter_map(|value| match value.value {
+        UntaggedValue::Row(dict) => Some(dict),
+        _ => None,
+    })
+    .collect()
+}
+
+/// Replaces the column with the fields of the nested row, each prefixed by the column name
+fn lift(
+    row: &IndexMap<String, Value>,
+    column: &str,
+    inner: Option<&Dictionary>,
+) -> IndexMap<String, Value> {
+    let mut out = IndexMap::new();
+
+    for (key, cell) in row {
+        if key != column {
+            out.insert(key.clone(), cell.clone());
+            continue;
+        }
+
+        if let Some(inner) = inner {
+            for (inner_key, inner_cell) in &inner.entries {
+                let name = unique_name(format!("{}.{}", column, inner_key), row, &out);
+                out.insert(name, inner_cell.clone());
+            }
+        }
+    }
+
+    out
+}
+
+/// Suffixes the name with the first free number when the parent row already has it
+fn unique_name(
+    name: String,
+    row: &IndexMap<String, Value>,
+    out: &IndexMap<String, Value>,
+) -> String {
+    let taken = |candidate: &str| row.contains_key(candidate) || out.contains_key(candidate);
+
+    if !taken(&name) {
+        return name;
+    }
+
+    let mut suffix = 1;
+    loop {
+        let candidate = format!("{}_{}", name, suffix);
+        if !taken(&candidate) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{flatten_value, Flatten};
+    use indexmap::{indexmap, IndexMap};
+    use nu_plugin::test_helpers::value::{int, string, table};
+    use nu_protocol::{UntaggedValue, Value};
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    fn columns(values: &[Value]) -> Vec<Vec<String>> {
+        values
+            .iter()
+            .map(|value| value.data_descriptors())
+            .collect()
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Flatten {})
+    }
+
+    #[test]
+    fn lifts_nested_row_fields_into_the_parent() {
+        let value = row(indexmap! {
+            "a".into() => int(1),
+            "b".into() => row(indexmap! {"c".into() => int(2)}),
+        });
+
+        let flattened = flatten_value(&value, &[], None);
+
+        assert_eq!(
+            flattened,
+            vec![row(
+                indexmap! {"a".into() => int(1), "b.c".into() => int(2)}
+            )]
+        );
+        assert_eq!(
+            columns(&flattened),
+            vec![vec!["a".to_string(), "b.c".to_string()]]
+        );
+    }
+
+    #[test]
+    fn flattens_table_columns_into_one_row_per_nested_row() {
+        let value = row(indexmap! {
+            "name".into() => string("nu"),
+            "tags".into() => table(&[
+                row(indexmap! {"id".into() => int(1)}),
+                row(indexmap! {"id".into() => int(2)}),
+            ]),
+        });
+
+        let flattened = flatten_value(&value, &["tags".to_string()], None);
+
+        assert_eq!(
+            flattened,
+            vec![
+                row(indexmap! {"name".into() => string("nu"), "tags.id".into() => int(1)}),
+                row(indexmap! {"name".into() => string("nu"), "tags.id".into() => int(2)}),
+            ]
+        );
+    }
+
+    #[test]
+    fn flattens_every_level_without_a_depth() {
+        let value = row(indexmap! {
+            "a".into() => row(indexmap! {
+                "b".into() => row(indexmap! {"c".into() => int(1)}),
+            }),
+        });
+
+        let flattened = flatten_value(&value, &[], None);
+
+        assert_eq!(flattened, vec![row(indexmap! {"a.b.c".into() => int(1)})]);
+    }
+
+    #[test]
+    fn stops_flattening_at_the_depth() {
+        let value = row(indexmap! {
+            "a".into() => row(indexmap! {
+                "b".into() => row(indexmap! {
+                    "c".into() => row(indexmap! {"d".into() => int(1)}),
+                }),
+            }),
+        });
+
+        assert_eq!(
+            flatten_value(&value, &[], Some(1)),
+            vec![row(indexmap! {
+                "a.b".into() => row(indexmap! {
+                    "c".into() => row(indexmap! {"d".into() => int(1)}),
+                }),
+            })]
+        );
+        assert_eq!(
+            flatten_value(&value, &[], Some(2)),
+            vec![row(indexmap! {
+                "a.b.c".into() => row(indexmap! {"d".into() => int(1)}),
+            })]
+        );
+    }
+
+    #[test]
+    fn disambiguates_names_colliding_with_parent_columns() {
+        let value = row(indexmap! {
+            "b".into() => row(indexmap! {"c".into() => int(2)}),
+            "b.c".into() => int(3),
+        });
+
+        let flattened = flatten_value(&value, &[], None);
+
+        assert_eq!(
+            columns(&flattened),
+            vec![vec!["b.c_1".to_string(), "b.c".to_string()]]
+        );
+    }
+}
@@ -3,8 +3,9 @@ use crate::context::CommandRegistry;
 use crate::evaluate::evaluate_baseline_expr;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
-use nu_source::Tagged;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
 use std::borrow::Borrow;
 
 pub struct Format;
@@ -12,6 +13,8 @@ pub struct Format;
 #[derive(Deserialize)]
 pub struct FormatArgs {
     pattern: Tagged<String>,
+    keep: bool,
+    into: Option<ColumnPath>,
 }
 
 #[async_trait]
@@ -21,11 +24,23 @@ impl WholeStreamCommand for Format {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("format").required(
-            "pattern",
-            SyntaxShape::String,
-            "the pattern to output. Eg) \"{foo}: {bar}\"",
-        )
+        Signature::build("format")
+            .required(
+                "pattern",
+                SyntaxShape::String,
+                "the pattern to output. Eg) \"{foo}: {bar}\"",
+            )
+            .switch(
+                "keep",
+                "leave placeholders that can't be filled in as they are",
+                Some('k'),
+            )
+            .named(
+                "into",
+                SyntaxShape::ColumnPath,
+                "put the output into the given column of each row instead of replacing the row",
+                Some('i'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -41,11 +56,23 @@ impl WholeStreamCommand for Format {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Print filenames with their sizes",
-            example: "ls | format '{name}: {size}'",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Print filenames with their sizes",
+                example: "ls | format '{name}: {size}'",
+                result: None,
+            },
+            Example {
+                description: "Add a column with the filenames and their sizes",
+                example: "ls | format '{name}: {size}' --into summary",
+                result: None,
+            },
+            Example {
+                description: "Leave the placeholders of missing columns as they are",
+                example: "ls | format '{name}: {nonexistent}' --keep",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -56,7 +83,7 @@ fn format_command(
     let registry = registry.clone();
     let stream = async_stream! {
         let scope = args.call_info.scope.clone();
-        let (FormatArgs { pattern }, mut input) = args.process(&registry).await?;
+        let (FormatArgs { pattern, keep, into }, mut input) = args.process(&registry).await?;
         let pattern_tag = pattern.tag.clone();
 
         let format_pattern = format(&pattern);
@@ -76,18 +103,26 @@ fn format_command(
 
                         let result = evaluate_baseline_expr(&full_column_path.0, &registry, &value, &scope.vars, &scope.env).await;
 
-                        if let Ok(c) = result {
-                            output
-                                .push_str(&value::format_leaf(c.borrow()).plain_string(100_000))
-                        } else {
-                            // That column doesn't match, so don't emit anything
+                        if let Err(err) = push_column(&mut output, c, result, keep, &pattern_tag, &value.tag) {
+                            yield Err(err);
+                            return;
                         }
                     }
                 }
             }
 
-            yield ReturnSuccess::value(
-                UntaggedValue::string(output).into_untagged_value())
+            let output = UntaggedValue::string(output);
+
+            match &into {
+                Some(column) => match value.insert_data_at_column_path(column, output.into_value(&value.tag)) {
+                    Ok(row) => yield ReturnSuccess::value(row),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                },
+                None => yield ReturnSuccess::value(output.into_untagged_value()),
+            }
         }
     };
 
@@ -95,12 +130,12 @@ fn format_command(
 }
 
 #[derive(Debug)]
-enum FormatCommand {
+pub(crate) enum FormatCommand {
     Text(String),
     Column(String),
 }
 
-fn format(input: &str) -> Vec<FormatCommand> {
+pub(crate) fn format(input: &str) -> Vec<FormatCommand> {
     let mut output = vec![];
 
     let mut loop_input = input.chars();
@@ -139,6 +174,37 @@ fn format(input: &str) -> Vec<FormatCommand> {
     output
 }
 
+/// Pushes the value found for a column of the pattern. When the row doesn't have
+/// the column, its placeholder is either kept as it is or reported as an error.
+pub(crate) fn push_column(
+    output: &mut String,
+    column: &str,
+    found: Result<Value, ShellError>,
+    keep: bool,
+    pattern_tag: &Tag,
+    row_tag: &Tag,
+) -> Result<(), ShellError> {
+    match found {
+        Ok(value) => output.push_str(&value::format_leaf(value.borrow()).plain_string(100_000)),
+        Err(_) if keep => {
+            output.push('{');
+            output.push_str(column);
+            output.push('}');
+        }
+        Err(_) => {
+            return Err(ShellError::labeled_error_with_secondary(
+                format!("Unknown column {}", column),
+                format!("the pattern refers to '{}'", column),
+                pattern_tag,
+                "which the row does not have",
+                row_tag,
+            ))
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::Format;
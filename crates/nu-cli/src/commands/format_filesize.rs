@@ -0,0 +1,223 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+use byte_unit::{Byte, ByteUnit};
+
+pub struct FormatFilesize;
+
+#[derive(Deserialize)]
+pub struct FormatFilesizeArgs {
+    rest: Vec<ColumnPath>,
+    unit: Option<Tagged<String>>,
+    decimal: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for FormatFilesize {
+    fn name(&self) -> &str {
+        "format filesize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format filesize")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally format the sizes at the column paths",
+            )
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "the unit to format with, eg) KB, MiB or GB",
+                Some('u'),
+            )
+            .switch(
+                "decimal",
+                "use multiples of 1000 instead of 1024 when picking the unit",
+                Some('d'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Format byte counts into human-readable sizes."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        format_filesize(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Format a byte count using multiples of 1024",
+                example: "echo 1536 | format filesize",
+                result: Some(vec![Value::from("1.5 KiB")]),
+            },
+            Example {
+                description: "Format a byte count using multiples of 1000",
+                example: "echo 1536 | format filesize --decimal",
+                result: Some(vec![Value::from("1.5 KB")]),
+            },
+            Example {
+                description: "Format a byte count in a given unit",
+                example: "echo 1536000 | format filesize --unit KB",
+                result: Some(vec![Value::from("1536.0 KB")]),
+            },
+        ]
+    }
+}
+
+fn format_filesize(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (FormatFilesizeArgs { rest, unit, decimal }, mut input) = args.process(&registry).await?;
+
+        let unit = match unit {
+            Some(Tagged { item, tag }) => match ByteUnit::from_str(&item) {
+                Ok(unit) => Some(unit),
+                Err(_) => {
+                    yield Err(ShellError::labeled_error(
+                        "Unknown unit",
+                        "expected a unit like KB, MiB or GB",
+                        tag,
+                    ));
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match action(&v, unit, decimal, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, unit, decimal, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn action(
+    input: &Value,
+    unit: Option<ByteUnit>,
+    decimal: bool,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
+    let bytes = match &input.value {
+        UntaggedValue::Primitive(Primitive::Bytes(bytes)) => Some(*bytes),
+        UntaggedValue::Primitive(Primitive::Int(int)) => int.to_u64(),
+        other => {
+            let got = format!("got {}", other.type_name());
+            return Err(ShellError::labeled_error(
+                "value is not a byte count",
+                got,
+                tag.span,
+            ));
+        }
+    };
+
+    let bytes = bytes.ok_or_else(|| {
+        ShellError::labeled_error(
+            "Could not format the byte count",
+            "expected a positive number of bytes",
+            tag.span,
+        )
+    })?;
+
+    let byte = Byte::from_bytes(bytes as u128);
+
+    let adjusted = match unit {
+        Some(unit) => byte.get_adjusted_unit(unit),
+        None => byte.get_appropriate_unit(!decimal),
+    };
+
+    Ok(UntaggedValue::string(adjusted.format(1)).into_value(tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, FormatFilesize};
+    use byte_unit::ByteUnit;
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(FormatFilesize {})
+    }
+
+    #[test]
+    fn formats_with_multiples_of_1000() {
+        let actual = action(&int(1536), None, true, Tag::unknown()).unwrap();
+
+        assert_eq!(actual, string("1.5 KB"));
+    }
+
+    #[test]
+    fn formats_with_multiples_of_1024() {
+        let actual = action(&int(1536), None, false, Tag::unknown()).unwrap();
+
+        assert_eq!(actual, string("1.5 KiB"));
+    }
+
+    #[test]
+    fn formats_in_the_given_unit() {
+        let actual = action(&int(1_500_000), Some(ByteUnit::KB), false, Tag::unknown()).unwrap();
+
+        assert_eq!(actual, string("1500.0 KB"));
+    }
+
+    #[test]
+    fn leaves_small_counts_in_bytes() {
+        let actual = action(&int(12), None, false, Tag::unknown()).unwrap();
+
+        assert_eq!(actual, string("12 B"));
+    }
+
+    #[test]
+    fn errors_given_negative_counts() {
+        assert!(action(&int(-1), None, false, Tag::unknown()).is_err());
+    }
+}
@@ -10,6 +10,7 @@ pub struct FromCSV;
 pub struct FromCSVArgs {
     headerless: bool,
     separator: Option<Value>,
+    quote: Option<Value>,
 }
 
 #[async_trait]
@@ -26,6 +27,12 @@ impl WholeStreamCommand for FromCSV {
                 "a character to separate columns, defaults to ','",
                 Some('s'),
             )
+            .named(
+                "quote",
+                SyntaxShape::String,
+                "a character to quote fields, defaults to '\"'",
+                Some('q'),
+            )
             .switch(
                 "headerless",
                 "don't treat the first row as column names",
@@ -62,6 +69,11 @@ impl WholeStreamCommand for FromCSV {
                 example: "open data.txt | from csv --separator ';'",
                 result: None,
             },
+            Example {
+                description: "Convert pipe-separated data quoted with single quotes to a table",
+                example: "open data.txt | from csv --separator '|' --quote \"'\"",
+                result: None,
+            },
         ]
     }
 }
@@ -77,33 +89,40 @@ async fn from_csv(
         FromCSVArgs {
             headerless,
             separator,
+            quote,
         },
         input,
     ) = args.process(&registry).await?;
-    let sep = match separator {
+
+    let sep = single_char(separator, "--separator", ',')?;
+    let quote = single_char(quote, "--quote", '"')?;
+
+    from_delimited_data(headerless, sep, quote, "CSV", input, name).await
+}
+
+fn single_char(value: Option<Value>, flag: &str, default: char) -> Result<char, ShellError> {
+    match value {
         Some(Value {
             value: UntaggedValue::Primitive(Primitive::String(s)),
             tag,
             ..
         }) => {
             if s == r"\t" {
-                '\t'
+                Ok('\t')
             } else {
                 let vec_s: Vec<char> = s.chars().collect();
-                if vec_s.len() != 1 {
+                if vec_s.len() != 1 || !vec_s[0].is_ascii() {
                     return Err(ShellError::labeled_error(
-                        "Expected a single separator char from --separator",
-                        "requires a single character string input",
+                        format!("Expected a single character from {}", flag),
+                        "requires a single ascii character string input",
                         tag,
                     ));
                 };
-                vec_s[0]
+                Ok(vec_s[0])
             }
         }
-        _ => ',',
-    };
-
-    from_delimited_data(headerless, sep, "CSV", input, name).await
+        _ => Ok(default),
+    }
 }
 
 #[cfg(test)]
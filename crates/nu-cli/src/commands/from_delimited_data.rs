@@ -7,11 +7,13 @@ fn from_delimited_string_to_value(
     s: String,
     headerless: bool,
     separator: char,
+    quote: char,
     tag: impl Into<Tag>,
 ) -> Result<Value, csv::Error> {
     let mut reader = ReaderBuilder::new()
         .has_headers(!headerless)
         .delimiter(separator as u8)
+        .quote(quote as u8)
         .from_reader(s.as_bytes());
     let tag = tag.into();
 
@@ -44,6 +46,7 @@ fn from_delimited_string_to_value(
 pub async fn from_delimited_data(
     headerless: bool,
     sep: char,
+    quote: char,
     format_name: &'static str,
     input: InputStream,
     name: Tag,
@@ -51,7 +54,13 @@ pub async fn from_delimited_data(
     let name_tag = name;
     let concat_string = input.collect_string(name_tag.clone()).await?;
 
-    match from_delimited_string_to_value(concat_string.item, headerless, sep, name_tag.clone()) {
+    match from_delimited_string_to_value(
+        concat_string.item,
+        headerless,
+        sep,
+        quote,
+        name_tag.clone(),
+    ) {
         Ok(x) => match x {
             Value {
                 value: UntaggedValue::Table(list),
@@ -1,8 +1,10 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use indexmap::map::IndexMap;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, Signature, TaggedDictBuilder, UntaggedValue, Value};
-use std::collections::HashMap;
+use serde_ini::parse::OkIter;
+use serde_ini::{Item, Parser};
 
 pub struct FromINI;
 
@@ -29,39 +31,62 @@ impl WholeStreamCommand for FromINI {
     }
 }
 
-fn convert_ini_second_to_nu_value(v: &HashMap<String, String>, tag: impl Into<Tag>) -> Value {
-    let mut second = TaggedDictBuilder::new(tag);
+pub fn from_ini_string_to_value(s: String, tag: impl Into<Tag>) -> Result<Value, String> {
+    let tag = tag.into();
 
-    for (key, value) in v.iter() {
-        second.insert_untagged(key.clone(), Primitive::String(value.clone()));
+    let mut top_level: IndexMap<String, String> = IndexMap::new();
+    let mut sections: IndexMap<String, IndexMap<String, String>> = IndexMap::new();
+    let mut current_section: Option<String> = None;
+
+    // Each line is one item, so its position gives the line number for errors
+    let lines = OkIter(s.lines().map(str::trim));
+
+    for (index, item) in Parser::new(lines).enumerate() {
+        let line = index + 1;
+
+        match item {
+            Ok(Item::Section { name }) => {
+                if top_level.contains_key(&name) {
+                    return Err(format!("section '{}' on line {} is also a key", name, line));
+                }
+
+                sections.entry(name.clone()).or_default();
+                current_section = Some(name);
+            }
+            Ok(Item::Value { key, value }) => {
+                let entries = match &current_section {
+                    Some(section) => sections.entry(section.clone()).or_default(),
+                    None => &mut top_level,
+                };
+
+                if entries.contains_key(&key) {
+                    return Err(format!("duplicate key '{}' on line {}", key, line));
+                }
+
+                entries.insert(key, value);
+            }
+            Ok(Item::Comment { .. }) | Ok(Item::Empty) => {}
+            Err(err) => return Err(format!("{} on line {}", err, line)),
+        }
     }
 
-    second.into_value()
-}
+    let mut top = TaggedDictBuilder::new(&tag);
 
-fn convert_ini_top_to_nu_value(
-    v: &HashMap<String, HashMap<String, String>>,
-    tag: impl Into<Tag>,
-) -> Value {
-    let tag = tag.into();
-    let mut top_level = TaggedDictBuilder::new(tag.clone());
-
-    for (key, value) in v.iter() {
-        top_level.insert_value(
-            key.clone(),
-            convert_ini_second_to_nu_value(value, tag.clone()),
-        );
+    for (key, value) in top_level {
+        top.insert_untagged(key, Primitive::String(value));
     }
 
-    top_level.into_value()
-}
+    for (name, entries) in sections {
+        let mut section = TaggedDictBuilder::new(&tag);
+
+        for (key, value) in entries {
+            section.insert_untagged(key, Primitive::String(value));
+        }
 
-pub fn from_ini_string_to_value(
-    s: String,
-    tag: impl Into<Tag>,
-) -> Result<Value, serde_ini::de::Error> {
-    let v: HashMap<String, HashMap<String, String>> = serde_ini::from_str(&s)?;
-    Ok(convert_ini_top_to_nu_value(&v, tag))
+        top.insert_value(name, section.into_value());
+    }
+
+    Ok(top.into_value())
 }
 
 async fn from_ini(
@@ -82,9 +107,9 @@ async fn from_ini(
             } => Ok(futures::stream::iter(list).to_output_stream()),
             x => Ok(OutputStream::one(x)),
         },
-        Err(_) => Err(ShellError::labeled_error_with_secondary(
+        Err(reason) => Err(ShellError::labeled_error_with_secondary(
             "Could not parse as INI",
-            "input cannot be parsed as INI",
+            format!("input cannot be parsed as INI: {}", reason),
             &tag,
             "value originates from here",
             concat_string.tag,
@@ -94,7 +119,15 @@ async fn from_ini(
 
 #[cfg(test)]
 mod tests {
-    use super::FromINI;
+    use super::{from_ini_string_to_value, FromINI};
+    use indexmap::{indexmap, IndexMap};
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::Tag;
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
 
     #[test]
     fn examples_work_as_expected() {
@@ -102,4 +135,46 @@ mod tests {
 
         test_examples(FromINI {})
     }
+
+    #[test]
+    fn parses_sections_into_nested_rows() {
+        let ini = r#"
+            ; settings that apply everywhere
+            name = nu
+
+            [server]
+            host = localhost
+            port = 8080
+
+            # the client section
+            [client]
+            retries = 3
+        "#;
+
+        let actual = from_ini_string_to_value(ini.to_string(), Tag::unknown()).unwrap();
+
+        assert_eq!(
+            actual,
+            row(indexmap! {
+                "name".into() => string("nu"),
+                "server".into() => row(indexmap! {
+                    "host".into() => string("localhost"),
+                    "port".into() => string("8080"),
+                }),
+                "client".into() => row(indexmap! {
+                    "retries".into() => string("3"),
+                }),
+            })
+        );
+        assert_eq!(actual.data_descriptors(), vec!["name", "server", "client"]);
+    }
+
+    #[test]
+    fn errors_on_duplicate_keys_in_a_section() {
+        let ini = "[server]\nport = 80\nport = 8080\n";
+
+        let actual = from_ini_string_to_value(ini.to_string(), Tag::unknown());
+
+        assert_eq!(actual, Err("duplicate key 'port' on line 3".to_string()));
+    }
 }
@@ -37,6 +37,58 @@ impl WholeStreamCommand for FromJSON {
     }
 }
 
+pub struct FromJSONL;
+
+#[async_trait]
+impl WholeStreamCommand for FromJSONL {
+    fn name(&self) -> &str {
+        "from jsonl"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from jsonl")
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as .jsonl/.ndjson (one json value per line) and create table."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let name_tag = args.call_info.name_tag.clone();
+        Ok(from_json_lines(args.input, name_tag))
+    }
+}
+
+pub struct FromNDJSON;
+
+#[async_trait]
+impl WholeStreamCommand for FromNDJSON {
+    fn name(&self) -> &str {
+        "from ndjson"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from ndjson")
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as .jsonl/.ndjson (one json value per line) and create table."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let name_tag = args.call_info.name_tag.clone();
+        Ok(from_json_lines(args.input, name_tag))
+    }
+}
+
 fn convert_json_value_to_nu_value(v: &serde_hjson::Value, tag: impl Into<Tag>) -> Value {
     let tag = tag.into();
 
@@ -76,33 +128,17 @@ fn from_json(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
     let registry = registry.clone();
 
     let stream = async_stream! {
-        let (FromJSONArgs { objects }, mut input) = args.process(&registry).await?;
-        let concat_string = input.collect_string(name_tag.clone()).await?;
+        let (FromJSONArgs { objects }, input) = args.process(&registry).await?;
 
         if objects {
-            for json_str in concat_string.item.lines() {
-                if json_str.is_empty() {
-                    continue;
-                }
+            let mut values = from_json_lines(input, name_tag);
 
-                match from_json_string_to_value(json_str.to_string(), &name_tag) {
-                    Ok(x) =>
-                        yield ReturnSuccess::value(x),
-                    Err(e) => {
-                        let mut message = "Could not parse as JSON (".to_string();
-                        message.push_str(&e.to_string());
-                        message.push_str(")");
-
-                        yield Err(ShellError::labeled_error_with_secondary(
-                            message,
-                            "input cannot be parsed as JSON",
-                            &name_tag,
-                            "value originates from here",
-                            concat_string.tag.clone()))
-                    }
-                }
+            while let Some(value) = values.next().await {
+                yield value;
             }
         } else {
+            let concat_string = input.collect_string(name_tag.clone()).await?;
+
             match from_json_string_to_value(concat_string.item, name_tag.clone()) {
                 Ok(x) =>
                     match x {
@@ -132,6 +168,73 @@ fn from_json(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
     Ok(stream.to_output_stream())
 }
 
+fn from_json_lines(mut input: InputStream, name_tag: Tag) -> OutputStream {
+    let stream = async_stream! {
+        let mut buffer = String::new();
+        let mut line_number = 0;
+
+        loop {
+            let done = match input.next().await {
+                Some(Value {
+                    value: UntaggedValue::Primitive(Primitive::String(s)),
+                    ..
+                })
+                | Some(Value {
+                    value: UntaggedValue::Primitive(Primitive::Line(s)),
+                    ..
+                }) => {
+                    buffer.push_str(&s);
+                    false
+                }
+                Some(Value { tag: value_tag, .. }) => {
+                    yield Err(ShellError::labeled_error_with_secondary(
+                        "Expected a string from pipeline",
+                        "requires string input",
+                        &name_tag,
+                        "value originates from here",
+                        value_tag,
+                    ));
+                    return;
+                }
+                None => {
+                    // Whatever is left over is the last line, even without a trailing newline
+                    buffer.push('\n');
+                    true
+                }
+            };
+
+            while let Some(idx) = buffer.find('\n') {
+                let line: String = buffer.drain(..=idx).collect();
+                line_number += 1;
+
+                let line = line.trim();
+
+                if line.is_empty() {
+                    continue;
+                }
+
+                match from_json_string_to_value(line.to_string(), &name_tag) {
+                    Ok(x) => yield ReturnSuccess::value(x),
+                    Err(e) => {
+                        yield Err(ShellError::labeled_error(
+                            format!("Could not parse line {} as JSON ({})", line_number, e),
+                            format!("line {} cannot be parsed as JSON", line_number),
+                            &name_tag,
+                        ));
+                        return;
+                    }
+                }
+            }
+
+            if done {
+                break;
+            }
+        }
+    };
+
+    stream.to_output_stream()
+}
+
 #[cfg(test)]
 mod tests {
     use super::FromJSON;
@@ -1,5 +1,6 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
 
@@ -45,7 +46,12 @@ pub fn convert_toml_value_to_nu_value(v: &toml::Value, tag: impl Into<Tag>) -> V
         )
         .into_value(tag),
         toml::Value::Datetime(dt) => {
-            UntaggedValue::Primitive(Primitive::String(dt.to_string())).into_value(tag)
+            let text = dt.to_string();
+
+            match convert_toml_datetime(&text) {
+                Some(date) => UntaggedValue::date(date).into_value(tag),
+                None => UntaggedValue::Primitive(Primitive::String(text)).into_value(tag),
+            }
         }
         toml::Value::Table(t) => {
             let mut collected = TaggedDictBuilder::new(&tag);
@@ -59,6 +65,25 @@ pub fn convert_toml_value_to_nu_value(v: &toml::Value, tag: impl Into<Tag>) -> V
     }
 }
 
+/// Datetimes with an offset keep their instant, while local datetimes and dates are read as UTC.
+/// Local times have no date to go with them, so those stay strings.
+fn convert_toml_datetime(text: &str) -> Option<DateTime<Utc>> {
+    // TOML allows a space between the date and the time instead of the T
+    let text = text.replacen(' ', "T", 1);
+
+    if let Ok(date) = DateTime::parse_from_rfc3339(&text) {
+        return Some(date.with_timezone(&Utc));
+    }
+
+    if let Ok(date) = NaiveDateTime::parse_from_str(&text, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Some(DateTime::from_utc(date, Utc));
+    }
+
+    NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+        .ok()
+        .map(|date| DateTime::from_utc(date.and_hms(0, 0, 0), Utc))
+}
+
 pub fn from_toml_string_to_value(s: String, tag: impl Into<Tag>) -> Result<Value, toml::de::Error> {
     let v: toml::Value = s.parse::<toml::Value>()?;
     Ok(convert_toml_value_to_nu_value(&v, tag))
@@ -101,7 +126,11 @@ pub fn from_toml(
 
 #[cfg(test)]
 mod tests {
-    use super::FromTOML;
+    use super::{from_toml_string_to_value, FromTOML};
+    use chrono::{TimeZone, Utc};
+    use nu_protocol::{Primitive, UntaggedValue};
+    use nu_source::{SpannedItem, Tag};
+    use nu_value_ext::ValueExt;
 
     #[test]
     fn examples_work_as_expected() {
@@ -109,4 +138,89 @@ mod tests {
 
         test_examples(FromTOML {})
     }
+
+    fn parse(toml: &str) -> nu_protocol::Value {
+        from_toml_string_to_value(toml.to_string(), Tag::unknown()).expect("valid toml")
+    }
+
+    #[test]
+    fn reads_datetimes_as_dates() {
+        let value = parse(
+            r#"
+            offset = 1979-05-27T00:32:00-07:00
+            local = 1979-05-27 07:32:00
+            day = 1979-05-27
+            time = 07:32:00
+            "#,
+        );
+
+        let expected = UntaggedValue::date(Utc.ymd(1979, 5, 27).and_hms(7, 32, 0));
+        let get = |key: &str| {
+            value
+                .get_data_by_key(key.spanned_unknown())
+                .expect("key exists")
+                .value
+        };
+
+        assert_eq!(get("offset"), expected);
+        assert_eq!(get("local"), expected);
+        assert_eq!(
+            get("day"),
+            UntaggedValue::date(Utc.ymd(1979, 5, 27).and_hms(0, 0, 0))
+        );
+        assert_eq!(
+            get("time"),
+            UntaggedValue::Primitive(Primitive::String("07:32:00".to_string()))
+        );
+    }
+
+    #[test]
+    fn reads_arrays_of_tables_as_tables_of_rows() {
+        let value = parse(
+            r#"
+            [[fruit]]
+            name = "apple"
+
+            [[fruit.variety]]
+            name = "red delicious"
+
+            [[fruit]]
+            name = "banana"
+            "#,
+        );
+
+        let fruit = value
+            .get_data_by_key("fruit".spanned_unknown())
+            .expect("fruit exists");
+
+        match &fruit.value {
+            UntaggedValue::Table(rows) => {
+                assert_eq!(rows.len(), 2);
+
+                let variety = rows[0]
+                    .get_data_by_key("variety".spanned_unknown())
+                    .expect("variety exists");
+
+                match variety.value {
+                    UntaggedValue::Table(varieties) => assert_eq!(varieties.len(), 1),
+                    other => panic!("expected a table, got {:?}", other),
+                }
+            }
+            other => panic!("expected a table, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reads_inline_tables_like_tables() {
+        let inline = parse(r#"owner = { name = "nu", dob = 1979-05-27 }"#);
+        let table = parse(
+            r#"
+            [owner]
+            name = "nu"
+            dob = 1979-05-27
+            "#,
+        );
+
+        assert_eq!(inline.value, table.value);
+    }
 }
@@ -9,6 +9,8 @@ pub struct FromTSV;
 #[derive(Deserialize)]
 pub struct FromTSVArgs {
     headerless: bool,
+    #[serde(rename = "no-headers")]
+    no_headers: bool,
 }
 
 #[async_trait]
@@ -18,11 +20,17 @@ impl WholeStreamCommand for FromTSV {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from tsv").switch(
-            "headerless",
-            "don't treat the first row as column names",
-            None,
-        )
+        Signature::build("from tsv")
+            .switch(
+                "headerless",
+                "don't treat the first row as column names",
+                None,
+            )
+            .switch(
+                "no-headers",
+                "don't treat the first row as column names (same as --headerless)",
+                Some('n'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -44,9 +52,15 @@ async fn from_tsv(
 ) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
     let name = args.call_info.name_tag.clone();
-    let (FromTSVArgs { headerless }, input) = args.process(&registry).await?;
+    let (
+        FromTSVArgs {
+            headerless,
+            no_headers,
+        },
+        input,
+    ) = args.process(&registry).await?;
 
-    from_delimited_data(headerless, '\t', "TSV", input, name).await
+    from_delimited_data(headerless || no_headers, '\t', '"', "TSV", input, name).await
 }
 
 #[cfg(test)]
@@ -28,12 +28,38 @@ impl WholeStreamCommand for FromXML {
     }
 }
 
-fn from_attributes_to_value(attributes: &[roxmltree::Attribute], tag: impl Into<Tag>) -> Value {
+/// Puts the namespace prefix back in front of the name so it isn't lost
+fn qualified_name(n: &roxmltree::Node, namespace: Option<&str>, name: &str) -> String {
+    match namespace.and_then(|uri| n.lookup_prefix(uri)) {
+        Some(prefix) if !prefix.is_empty() => format!("{}:{}", prefix, name),
+        _ => name.to_string(),
+    }
+}
+
+fn from_attributes_to_value(n: &roxmltree::Node, tag: impl Into<Tag>) -> Value {
     let tag = tag.into();
 
     let mut collected = TaggedDictBuilder::new(tag);
-    for a in attributes {
-        collected.insert_untagged(String::from(a.name()), UntaggedValue::string(a.value()));
+
+    // Namespaces in scope are inherited, only the ones declared here are attributes
+    let inherited = n.parent_element().map(|p| p.namespaces()).unwrap_or(&[]);
+    for ns in n.namespaces() {
+        if inherited.contains(ns) {
+            continue;
+        }
+
+        let name = match ns.name() {
+            Some(prefix) => format!("xmlns:{}", prefix),
+            None => String::from("xmlns"),
+        };
+        collected.insert_untagged(name, UntaggedValue::string(ns.uri()));
+    }
+
+    for a in n.attributes() {
+        collected.insert_untagged(
+            qualified_name(n, a.namespace(), a.name()),
+            UntaggedValue::string(a.value()),
+        );
     }
 
     collected.into_value()
@@ -43,7 +69,7 @@ fn from_node_to_value<'a, 'd>(n: &roxmltree::Node<'a, 'd>, tag: impl Into<Tag>)
     let tag = tag.into();
 
     if n.is_element() {
-        let name = n.tag_name().name().trim().to_string();
+        let name = qualified_name(n, n.tag_name().namespace(), n.tag_name().name().trim());
 
         let mut children_values = vec![];
         for c in n.children() {
@@ -63,9 +89,16 @@ fn from_node_to_value<'a, 'd>(n: &roxmltree::Node<'a, 'd>, tag: impl Into<Tag>)
             })
             .collect();
 
+        let content: String = n
+            .children()
+            .filter(|c| c.is_text())
+            .filter_map(|c| c.text())
+            .filter(|text| !text.trim().is_empty())
+            .collect();
+
         let mut collected = TaggedDictBuilder::new(&tag);
 
-        let attribute_value: Value = from_attributes_to_value(&n.attributes(), &tag);
+        let attribute_value: Value = from_attributes_to_value(n, &tag);
 
         let mut row = TaggedDictBuilder::new(&tag);
         row.insert_untagged(
@@ -73,6 +106,7 @@ fn from_node_to_value<'a, 'd>(n: &roxmltree::Node<'a, 'd>, tag: impl Into<Tag>)
             UntaggedValue::Table(children_values),
         );
         row.insert_untagged(String::from("attributes"), attribute_value);
+        row.insert_untagged(String::from("content"), UntaggedValue::string(content));
         collected.insert_untagged(name, row.into_value());
 
         collected.into_value()
@@ -117,10 +151,10 @@ fn from_xml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
                 }
                 x => yield ReturnSuccess::value(x),
             },
-            Err(_) => {
+            Err(err) => {
                 yield Err(ShellError::labeled_error_with_secondary(
                     "Could not parse as XML",
-                    "input cannot be parsed as XML",
+                    format!("input cannot be parsed as XML: {}", err),
                     &tag,
                     "value originates from here",
                     &concat_string.tag,
@@ -165,7 +199,8 @@ mod tests {
             row(indexmap! {
                 "nu".into() => row(indexmap! {
                     "children".into() => table(&[]),
-                    "attributes".into() => row(indexmap! {})
+                    "attributes".into() => row(indexmap! {}),
+                    "content".into() => string("")
                 })
             })
         );
@@ -182,7 +217,8 @@ mod tests {
             row(indexmap! {
                 "nu".into() => row(indexmap! {
                     "children".into() => table(&[string("La era de los tres caballeros")]),
-                    "attributes".into() => row(indexmap! {})
+                    "attributes".into() => row(indexmap! {}),
+                    "content".into() => string("La era de los tres caballeros")
                 })
             })
         );
@@ -207,23 +243,27 @@ mod tests {
                         row(indexmap! {
                             "dev".into() => row(indexmap! {
                                 "children".into() => table(&[string("Andrés")]),
-                                "attributes".into() => row(indexmap! {})
+                                "attributes".into() => row(indexmap! {}),
+                                "content".into() => string("Andrés")
                             })
                         }),
                         row(indexmap! {
                             "dev".into() => row(indexmap! {
                                 "children".into() => table(&[string("Jonathan")]),
-                                "attributes".into() => row(indexmap! {})
+                                "attributes".into() => row(indexmap! {}),
+                                "content".into() => string("Jonathan")
                             })
                         }),
                         row(indexmap! {
                             "dev".into() => row(indexmap! {
                                 "children".into() => table(&[string("Yehuda")]),
-                                "attributes".into() => row(indexmap! {})
+                                "attributes".into() => row(indexmap! {}),
+                                "content".into() => string("Yehuda")
                             })
                         })
                     ]),
-                    "attributes".into() => row(indexmap! {})
+                    "attributes".into() => row(indexmap! {}),
+                    "content".into() => string("")
                 })
             })
         );
@@ -244,7 +284,8 @@ mod tests {
                     "children".into() => table(&[]),
                     "attributes".into() => row(indexmap! {
                         "version".into() => string("2.0")
-                    })
+                    }),
+                    "content".into() => string("")
                 })
             })
         );
@@ -267,13 +308,15 @@ mod tests {
                            row(indexmap! {
                                 "version".into() => row(indexmap! {
                                     "children".into() => table(&[string("2.0")]),
-                                    "attributes".into() => row(indexmap! {})
+                                    "attributes".into() => row(indexmap! {}),
+                                    "content".into() => string("2.0")
                                 })
                           })
                     ]),
                     "attributes".into() => row(indexmap! {
                         "version".into() => string("2.0")
-                    })
+                    }),
+                    "content".into() => string("")
                 })
             })
         );
@@ -295,7 +338,8 @@ mod tests {
                     "attributes".into() => row(indexmap! {
                         "version".into() => string("2.0"),
                         "age".into() => string("25")
-                    })
+                    }),
+                    "content".into() => string("")
                 })
             })
         );
@@ -303,6 +347,73 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parses_element_with_attribute_and_text_content() -> Result<(), roxmltree::Error> {
+        let source = "<a x=\"1\"><b>hi</b></a>";
+
+        assert_eq!(
+            parse(source)?,
+            row(indexmap! {
+                "a".into() => row(indexmap! {
+                    "children".into() => table(&[
+                        row(indexmap! {
+                            "b".into() => row(indexmap! {
+                                "children".into() => table(&[string("hi")]),
+                                "attributes".into() => row(indexmap! {}),
+                                "content".into() => string("hi")
+                            })
+                        })
+                    ]),
+                    "attributes".into() => row(indexmap! {
+                        "x".into() => string("1")
+                    }),
+                    "content".into() => string("")
+                })
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_namespace_prefixes_in_names() -> Result<(), roxmltree::Error> {
+        let source = "\
+<rss xmlns:dc=\"http://purl.org/dc/elements/1.1/\" dc:lang=\"en\">
+    <dc:creator>Andrés</dc:creator>
+</rss>";
+
+        assert_eq!(
+            parse(source)?,
+            row(indexmap! {
+                "rss".into() => row(indexmap! {
+                    "children".into() => table(&[
+                        row(indexmap! {
+                            "dc:creator".into() => row(indexmap! {
+                                "children".into() => table(&[string("Andrés")]),
+                                "attributes".into() => row(indexmap! {}),
+                                "content".into() => string("Andrés")
+                            })
+                        })
+                    ]),
+                    "attributes".into() => row(indexmap! {
+                        "xmlns:dc".into() => string("http://purl.org/dc/elements/1.1/"),
+                        "dc:lang".into() => string("en")
+                    }),
+                    "content".into() => string("")
+                })
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_where_malformed_xml_fails() {
+        let error = parse("<nu>\n<dev></nu>").unwrap_err();
+
+        assert!(error.to_string().contains("2:"));
+    }
+
     #[test]
     fn examples_work_as_expected() {
         use super::FromXML;
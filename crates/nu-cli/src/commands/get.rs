@@ -5,16 +5,18 @@ use log::trace;
 use nu_errors::ShellError;
 use nu_protocol::{
     did_you_mean, ColumnPath, PathMember, Primitive, ReturnSuccess, Signature, SyntaxShape,
-    UnspannedPathMember, UntaggedValue, Value,
+    TaggedDictBuilder, UnspannedPathMember, UntaggedValue, Value,
 };
 use nu_source::span_for_spanned_list;
-use nu_value_ext::get_data_by_column_path;
+use nu_value_ext::{as_string, get_data_by_column_path};
 
 pub struct Get;
 
 #[derive(Deserialize)]
 pub struct GetArgs {
     rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "ignore-errors"))]
+    ignore_errors: bool,
 }
 
 #[async_trait]
@@ -24,14 +26,20 @@ impl WholeStreamCommand for Get {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("get").rest(
-            SyntaxShape::ColumnPath,
-            "optionally return additional data by path",
-        )
+        Signature::build("get")
+            .switch(
+                "ignore-errors",
+                "leave out the paths that can't be found instead of erroring",
+                Some('i'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally return additional data by path",
+            )
     }
 
     fn usage(&self) -> &str {
-        "Open given cells as text."
+        "Open given cells as text. Given more than one path, each row is narrowed down to the data at those paths."
     }
 
     async fn run(
@@ -54,6 +62,11 @@ impl WholeStreamCommand for Get {
                 example: "sys | get cpu",
                 result: None,
             },
+            Example {
+                description: "Narrow each file down to its name and size",
+                example: "ls | get name size",
+                result: None,
+            },
         ]
     }
 }
@@ -195,7 +208,7 @@ pub fn get_column_path(path: &ColumnPath, obj: &Value) -> Result<Value, ShellErr
 pub fn get(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
     let stream = async_stream! {
-        let (GetArgs { rest: mut fields }, mut input) = args.process(&registry).await?;
+        let (GetArgs { rest: mut fields, ignore_errors }, mut input) = args.process(&registry).await?;
         if fields.is_empty() {
             let mut vec = input.drain_vec().await;
 
@@ -203,41 +216,52 @@ pub fn get(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
             for desc in descs {
                 yield ReturnSuccess::value(desc);
             }
-        } else {
+        } else if fields.len() == 1 {
             let member = fields.remove(0);
-            trace!("get {:?} {:?}", member, fields);
+            trace!("get {:?}", member);
             while let Some(item) = input.next().await {
-                let member = vec![member.clone()];
-
-                let column_paths = vec![&member, &fields]
-                    .into_iter()
-                    .flatten()
-                    .collect::<Vec<&ColumnPath>>();
-
-                for path in column_paths {
-                    let res = get_column_path(&path, &item);
-
-                    match res {
-                        Ok(got) => match got {
-                            Value {
-                                value: UntaggedValue::Table(rows),
-                                ..
-                            } => {
-                                for item in rows {
-                                    yield ReturnSuccess::value(item.clone());
-                                }
+                match get_column_path(&member, &item) {
+                    Ok(got) => match got {
+                        Value {
+                            value: UntaggedValue::Table(rows),
+                            ..
+                        } => {
+                            for item in rows {
+                                yield ReturnSuccess::value(item.clone());
                             }
-                            Value {
-                                value: UntaggedValue::Primitive(Primitive::Nothing),
-                                ..
-                            } => {}
-                            other => yield ReturnSuccess::value(other.clone()),
-                        },
-                        Err(reason) => yield ReturnSuccess::value(
-                            UntaggedValue::Error(reason).into_untagged_value(),
-                        ),
+                        }
+                        Value {
+                            value: UntaggedValue::Primitive(Primitive::Nothing),
+                            ..
+                        } => {}
+                        other => yield ReturnSuccess::value(other.clone()),
+                    },
+                    Err(_) if ignore_errors => {}
+                    Err(reason) => yield ReturnSuccess::value(
+                        UntaggedValue::Error(reason).into_untagged_value(),
+                    ),
+                }
+            }
+        } else {
+            trace!("get {:?}", fields);
+            while let Some(item) = input.next().await {
+                let mut row = TaggedDictBuilder::new(&item.tag);
+
+                for path in &fields {
+                    match get_column_path(&path, &item) {
+                        Ok(got) => {
+                            let key = as_string(&UntaggedValue::Primitive(Primitive::ColumnPath(path.clone())).into_untagged_value())?;
+                            row.insert_value(key, got);
+                        }
+                        Err(_) if ignore_errors => {}
+                        Err(reason) => {
+                            yield Err(reason);
+                            return;
+                        }
                     }
                 }
+
+                yield ReturnSuccess::value(row.into_value());
             }
         }
     };
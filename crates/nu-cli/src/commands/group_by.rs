@@ -210,6 +210,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn groups_rows_missing_the_column_under_an_empty_key() -> Result<(), ShellError> {
+        let for_key = String::from("type").tagged_unknown();
+
+        let values = vec![
+            row(indexmap! {"name".into() => string("ls"), "type".into() => string("builtin")}),
+            row(indexmap! {"name".into() => string("vim")}),
+            row(indexmap! {"name".into() => string("cd"), "type".into() => string("builtin")}),
+        ];
+
+        assert_eq!(
+            group(&for_key, values, Tag::unknown())?,
+            row(indexmap! {
+                "builtin".into() => table(&[
+                    row(indexmap! {"name".into() => string("ls"), "type".into() => string("builtin")}),
+                    row(indexmap! {"name".into() => string("cd"), "type".into() => string("builtin")}),
+                ]),
+                "".into() => table(&[
+                    row(indexmap! {"name".into() => string("vim")}),
+                ]),
+            })
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn examples_work_as_expected() {
         use super::GroupBy;
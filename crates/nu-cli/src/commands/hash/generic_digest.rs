@@ -0,0 +1,82 @@
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, UntaggedValue, Value};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+use sha2::Digest;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+/// Runs a hash subcommand with the given digest, so each algorithm only needs its own command
+pub fn operate<D: Digest>(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+
+        let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
+
+        while let Some(v) = input.next().await {
+            if column_paths.is_empty() {
+                match action::<D>(&v, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+
+                let mut ret = v.clone();
+
+                for path in &column_paths {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action::<D>(old, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+pub fn action<D: Digest>(input: &Value, tag: Tag) -> Result<Value, ShellError> {
+    let bytes = match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => s.as_bytes(),
+        UntaggedValue::Primitive(Primitive::Binary(b)) => b.as_slice(),
+        other => {
+            let got = format!("got {}", other.type_name());
+            return Err(ShellError::labeled_error(
+                "value is not string or binary",
+                got,
+                tag.span,
+            ));
+        }
+    };
+
+    let digest: String = D::digest(bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+
+    Ok(UntaggedValue::string(digest).into_value(tag))
+}
@@ -0,0 +1,78 @@
+use crate::commands::hash::generic_digest;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use md5::Md5;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "hash md5"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("hash md5").rest(
+            SyntaxShape::ColumnPath,
+            "optionally md5 hash data by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "md5 encode a value"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        generic_digest::operate::<Md5>(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "md5 encode a string",
+            example: "echo 'abcdefghijklmnopqrstuvwxyz' | hash md5",
+            result: Some(vec![Value::from("c3fcd3d76192e4007dfb496cca67e13b")]),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+    use crate::commands::hash::generic_digest::action;
+    use md5::Md5;
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::{Primitive, UntaggedValue};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn md5_encode_string() {
+        let word = string("abcdefghijklmnopqrstuvwxyz");
+        let expected = string("c3fcd3d76192e4007dfb496cca67e13b");
+
+        let actual = action::<Md5>(&word, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn md5_encode_bytes() {
+        let bytes = UntaggedValue::Primitive(Primitive::Binary(vec![0xC0, 0xFF, 0xEE]))
+            .into_untagged_value();
+        let expected = string("5f80e231382769b0102b1164cf722d83");
+
+        let actual = action::<Md5>(&bytes, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
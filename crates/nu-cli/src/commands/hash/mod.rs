@@ -0,0 +1,8 @@
+mod command;
+mod generic_digest;
+mod md5;
+mod sha256;
+
+pub use self::md5::SubCommand as HashMd5;
+pub use command::Command as Hash;
+pub use sha256::SubCommand as HashSha256;
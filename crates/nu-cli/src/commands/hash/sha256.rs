@@ -0,0 +1,80 @@
+use crate::commands::hash::generic_digest;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+use sha2::Sha256;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "hash sha256"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("hash sha256").rest(
+            SyntaxShape::ColumnPath,
+            "optionally sha256 hash data by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "sha256 encode a value"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        generic_digest::operate::<Sha256>(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "sha256 encode a string",
+            example: "echo 'abc' | hash sha256",
+            result: Some(vec![Value::from(
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            )]),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+    use crate::commands::hash::generic_digest::action;
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::{Primitive, UntaggedValue};
+    use nu_source::Tag;
+    use sha2::Sha256;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn sha256_encode_string() {
+        let word = string("abc");
+        let expected = string("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+
+        let actual = action::<Sha256>(&word, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn sha256_encode_bytes() {
+        let bytes = UntaggedValue::Primitive(Primitive::Binary(vec![0xC0, 0xFF, 0xEE]))
+            .into_untagged_value();
+        let expected = string("c47a10dc272b1221f0380a2ae0f7d7fa830b3e378f2f5309bbf13f61ad211913");
+
+        let actual = action::<Sha256>(&bytes, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
@@ -45,41 +45,35 @@ pub fn headers(args: CommandArgs, _registry: &CommandRegistry) -> Result<OutputS
         let mut input = args.input;
         let rows: Vec<Value> = input.collect().await;
 
-        if rows.len() < 1 {
+        if rows.is_empty() {
             yield Err(ShellError::untagged_runtime_error("Couldn't find headers, was the input a properly formatted, non-empty table?"));
+            return;
         }
 
         //the headers are the first row in the table
-        let headers: Vec<String> = match &rows[0].value {
-            UntaggedValue::Row(d) => {
-                Ok(d.entries.iter().map(|(k, v)| {
-                    match v.as_string() {
-                        Ok(s) => s,
-                        Err(_) => { //If a cell that should contain a header name is empty, we name the column Column[index]
-                            match d.entries.get_full(k) {
-                                Some((index, _, _)) => format!("Column{}", index),
-                                None => "unknownColumn".to_string()
-                            }
-                        }
-                    }
-                }).collect())
+        let headers: Vec<String> = match cells(&rows[0]) {
+            Some(cells) => unique(cells.iter().enumerate().map(|(index, cell)| header_name(index, cell)).collect()),
+            None => {
+                yield Err(ShellError::unexpected_eof("Could not get headers, is the table empty?", rows[0].tag.span));
+                return;
             }
-            _ => Err(ShellError::unexpected_eof("Could not get headers, is the table empty?", rows[0].tag.span))
-        }?;
+        };
 
         //Each row is a dictionary with the headers as keys
         for r in rows.iter().skip(1) {
-            match &r.value {
-                UntaggedValue::Row(d) => {
-                    let mut i = 0;
+            match cells(r) {
+                Some(cells) => {
                     let mut entries = IndexMap::new();
-                    for (_, v) in d.entries.iter() {
-                        entries.insert(headers[i].clone(), v.clone());
-                        i += 1;
+                    for (i, v) in cells.into_iter().enumerate() {
+                        let key = match headers.get(i) {
+                            Some(header) => header.clone(),
+                            None => format!("Column{}", i),
+                        };
+                        entries.insert(key, v.clone());
                     }
                     yield Ok(ReturnSuccess::Value(UntaggedValue::Row(Dictionary{entries}).into_value(r.tag.clone())))
                 }
-                _ => yield Err(ShellError::unexpected_eof("Couldn't iterate through rows, was the input a properly formatted table?", r.tag.span))
+                None => yield Err(ShellError::unexpected_eof("Couldn't iterate through rows, was the input a properly formatted table?", r.tag.span))
             }
         }
     };
@@ -87,9 +81,51 @@ pub fn headers(args: CommandArgs, _registry: &CommandRegistry) -> Result<OutputS
     Ok(stream.to_output_stream())
 }
 
+/// The cells of a row, which may be a table row or a list of values like `[a b]`
+fn cells(row: &Value) -> Option<Vec<&Value>> {
+    match &row.value {
+        UntaggedValue::Row(d) => Some(d.entries.values().collect()),
+        UntaggedValue::Table(values) => Some(values.iter().collect()),
+        _ => None,
+    }
+}
+
+fn header_name(index: usize, cell: &Value) -> String {
+    let name = match cell.as_string() {
+        Ok(s) => s.trim_end_matches('\n').to_string(),
+        Err(_) => cell.convert_to_string(),
+    };
+
+    //If a cell that should contain a header name is empty, we name the column Column[index]
+    if name.trim().is_empty() {
+        format!("Column{}", index)
+    } else {
+        name
+    }
+}
+
+/// Suffixes repeated header names (`name`, `name_1`, `name_2`, ...) so no column is overwritten
+fn unique(names: Vec<String>) -> Vec<String> {
+    let mut headers: Vec<String> = Vec::with_capacity(names.len());
+
+    for name in names {
+        let mut header = name.clone();
+        let mut suffix = 1;
+
+        while headers.contains(&header) {
+            header = format!("{}_{}", name, suffix);
+            suffix += 1;
+        }
+
+        headers.push(header);
+    }
+
+    headers
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Headers;
+    use super::{unique, Headers};
 
     #[test]
     fn examples_work_as_expected() {
@@ -97,4 +133,14 @@ mod tests {
 
         test_examples(Headers {})
     }
+
+    #[test]
+    fn suffixes_repeated_header_names() {
+        let names = vec!["name", "size", "name", "name"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        assert_eq!(unique(names), vec!["name", "size", "name_1", "name_2"]);
+    }
 }
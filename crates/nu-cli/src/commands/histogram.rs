@@ -1,13 +1,9 @@
 use crate::commands::group_by::group;
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
-use crate::utils::data_processing::{columns_sorted, evaluate, map_max, reduce, t_sort};
 use nu_errors::ShellError;
-use nu_protocol::{
-    Primitive, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
-};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
 use nu_source::Tagged;
-use num_traits::{ToPrimitive, Zero};
 
 pub struct Histogram;
 
@@ -15,6 +11,7 @@ pub struct Histogram;
 pub struct HistogramArgs {
     column_name: Tagged<String>,
     rest: Vec<Tagged<String>>,
+    bars: bool,
 }
 
 #[async_trait]
@@ -30,6 +27,11 @@ impl WholeStreamCommand for Histogram {
                 SyntaxShape::String,
                 "the name of the column to graph by",
             )
+            .switch(
+                "bars",
+                "add a bars column drawing each frequency relative to the largest",
+                Some('b'),
+            )
             .rest(
                 SyntaxShape::String,
                 "column name to give the histogram's frequency column",
@@ -37,7 +39,7 @@ impl WholeStreamCommand for Histogram {
     }
 
     fn usage(&self) -> &str {
-        "Creates a new table with a histogram based on the column name passed in."
+        "Creates a new table with the frequency and percentage of each value in the column passed in, most frequent first."
     }
 
     async fn run(
@@ -62,8 +64,8 @@ impl WholeStreamCommand for Histogram {
                 result: None,
             },
             Example {
-                description: "Get a histogram for a list of numbers",
-                example: "echo [1 2 3 1 1 1 2 2 1 1] | histogram",
+                description: "Get a histogram for the types of files, drawing the frequencies",
+                example: "ls | histogram type --bars",
                 result: None,
             },
         ]
@@ -77,144 +79,52 @@ pub fn histogram(
     let registry = registry.clone();
     let name = args.call_info.name_tag.clone();
     let stream = async_stream! {
-        let (HistogramArgs { column_name, rest}, mut input) = args.process(&registry).await?;
+        let (HistogramArgs { column_name, rest, bars }, mut input) = args.process(&registry).await?;
         let values: Vec<Value> = input.collect().await;
 
-        let Tagged { item: group_by, .. } = column_name.clone();
-
         let groups = group(&column_name, values, &name)?;
-        let group_labels = columns_sorted(Some(group_by.clone()), &groups, &name);
-        let sorted = t_sort(Some(group_by.clone()), None, &groups, &name)?;
-        let evaled = evaluate(&sorted, None, &name)?;
-        let reduced = reduce(&evaled, None, &name)?;
-        let maxima = map_max(&reduced, None, &name)?;
-        let percents = percentages(&reduced, maxima, &name)?;
-
-        match percents {
-            Value {
-                value: UntaggedValue::Table(datasets),
-                ..
-            } => {
-
-                let mut idx = 0;
-
-                let column_names_supplied: Vec<_> = rest.iter().map(|f| f.item.clone()).collect();
-
-                let frequency_column_name = if column_names_supplied.is_empty() {
-                    "frequency".to_string()
-                } else {
-                    column_names_supplied[0].clone()
-                };
-
-                let column = (*column_name).clone();
-
-                let count_column_name = "count".to_string();
-                let count_shell_error = ShellError::labeled_error("Unable to load group count", "unabled to load group count", &name);
-                let mut count_values: Vec<u64> = Vec::new();
-
-                for table_entry in reduced.table_entries() {
-                    match table_entry {
-                        Value {
-                            value: UntaggedValue::Table(list),
-                            ..
-                        } => {
-                            for i in list {
-                                if let Ok(count) = i.value.clone().into_value(&name).as_u64() {
-                                    count_values.push(count);
-                                } else {
-                                    yield Err(count_shell_error);
-                                    return;
-                                }
-                            }
-                        }
-                        _ => {
-                            yield Err(count_shell_error);
-                            return;
-                        }
-                    }
-                }
-
-                if let Value { value: UntaggedValue::Table(start), .. } = datasets.get(0).ok_or_else(|| ShellError::labeled_error("Unable to load dataset", "unabled to load dataset", &name))? {
-                    for percentage in start.iter() {
-
-                        let mut fact = TaggedDictBuilder::new(&name);
-                        let value: Tagged<String> = group_labels.get(idx).ok_or_else(|| ShellError::labeled_error("Unable to load group labels", "unabled to load group labels", &name))?.clone();
-                        fact.insert_value(&column, UntaggedValue::string(value.item).into_value(value.tag));
-
-                        fact.insert_untagged(&count_column_name, UntaggedValue::int(count_values[idx]));
-
-                        if let Value { value: UntaggedValue::Primitive(Primitive::Int(ref num)), ref tag } = percentage.clone() {
-                            let string = std::iter::repeat("*").take(num.to_i32().ok_or_else(|| ShellError::labeled_error("Expected a number", "expected a number", tag))? as usize).collect::<String>();
-                            fact.insert_untagged(&frequency_column_name, UntaggedValue::string(string));
-                        }
-
-                        idx += 1;
-
-                        yield ReturnSuccess::value(fact.into_value());
-                    }
-                }
+
+        let mut frequencies: Vec<(String, usize)> = groups
+            .row_entries()
+            .map(|(label, rows)| (label.clone(), rows.table_entries().count()))
+            .collect();
+
+        // Stable, so values with the same frequency keep the order they were first seen in
+        frequencies.sort_by(|(_, left), (_, right)| right.cmp(left));
+
+        let total: usize = frequencies.iter().map(|(_, count)| count).sum();
+        let max = frequencies.first().map(|(_, count)| *count).unwrap_or(0);
+
+        let frequency_column_name = match rest.first() {
+            Some(column) => column.item.clone(),
+            None => "frequency".to_string(),
+        };
+
+        for (label, count) in frequencies {
+            let mut fact = TaggedDictBuilder::new(&name);
+
+            fact.insert_untagged(&column_name.item, UntaggedValue::string(label));
+            fact.insert_untagged(&frequency_column_name, UntaggedValue::int(count));
+            fact.insert_untagged("percentage", UntaggedValue::decimal(percentage(count, total)));
+
+            if bars {
+                fact.insert_untagged("bars", UntaggedValue::string("*".repeat(count * 100 / max)));
             }
-            _ => {}
+
+            yield ReturnSuccess::value(fact.into_value());
         }
     };
 
     Ok(stream.to_output_stream())
 }
 
-fn percentages(values: &Value, max: Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
-    let tag = tag.into();
-
-    let results: Value = match values {
-        Value {
-            value: UntaggedValue::Table(datasets),
-            ..
-        } => {
-            let datasets: Vec<_> = datasets
-                .iter()
-                .map(|subsets| match subsets {
-                    Value {
-                        value: UntaggedValue::Table(data),
-                        ..
-                    } => {
-                        let data = data
-                            .iter()
-                            .map(|d| match d {
-                                Value {
-                                    value: UntaggedValue::Primitive(Primitive::Int(n)),
-                                    ..
-                                } => {
-                                    let max = match &max {
-                                        Value {
-                                            value: UntaggedValue::Primitive(Primitive::Int(maxima)),
-                                            ..
-                                        } => maxima.clone(),
-                                        _ => Zero::zero(),
-                                    };
-
-                                    let n = (n * 100) / max;
-
-                                    UntaggedValue::int(n).into_value(&tag)
-                                }
-                                _ => UntaggedValue::int(0).into_value(&tag),
-                            })
-                            .collect::<Vec<_>>();
-                        UntaggedValue::Table(data).into_value(&tag)
-                    }
-                    _ => UntaggedValue::Table(vec![]).into_value(&tag),
-                })
-                .collect();
-
-            UntaggedValue::Table(datasets).into_value(&tag)
-        }
-        other => other.clone(),
-    };
-
-    Ok(results)
+fn percentage(count: usize, total: usize) -> f64 {
+    (count as f64) * 100.0 / (total as f64)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Histogram;
+    use super::{percentage, Histogram};
 
     #[test]
     fn examples_work_as_expected() {
@@ -222,4 +132,11 @@ mod tests {
 
         test_examples(Histogram {})
     }
+
+    #[test]
+    fn gives_percentages_of_the_total() {
+        assert_eq!(percentage(1, 4), 25.0);
+        assert_eq!(percentage(3, 4), 75.0);
+        assert_eq!(percentage(4, 4), 100.0);
+    }
 }
@@ -1,10 +1,17 @@
+use crate::commands::classified::block::run_block;
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
+use indexmap::IndexMap;
 use nu_errors::ShellError;
-use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{
+    ColumnPath, ReturnSuccess, Signature, SyntaxShape, UnspannedPathMember, UntaggedValue, Value,
+};
+use nu_source::span_for_spanned_list;
 use nu_value_ext::ValueExt;
 
+use futures::stream::once;
+
 pub struct Insert;
 
 #[derive(Deserialize)]
@@ -28,13 +35,13 @@ impl WholeStreamCommand for Insert {
             )
             .required(
                 "value",
-                SyntaxShape::String,
-                "the value to give the cell(s)",
+                SyntaxShape::Any,
+                "the value to give the cell(s), or a block run with each row as $it",
             )
     }
 
     fn usage(&self) -> &str {
-        "Insert a new column with a given value."
+        "Insert a new column with a given value. Existing columns are never replaced, use update for that."
     }
 
     async fn run(
@@ -44,31 +51,107 @@ impl WholeStreamCommand for Insert {
     ) -> Result<OutputStream, ShellError> {
         insert(args, registry)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Insert a column with the same value for every row",
+                example: "ls | insert is_on_a_computer yes",
+                result: None,
+            },
+            Example {
+                description: "Insert a column computed from each row",
+                example: r#"echo '[{"a": 1, "b": 2}, {"a": 3, "b": 4}]' | from json | insert total {= $it.a + $it.b}"#,
+                result: None,
+            },
+            Example {
+                description: "Insert a nested column, creating the rows leading to it",
+                example: r#"echo '{"name": "Andrés"}' | from json | insert address.verified $true"#,
+                result: None,
+            },
+        ]
+    }
 }
 
-fn insert(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+fn insert(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
+    let scope = raw_args.call_info.scope.clone();
 
     let stream = async_stream! {
-        let (InsertArgs { column, value }, mut input) = args.process(&registry).await?;
+        let mut context = Context::from_raw(&raw_args, &registry);
+        let (InsertArgs { column, value }, mut input) = raw_args.process(&registry).await?;
         while let Some(row) = input.next().await {
-            match row {
+            let tag = match &row {
                 Value {
                     value: UntaggedValue::Row(_),
-                    ..
-                } => match row.insert_data_at_column_path(&column, value.clone()) {
-                    Ok(v) => yield Ok(ReturnSuccess::Value(v)),
-                    Err(err) => yield Err(err),
-                },
-
+                    tag,
+                } => tag.clone(),
                 Value { tag, ..} => {
                     yield Err(ShellError::labeled_error(
                         "Unrecognized type in stream",
                         "original value",
                         tag,
                     ));
+                    continue;
+                }
+            };
+
+            if row.get_data_by_column_path(&column, Box::new(|(_, _, err)| err)).is_ok() {
+                yield Err(ShellError::labeled_error_with_secondary(
+                    "Column already exists",
+                    "there is already a column here",
+                    span_for_spanned_list(column.members().iter().map(|p| p.span)),
+                    "in this row (use update to change columns)",
+                    tag.span,
+                ));
+                continue;
+            }
+
+            let new_value = match &value {
+                Value {
+                    value: UntaggedValue::Block(block),
+                    ..
+                } => {
+                    let for_block = row.clone();
+                    let input_stream = once(async { Ok(for_block) }).to_input_stream();
+
+                    let result = run_block(
+                        &block,
+                        &mut context,
+                        input_stream,
+                        &row,
+                        &scope.vars,
+                        &scope.env
+                    ).await;
+
+                    match result {
+                        Ok(mut stream) => {
+                            let errors = context.get_errors();
+                            if let Some(error) = errors.first() {
+                                yield Err(error.clone());
+                                continue;
+                            }
+
+                            match stream.next().await {
+                                Some(v) => v,
+                                None => UntaggedValue::nothing().into_value(&tag),
+                            }
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            continue;
+                        }
+                    }
                 }
+                other => other.clone(),
+            };
 
+            let inserted = with_missing_rows(row, &column)
+                .and_then(|row| row.insert_data_at_column_path(&column, new_value));
+
+            match inserted {
+                Ok(v) => yield Ok(ReturnSuccess::Value(v)),
+                Err(err) => yield Err(err),
             }
         };
 
@@ -76,9 +159,37 @@ fn insert(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream,
     Ok(stream.to_output_stream())
 }
 
+/// Adds empty rows for the named columns leading up to the last member of the path
+/// that the row doesn't have yet, so eg) `address.verified` can be inserted into
+/// a row without an `address`.
+fn with_missing_rows(row: Value, column: &ColumnPath) -> Result<Value, ShellError> {
+    let members = column.members();
+    let mut row = row;
+
+    for idx in 1..members.len() {
+        if let UnspannedPathMember::String(_) = &members[idx - 1].unspanned {
+            let leading = ColumnPath::new(members[..idx].to_vec());
+
+            if row
+                .get_data_by_column_path(&leading, Box::new(|(_, _, err)| err))
+                .is_err()
+            {
+                let empty = UntaggedValue::row(IndexMap::new()).into_value(&row.tag);
+                row = row.insert_data_at_column_path(&leading, empty)?;
+            }
+        }
+    }
+
+    Ok(row)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Insert;
+    use super::{with_missing_rows, Insert};
+    use indexmap::indexmap;
+    use nu_protocol::{ColumnPath, PathMember, UntaggedValue};
+    use nu_source::{Span, Tag};
+    use nu_value_ext::ValueExt;
 
     #[test]
     fn examples_work_as_expected() {
@@ -86,4 +197,35 @@ mod tests {
 
         test_examples(Insert {})
     }
+
+    #[test]
+    fn creates_the_rows_leading_to_a_nested_column() {
+        let row = UntaggedValue::row(indexmap! {
+            "name".to_string() => UntaggedValue::string("Andrés").into_untagged_value(),
+        })
+        .into_untagged_value();
+
+        let column = ColumnPath::new(vec![
+            PathMember::string("address", Span::unknown()),
+            PathMember::string("verified", Span::unknown()),
+        ]);
+
+        let actual = with_missing_rows(row, &column)
+            .and_then(|row| {
+                row.insert_data_at_column_path(
+                    &column,
+                    UntaggedValue::boolean(true).into_value(Tag::unknown()),
+                )
+            })
+            .expect("Could not insert the nested column");
+
+        assert_eq!(
+            *actual
+                .get_data("address")
+                .borrow()
+                .get_data("verified")
+                .borrow(),
+            UntaggedValue::boolean(true).into_untagged_value()
+        );
+    }
 }
@@ -34,7 +34,7 @@ impl WholeStreamCommand for IsEmpty {
     }
 
     fn usage(&self) -> &str {
-        "Checks emptiness. The last value is the replacement value for any empty column(s) given to check against the table."
+        "Checks emptiness. The last value is the replacement value for any empty column(s) given to check against the table. Nothing, empty strings, and lists or rows holding only empty values are empty."
     }
 
     async fn run(
@@ -44,6 +44,31 @@ impl WholeStreamCommand for IsEmpty {
     ) -> Result<OutputStream, ShellError> {
         is_empty(args, registry)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check if a value is empty",
+                example: "echo '' | empty?",
+                result: Some(vec![UntaggedValue::boolean(true).into()]),
+            },
+            Example {
+                description: "Check if a value is not empty",
+                example: "echo 'x' | empty?",
+                result: Some(vec![UntaggedValue::boolean(false).into()]),
+            },
+            Example {
+                description: "Set a column to whether it is empty",
+                example: r#"echo '[{"likes": ""}, {"likes": 2}]' | from json | empty? likes"#,
+                result: None,
+            },
+            Example {
+                description: "Replace empty columns with a value",
+                example: r#"echo '[{"likes": ""}, {"likes": 2}]' | from json | empty? likes 0"#,
+                result: None,
+            },
+        ]
+    }
 }
 
 fn is_empty(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
@@ -138,20 +163,16 @@ fn is_empty(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
                             value: UntaggedValue::Row(_),
                             ..
                         } => {
-                            if val.is_empty() {
-                                match obj.replace_data_at_column_path(
-                                    &field,
-                                    UntaggedValue::boolean(true).into_value(&value_tag),
-                                ) {
-                                    Some(v) => yield Ok(ReturnSuccess::Value(v)),
-                                    None => yield Err(ShellError::labeled_error(
-                                        "empty? could not find place to check emptiness",
-                                        "column name",
-                                        &field.tag,
-                                    )),
-                                }
-                            } else {
-                                yield Ok(ReturnSuccess::Value(value))
+                            match obj.replace_data_at_column_path(
+                                &field,
+                                UntaggedValue::boolean(val.is_empty()).into_value(&value_tag),
+                            ) {
+                                Some(v) => yield Ok(ReturnSuccess::Value(v)),
+                                None => yield Err(ShellError::labeled_error(
+                                    "empty? could not find place to check emptiness",
+                                    "column name",
+                                    &field.tag,
+                                )),
                             }
                         }
                         _ => yield Err(ShellError::labeled_error(
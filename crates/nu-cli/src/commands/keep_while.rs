@@ -34,39 +34,78 @@ impl WholeStreamCommand for KeepWhile {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        let registry = registry.clone();
-        let scope = args.call_info.scope.clone();
-        let stream = async_stream! {
-            let mut call_info = args.evaluate_once(&registry).await?;
+        keep_while(args, registry)
+    }
+}
 
-            let block = call_info.args.expect_nth(0)?.clone();
+pub struct TakeWhile;
 
-            let condition = match block {
-                Value {
-                    value: UntaggedValue::Block(block),
-                    tag,
-                } => {
-                    if block.block.len() != 1 {
-                        yield Err(ShellError::labeled_error(
-                            "Expected a condition",
-                            "expected a condition",
-                            tag,
-                        ));
-                        return;
-                    }
-                    match block.block[0].list.get(0) {
-                        Some(item) => match item {
-                            ClassifiedCommand::Expr(expr) => expr.clone(),
-                            _ => {
-                                yield Err(ShellError::labeled_error(
-                                    "Expected a condition",
-                                    "expected a condition",
-                                    tag,
-                                ));
-                                return;
-                            }
-                        },
-                        None => {
+#[async_trait]
+impl WholeStreamCommand for TakeWhile {
+    fn name(&self) -> &str {
+        "take while"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("take while")
+            .required(
+                "condition",
+                SyntaxShape::Math,
+                "the condition that must be met to keep rows",
+            )
+            .filter()
+    }
+
+    fn usage(&self) -> &str {
+        "Takes rows from the start while the condition matches and stops at the first that doesn't."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        keep_while(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Take the leading positive numbers",
+            example: "echo [1 2 3 0 4] | take while {$it > 0}",
+            result: Some(vec![
+                UntaggedValue::int(1).into(),
+                UntaggedValue::int(2).into(),
+                UntaggedValue::int(3).into(),
+            ]),
+        }]
+    }
+}
+
+fn keep_while(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let scope = args.call_info.scope.clone();
+    let stream = async_stream! {
+        let mut call_info = args.evaluate_once(&registry).await?;
+
+        let block = call_info.args.expect_nth(0)?.clone();
+
+        let condition = match block {
+            Value {
+                value: UntaggedValue::Block(block),
+                tag,
+            } => {
+                if block.block.len() != 1 {
+                    yield Err(ShellError::labeled_error(
+                        "Expected a condition",
+                        "expected a condition",
+                        tag,
+                    ));
+                    return;
+                }
+                match block.block[0].list.get(0) {
+                    Some(item) => match item {
+                        ClassifiedCommand::Expr(expr) => expr.clone(),
+                        _ => {
                             yield Err(ShellError::labeled_error(
                                 "Expected a condition",
                                 "expected a condition",
@@ -74,51 +113,60 @@ impl WholeStreamCommand for KeepWhile {
                             ));
                             return;
                         }
+                    },
+                    None => {
+                        yield Err(ShellError::labeled_error(
+                            "Expected a condition",
+                            "expected a condition",
+                            tag,
+                        ));
+                        return;
                     }
                 }
-                Value { tag, .. } => {
-                    yield Err(ShellError::labeled_error(
-                        "Expected a condition",
-                        "expected a condition",
-                        tag,
-                    ));
-                    return;
-                }
+            }
+            Value { tag, .. } => {
+                yield Err(ShellError::labeled_error(
+                    "Expected a condition",
+                    "expected a condition",
+                    tag,
+                ));
+                return;
+            }
+        };
+
+        while let Some(item) = call_info.input.next().await {
+            let condition = condition.clone();
+            trace!("ITEM = {:?}", item);
+            let result =
+                evaluate_baseline_expr(&*condition, &registry, &item, &scope.vars, &scope.env)
+                    .await;
+            trace!("RESULT = {:?}", result);
+
+            let return_value = match result {
+                Ok(ref v) if v.is_true() => true,
+                _ => false,
             };
 
-            while let Some(item) = call_info.input.next().await {
-                let condition = condition.clone();
-                trace!("ITEM = {:?}", item);
-                let result =
-                    evaluate_baseline_expr(&*condition, &registry, &item, &scope.vars, &scope.env)
-                        .await;
-                trace!("RESULT = {:?}", result);
-
-                let return_value = match result {
-                    Ok(ref v) if v.is_true() => true,
-                    _ => false,
-                };
-
-                if return_value {
-                    yield ReturnSuccess::value(item);
-                } else {
-                    break;
-                }
+            if return_value {
+                yield ReturnSuccess::value(item);
+            } else {
+                break;
             }
-        };
+        }
+    };
 
-        Ok(stream.to_output_stream())
-    }
+    Ok(stream.to_output_stream())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::KeepWhile;
+    use super::{KeepWhile, TakeWhile};
 
     #[test]
     fn examples_work_as_expected() {
         use crate::examples::test as test_examples;
 
-        test_examples(KeepWhile {})
+        test_examples(KeepWhile {});
+        test_examples(TakeWhile {})
     }
 }
@@ -2,14 +2,14 @@ use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
 
 pub struct Last;
 
 #[derive(Deserialize)]
 pub struct LastArgs {
-    rows: Option<Tagged<u64>>,
+    rows: Option<Tagged<usize>>,
 }
 
 #[async_trait]
@@ -21,7 +21,7 @@ impl WholeStreamCommand for Last {
     fn signature(&self) -> Signature {
         Signature::build("last").optional(
             "rows",
-            SyntaxShape::Number,
+            SyntaxShape::Int,
             "starting from the back, the number of rows to return",
         )
     }
@@ -54,14 +54,21 @@ impl WholeStreamCommand for Last {
                     UntaggedValue::int(5).into(),
                 ]),
             },
+            Example {
+                description: "Get all the rows when asking for more than there are",
+                example: "echo [1 2] | last 5",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                ]),
+            },
         ]
     }
 }
 
 async fn last(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
-    let (LastArgs { rows }, input) = args.process(&registry).await?;
-    let v: Vec<_> = input.into_vec().await;
+    let (LastArgs { rows }, mut input) = args.process(&registry).await?;
 
     let rows_desired = if let Some(quantity) = rows {
         *quantity
@@ -69,15 +76,14 @@ async fn last(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
         1
     };
 
+    // Only the last rows_desired rows are kept while the input streams past
     let mut values_vec_deque = VecDeque::new();
 
-    let count = rows_desired as usize;
-
-    if count < v.len() {
-        let k = v.len() - count;
+    while let Some(value) = input.next().await {
+        values_vec_deque.push_back(value);
 
-        for x in v[k..].iter() {
-            values_vec_deque.push_back(ReturnSuccess::value(x.clone()));
+        if values_vec_deque.len() > rows_desired {
+            values_vec_deque.pop_front();
         }
     }
 
@@ -5,6 +5,12 @@ use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
 
 pub struct Lines;
 
+#[derive(Deserialize)]
+struct LinesArgs {
+    #[serde(rename(deserialize = "skip-empty"))]
+    skip_empty: bool,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Lines {
     fn name(&self) -> &str {
@@ -12,7 +18,7 @@ impl WholeStreamCommand for Lines {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("lines")
+        Signature::build("lines").switch("skip-empty", "drop the empty lines", Some('s'))
     }
 
     fn usage(&self) -> &str {
@@ -28,11 +34,18 @@ impl WholeStreamCommand for Lines {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Split multi-line string into lines",
-            example: r#"^echo "two\nlines" | lines"#,
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Split multi-line string into lines",
+                example: r#"^echo "two\nlines" | lines"#,
+                result: None,
+            },
+            Example {
+                description: "Split into lines, leaving out the empty ones",
+                example: r#"^echo "two\n\nlines" | lines --skip-empty"#,
+                result: None,
+            },
+        ]
     }
 }
 
@@ -51,10 +64,14 @@ fn lines(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream,
     let mut leftover_string = String::new();
     let registry = registry.clone();
     let stream = async_stream! {
-        let args = args.evaluate_once(&registry).await.unwrap();
-        let tag = args.name_tag();
-        let name_span = tag.span;
-        let mut input = args.input;
+        let name_span = args.call_info.name_tag.span;
+        let (LinesArgs { skip_empty }, mut input) = match args.process(&registry).await {
+            Ok(processed) => processed,
+            Err(e) => {
+                yield futures::stream::iter(vec![Err(e)]);
+                return;
+            }
+        };
         loop {
             match input.next().await {
                 Some(Value { value: UntaggedValue::Primitive(Primitive::String(st)), ..}) => {
@@ -73,7 +90,7 @@ fn lines(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream,
                         leftover_string.clear();
                     }
 
-                    let success_lines: Vec<_> = lines.iter().map(|x| ReturnSuccess::value(UntaggedValue::line(x).into_untagged_value())).collect();
+                    let success_lines: Vec<_> = lines.iter().filter(|x| !skip_empty || !x.is_empty()).map(|x| ReturnSuccess::value(UntaggedValue::line(x).into_untagged_value())).collect();
 
                     yield futures::stream::iter(success_lines)
                 }
@@ -92,7 +109,7 @@ fn lines(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream,
                         leftover_string.clear();
                     }
 
-                    let success_lines: Vec<_> = lines.iter().map(|x| ReturnSuccess::value(UntaggedValue::line(x).into_untagged_value())).collect();
+                    let success_lines: Vec<_> = lines.iter().filter(|x| !skip_empty || !x.is_empty()).map(|x| ReturnSuccess::value(UntaggedValue::line(x).into_untagged_value())).collect();
                     yield futures::stream::iter(success_lines)
                 }
                 Some( Value { tag: value_span, ..}) => {
@@ -0,0 +1,151 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+use num_traits::Signed;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math abs"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math abs").rest(
+            SyntaxShape::ColumnPath,
+            "optionally take the absolute value of the numbers at the column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the absolute value of each number."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the absolute value of each number",
+            example: "echo [-3 3 -2.5] | math abs",
+            result: Some(vec![
+                UntaggedValue::int(3).into(),
+                UntaggedValue::int(3).into(),
+                UntaggedValue::decimal(2.5).into(),
+            ]),
+        }]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match abs(&v, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| abs(old, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+pub fn abs(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => {
+            Ok(UntaggedValue::int(i.abs()).into_value(tag))
+        }
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            Ok(UntaggedValue::decimal(d.abs()).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not a number",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{abs, SubCommand};
+    use nu_plugin::test_helpers::value::{decimal, int, string};
+    use nu_source::Tag;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn keeps_ints_as_ints() {
+        assert_eq!(abs(&int(-3), Tag::unknown()).unwrap(), int(3));
+    }
+
+    #[test]
+    fn keeps_decimals_as_decimals() {
+        assert_eq!(abs(&decimal(-2.5), Tag::unknown()).unwrap(), decimal(2.5));
+    }
+
+    #[test]
+    fn handles_ints_larger_than_i64() {
+        let big = BigInt::from(i64::MIN) * BigInt::from(2);
+
+        assert_eq!(abs(&int(big.clone()), Tag::unknown()).unwrap(), int(-big));
+    }
+
+    #[test]
+    fn errors_given_non_numbers() {
+        assert!(abs(&string("nu"), Tag::unknown()).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::commands::math::utils::pow10;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+use num_bigint::BigInt;
+use num_traits::{One, Signed};
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math ceil"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math ceil").rest(
+            SyntaxShape::ColumnPath,
+            "optionally round up the numbers at the column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Rounds each number up to the nearest integer."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Round each number up to the nearest integer",
+            example: "echo [-1.5 1.5] | math ceil",
+            result: Some(vec![
+                UntaggedValue::int(-1).into(),
+                UntaggedValue::int(2).into(),
+            ]),
+        }]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match ceil(&v, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| ceil(old, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+pub fn ceil(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => {
+            Ok(UntaggedValue::int(i.clone()).into_value(tag))
+        }
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            let (digits, scale) = d.as_bigint_and_exponent();
+
+            let rounded = if scale <= 0 {
+                digits * pow10(-scale)
+            } else {
+                let divisor = pow10(scale);
+                let mut quotient = &digits / &divisor;
+                let remainder = &digits % &divisor;
+
+                if remainder.is_positive() {
+                    quotient += BigInt::one();
+                }
+
+                quotient
+            };
+
+            Ok(UntaggedValue::int(rounded).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not a number",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ceil, SubCommand};
+    use nu_plugin::test_helpers::value::{decimal, int, string};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn rounds_negative_decimals_up() {
+        assert_eq!(ceil(&decimal(-1.5), Tag::unknown()).unwrap(), int(-1));
+    }
+
+    #[test]
+    fn rounds_positive_decimals_up() {
+        assert_eq!(ceil(&decimal(1.5), Tag::unknown()).unwrap(), int(2));
+    }
+
+    #[test]
+    fn leaves_ints_as_they_are() {
+        assert_eq!(ceil(&int(-7), Tag::unknown()).unwrap(), int(-7));
+    }
+
+    #[test]
+    fn errors_given_non_numbers() {
+        assert!(ceil(&string("nu"), Tag::unknown()).is_err());
+    }
+}
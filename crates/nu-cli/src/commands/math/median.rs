@@ -0,0 +1,173 @@
+use crate::commands::math::utils::calculate;
+use crate::commands::WholeStreamCommand;
+use crate::data::base::coerce_compare;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::hir::Operator;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math median"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math median").rest(
+            SyntaxShape::ColumnPath,
+            "optionally compute the median of the values at the column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the median of the values."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+
+        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+        let values: Vec<Value> = input.drain_vec().await;
+
+        let result = calculate(&values, &rest, &name, median)?;
+
+        Ok(OutputStream::one(ReturnSuccess::value(result)))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the median of an odd-length list of numbers",
+                example: "echo [3 1 2] | math median",
+                result: Some(vec![UntaggedValue::int(2).into()]),
+            },
+            Example {
+                description: "Get the median of an even-length list of numbers",
+                example: "echo [1 2 3 4] | math median",
+                result: Some(vec![UntaggedValue::decimal(2.5).into()]),
+            },
+        ]
+    }
+}
+
+pub fn median(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    if values.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Cannot compute the median of an empty list",
+            "needs at least one value",
+            name,
+        ));
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(
+        |left, right| match coerce_compare(&left.value, &right.value) {
+            Ok(coerced) => coerced.compare(),
+            Err(_) => left.cmp(right),
+        },
+    );
+
+    let middle = sorted.len() / 2;
+
+    if sorted.len() % 2 == 1 {
+        Ok(sorted[middle].clone())
+    } else {
+        midpoint(&sorted[middle - 1], &sorted[middle], name)
+    }
+}
+
+fn midpoint(left: &Value, right: &Value, name: &Tag) -> Result<Value, ShellError> {
+    let coerce_error = |(left_type, right_type): (&'static str, &'static str)| {
+        ShellError::coerce_error(left_type.spanned(name.span), right_type.spanned(name.span))
+    };
+
+    match (&left.value, &right.value) {
+        (
+            UntaggedValue::Primitive(Primitive::Date(_)),
+            UntaggedValue::Primitive(Primitive::Date(_)),
+        ) => {
+            let half = match crate::data::value::compute_values(
+                Operator::Minus,
+                &right.value,
+                &left.value,
+            )
+            .map_err(coerce_error)?
+            {
+                UntaggedValue::Primitive(Primitive::Duration(seconds)) => {
+                    UntaggedValue::Primitive(Primitive::Duration(seconds / 2))
+                }
+                _ => {
+                    return Err(ShellError::labeled_error(
+                        "could not calculate the median of the dates",
+                        "source",
+                        name,
+                    ))
+                }
+            };
+
+            let result = crate::data::value::compute_values(Operator::Plus, &left.value, &half)
+                .map_err(coerce_error)?;
+
+            Ok(result.into_value(name))
+        }
+        (left, right) => {
+            let total = crate::data::value::compute_values(Operator::Plus, left, right)
+                .map_err(coerce_error)?;
+            let result = crate::data::value::compute_values(
+                Operator::Divide,
+                &total,
+                &UntaggedValue::decimal(2),
+            )
+            .map_err(coerce_error)?;
+
+            Ok(result.into_value(name))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{median, SubCommand};
+    use nu_plugin::test_helpers::value::{decimal, int};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn gets_the_middle_value_of_an_odd_length_list() {
+        let values = vec![int(3), int(1), int(2)];
+
+        assert_eq!(median(&values, &Tag::unknown()).unwrap(), int(2));
+    }
+
+    #[test]
+    fn averages_the_middle_values_of_an_even_length_list() {
+        let values = vec![int(1), int(2), int(3), int(4)];
+
+        assert_eq!(median(&values, &Tag::unknown()).unwrap(), decimal(2.5));
+    }
+
+    #[test]
+    fn errors_given_no_values() {
+        assert!(median(&[], &Tag::unknown()).is_err());
+    }
+}
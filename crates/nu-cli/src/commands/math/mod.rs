@@ -0,0 +1,26 @@
+pub mod abs;
+pub mod ceil;
+pub mod command;
+pub mod floor;
+pub mod median;
+pub mod mode;
+pub mod pow;
+pub mod product;
+pub mod round;
+pub mod sqrt;
+pub mod stddev;
+pub mod utils;
+pub mod variance;
+
+pub use abs::SubCommand as MathAbs;
+pub use ceil::SubCommand as MathCeil;
+pub use command::Command as Math;
+pub use floor::SubCommand as MathFloor;
+pub use median::SubCommand as MathMedian;
+pub use mode::SubCommand as MathMode;
+pub use pow::SubCommand as MathPow;
+pub use product::SubCommand as MathProduct;
+pub use round::SubCommand as MathRound;
+pub use sqrt::SubCommand as MathSqrt;
+pub use stddev::SubCommand as MathStddev;
+pub use variance::SubCommand as MathVariance;
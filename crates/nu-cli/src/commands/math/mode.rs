@@ -0,0 +1,157 @@
+use crate::commands::math::utils::calculate;
+use crate::commands::WholeStreamCommand;
+use crate::data::base::coerce_compare;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Signature, SyntaxShape, UntaggedValue, Value};
+
+use std::collections::BTreeMap;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math mode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math mode").rest(
+            SyntaxShape::ColumnPath,
+            "optionally compute the mode of the values at the column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the most frequent value(s) of the values."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+
+        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+        let values: Vec<Value> = input.drain_vec().await;
+
+        let result = calculate(&values, &rest, &name, mode)?;
+
+        match result {
+            Value {
+                value: UntaggedValue::Table(modes),
+                ..
+            } => Ok(futures::stream::iter(modes).to_output_stream()),
+            result => Ok(OutputStream::one(result)),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the most frequent value of a list of numbers",
+                example: "echo [1 1 2 3] | math mode",
+                result: Some(vec![UntaggedValue::int(1).into()]),
+            },
+            Example {
+                description: "Get all the most frequent values when there is a tie",
+                example: "echo [2 2 1 1] | math mode",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                ]),
+            },
+        ]
+    }
+}
+
+pub fn mode(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    if values.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Cannot compute the mode of an empty list",
+            "needs at least one value",
+            name,
+        ));
+    }
+
+    let mut frequencies: BTreeMap<&UntaggedValue, usize> = BTreeMap::new();
+
+    for value in values {
+        *frequencies.entry(&value.value).or_insert(0) += 1;
+    }
+
+    let max_frequency = frequencies.values().copied().max().unwrap_or(0);
+
+    let mut modes: Vec<&UntaggedValue> = frequencies
+        .into_iter()
+        .filter(|(_, frequency)| *frequency == max_frequency)
+        .map(|(value, _)| value)
+        .collect();
+
+    modes.sort_by(|left, right| match coerce_compare(left, right) {
+        Ok(coerced) => coerced.compare(),
+        Err(_) => left.cmp(right),
+    });
+
+    if modes.len() == 1 {
+        Ok(modes[0].clone().into_value(name))
+    } else {
+        Ok(UntaggedValue::Table(
+            modes
+                .into_iter()
+                .map(|value| value.clone().into_value(name))
+                .collect(),
+        )
+        .into_value(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mode, SubCommand};
+    use nu_plugin::test_helpers::value::{int, string, table};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn gets_the_unique_most_frequent_value() {
+        let values = vec![int(1), int(1), int(2), int(3)];
+
+        assert_eq!(mode(&values, &Tag::unknown()).unwrap(), int(1));
+    }
+
+    #[test]
+    fn gets_the_sorted_most_frequent_values_given_a_tie() {
+        let values = vec![int(2), int(1), int(2), int(1)];
+
+        assert_eq!(
+            mode(&values, &Tag::unknown()).unwrap(),
+            table(&[int(1), int(2)])
+        );
+    }
+
+    #[test]
+    fn gets_the_most_frequent_string() {
+        let values = vec![string("b"), string("a"), string("b")];
+
+        assert_eq!(mode(&values, &Tag::unknown()).unwrap(), string("b"));
+    }
+
+    #[test]
+    fn errors_given_no_values() {
+        assert!(mode(&[], &Tag::unknown()).is_err());
+    }
+}
@@ -0,0 +1,318 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+
+#[derive(Deserialize)]
+struct Arguments {
+    exponent: Value,
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math pow"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math pow")
+            .required(
+                "exponent",
+                SyntaxShape::Number,
+                "the exponent to raise each number to",
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally raise the numbers at the column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Raises each number to the given exponent."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Raise an int to an int exponent",
+                example: "echo 2 | math pow 10",
+                result: Some(vec![UntaggedValue::int(1024).into()]),
+            },
+            Example {
+                description: "Raise a number to a decimal exponent",
+                example: "echo 4 | math pow 0.5",
+                result: Some(vec![UntaggedValue::decimal(2).into()]),
+            },
+        ]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { exponent, rest }, mut input) = args.process(&registry).await?;
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match pow(&v, &exponent, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let exponent = exponent.clone();
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| pow(old, &exponent, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// The most bits the digits of an exact power may take before it's refused as too large.
+const MAX_BITS: u64 = 1 << 20;
+
+/// Ints raised to a non-negative int exponent stay ints, everything else becomes a decimal.
+///
+/// Int exponents are computed exactly, decimal exponents go through floating point.
+pub fn pow(input: &Value, exponent: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
+    let power = match &exponent.value {
+        UntaggedValue::Primitive(Primitive::Int(power)) => power,
+        _ => return float_pow(input, exponent, tag),
+    };
+
+    let times = power.abs().to_usize().ok_or_else(|| too_large(exponent))?;
+
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Int(base)) if !power.is_negative() => {
+            if too_many_bits(base, times) {
+                return Err(too_large(exponent));
+            }
+
+            Ok(UntaggedValue::int(num_traits::pow(base.clone(), times)).into_value(tag))
+        }
+        UntaggedValue::Primitive(Primitive::Int(base)) => decimal_pow(
+            &BigDecimal::new(base.clone(), 0),
+            power,
+            times,
+            exponent,
+            tag,
+        ),
+        UntaggedValue::Primitive(Primitive::Decimal(base)) => {
+            decimal_pow(base, power, times, exponent, tag)
+        }
+        _ => float_pow(input, exponent, tag),
+    }
+}
+
+fn decimal_pow(
+    base: &BigDecimal,
+    power: &BigInt,
+    times: usize,
+    exponent: &Value,
+    tag: Tag,
+) -> Result<Value, ShellError> {
+    let (digits, _) = base.as_bigint_and_exponent();
+
+    if too_many_bits(&digits, times) {
+        return Err(too_large(exponent));
+    }
+
+    let result = num_traits::pow(base.clone(), times);
+
+    if !power.is_negative() {
+        return Ok(UntaggedValue::decimal(result).into_value(tag));
+    }
+
+    if result.is_zero() {
+        return Err(ShellError::labeled_error(
+            "Could not raise the number to the exponent",
+            "zero can not be raised to a negative exponent",
+            tag.span,
+        ));
+    }
+
+    Ok(UntaggedValue::decimal(BigDecimal::one() / result).into_value(tag))
+}
+
+/// Whether the digits raised `times` would take more than `MAX_BITS`. Digits of zero or one
+/// never grow, however large the exponent.
+fn too_many_bits(digits: &BigInt, times: usize) -> bool {
+    if digits.abs() <= BigInt::one() {
+        return false;
+    }
+
+    match (digits.bits() as u64).checked_mul(times as u64) {
+        Some(bits) => bits > MAX_BITS,
+        None => true,
+    }
+}
+
+fn float_pow(input: &Value, exponent: &Value, tag: Tag) -> Result<Value, ShellError> {
+    let base = as_f64(input)?;
+    let power = as_f64(exponent)?;
+    let result = base.powf(power);
+
+    if result.is_finite() {
+        Ok(UntaggedValue::decimal(result).into_value(tag))
+    } else {
+        Err(ShellError::labeled_error(
+            "Could not raise the number to the exponent",
+            "result is not a number",
+            tag.span,
+        ))
+    }
+}
+
+fn too_large(exponent: &Value) -> ShellError {
+    ShellError::labeled_error(
+        "Exponent is too large",
+        "exponent is too large",
+        &exponent.tag,
+    )
+}
+
+fn as_f64(value: &Value) -> Result<f64, ShellError> {
+    let number = match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_f64(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.to_f64(),
+        other => {
+            let got = format!("got {}", other.type_name());
+            return Err(ShellError::labeled_error(
+                "value is not a number",
+                got,
+                value.tag.span,
+            ));
+        }
+    };
+
+    number.ok_or_else(|| {
+        ShellError::labeled_error(
+            "Could not convert the number to a decimal",
+            "number is too large",
+            value.tag.span,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pow, SubCommand};
+    use bigdecimal::BigDecimal;
+    use nu_plugin::test_helpers::value::{decimal, int, string};
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+    use std::str::FromStr;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn keeps_ints_raised_to_int_exponents_as_ints() {
+        assert_eq!(pow(&int(2), &int(10), Tag::unknown()).unwrap(), int(1024));
+    }
+
+    #[test]
+    fn gives_decimals_for_negative_exponents() {
+        assert_eq!(
+            pow(&int(2), &int(-1), Tag::unknown()).unwrap(),
+            decimal(0.5)
+        );
+    }
+
+    #[test]
+    fn gives_decimals_for_decimal_bases() {
+        assert_eq!(
+            pow(&decimal(1.5), &int(2), Tag::unknown()).unwrap(),
+            decimal(2.25)
+        );
+    }
+
+    #[test]
+    fn gives_exact_decimals_for_negative_exponents() {
+        assert_eq!(
+            pow(&decimal(0.5), &int(-2), Tag::unknown()).unwrap(),
+            decimal(4)
+        );
+    }
+
+    #[test]
+    fn errors_when_the_exact_result_would_be_too_large() {
+        assert!(pow(&int(2), &int(1_000_000_000_000i64), Tag::unknown()).is_err());
+        assert_eq!(
+            pow(&int(1), &int(1_000_000_000_000i64), Tag::unknown()).unwrap(),
+            int(1)
+        );
+    }
+
+    #[test]
+    fn errors_when_the_exponent_is_the_largest_int() {
+        let largest = int(i64::MAX);
+        let one_point_zero =
+            UntaggedValue::decimal(BigDecimal::from_str("1.0").unwrap()).into_untagged_value();
+
+        assert!(pow(&int(4), &largest, Tag::unknown()).is_err());
+        assert!(pow(&decimal(1.5), &largest, Tag::unknown()).is_err());
+        assert!(pow(&one_point_zero, &largest, Tag::unknown()).is_err());
+    }
+
+    #[test]
+    fn errors_raising_zero_to_a_negative_exponent() {
+        assert!(pow(&int(0), &int(-1), Tag::unknown()).is_err());
+    }
+
+    #[test]
+    fn errors_when_the_result_is_not_a_number() {
+        assert!(pow(&int(-8), &decimal(0.5), Tag::unknown()).is_err());
+    }
+
+    #[test]
+    fn errors_given_non_numbers() {
+        assert!(pow(&string("nu"), &int(2), Tag::unknown()).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use crate::utils::data_processing::{reducer_for, Reduce};
+use nu_errors::ShellError;
+use nu_protocol::{Dictionary, ReturnSuccess, ReturnValue, Signature, UntaggedValue, Value};
+use num_traits::identities::Zero;
+
+use indexmap::map::IndexMap;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math product"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math product")
+    }
+
+    fn usage(&self) -> &str {
+        "Multiplies the values."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        product(RunnableContext {
+            input: args.input,
+            registry: registry.clone(),
+            shell_manager: args.shell_manager,
+            host: args.host,
+            ctrl_c: args.ctrl_c,
+            current_errors: args.current_errors,
+            name: args.call_info.name_tag,
+            raw_input: args.raw_input,
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Multiply a list of integers",
+                example: "echo [2 3 4] | math product",
+                result: Some(vec![UntaggedValue::int(24).into()]),
+            },
+            Example {
+                description: "Multiply a list of numbers mixing decimals",
+                example: "echo [1.5 2] | math product",
+                result: Some(vec![UntaggedValue::decimal(3).into()]),
+            },
+        ]
+    }
+}
+
+fn product(RunnableContext { mut input, .. }: RunnableContext) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let values: Vec<Value> = input.drain_vec().await;
+        let action = reducer_for(Reduce::Product);
+
+        if values.iter().all(|v| if let UntaggedValue::Primitive(_) = v.value {true} else {false}) {
+            match action(Value::zero(), values) {
+                Ok(total) => yield ReturnSuccess::value(total),
+                Err(err) => yield Err(err),
+            }
+        } else {
+            let mut column_values = IndexMap::new();
+            for value in values {
+                if let UntaggedValue::Row(row_dict) = value.value {
+                    for (key, value) in row_dict.entries.iter() {
+                        column_values
+                            .entry(key.clone())
+                            .and_modify(|v: &mut Vec<Value>| v.push(value.clone()))
+                            .or_insert(vec![value.clone()]);
+                    }
+                }
+            }
+
+            let mut column_totals = IndexMap::new();
+            for (col_name, col_vals) in column_values {
+                match action(Value::zero(), col_vals) {
+                    Ok(value) => {
+                        column_totals.insert(col_name, value);
+                    },
+                    Err(err) => yield Err(err),
+                };
+            }
+            yield ReturnSuccess::value(
+                UntaggedValue::Row(Dictionary {entries: column_totals}).into_untagged_value())
+        }
+    };
+
+    let stream: BoxStream<'static, ReturnValue> = stream.boxed();
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
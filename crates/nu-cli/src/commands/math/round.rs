@@ -0,0 +1,246 @@
+use crate::commands::math::utils::pow10;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+use num_traits::{One, Signed};
+
+#[derive(Deserialize)]
+struct Arguments {
+    precision: Option<Tagged<i64>>,
+    rest: Vec<ColumnPath>,
+}
+
+/// The most decimal places, either way, a number can be rounded to.
+const MAX_PRECISION: i64 = 10_000;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math round"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math round")
+            .named(
+                "precision",
+                SyntaxShape::Int,
+                "the number of decimal places to round to, defaults to 0",
+                Some('p'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally round the numbers at the column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Rounds each number half away from zero to the given number of decimal places."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Round each number to an integer",
+                example: "echo [1.5 2.4 -2.5] | math round",
+                result: Some(vec![
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(-3).into(),
+                ]),
+            },
+            Example {
+                description: "Round a number to two decimal places",
+                example: "echo 2.3456 | math round --precision 2",
+                result: Some(vec![UntaggedValue::decimal(2.35).into()]),
+            },
+            Example {
+                description: "Round a number to the nearest hundred",
+                example: "echo 1250 | math round --precision -2",
+                result: Some(vec![UntaggedValue::int(1300).into()]),
+            },
+        ]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { precision, rest }, mut input) = args.process(&registry).await?;
+        let precision = match precision {
+            Some(p) if p.item.abs() > MAX_PRECISION => {
+                yield Err(ShellError::labeled_error(
+                    "Precision is too large",
+                    format!("precision must be between -{0} and {0}", MAX_PRECISION),
+                    p.tag,
+                ));
+                return;
+            }
+            Some(p) => p.item,
+            None => 0,
+        };
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match round(&v, precision, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| round(old, precision, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Rounds half away from zero, so `2.5` becomes `3` and `-2.5` becomes `-3`.
+///
+/// A precision of zero or less gives back an int, and a negative precision rounds to tens,
+/// hundreds and so on. Ints are left as they are unless the precision is negative.
+pub fn round(input: &Value, precision: i64, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) if precision >= 0 => {
+            Ok(UntaggedValue::int(i.clone()).into_value(tag))
+        }
+        UntaggedValue::Primitive(Primitive::Int(i)) => {
+            Ok(round_decimal(&BigDecimal::new(i.clone(), 0), precision).into_value(tag))
+        }
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => {
+            Ok(round_decimal(d, precision).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not a number",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+fn round_decimal(value: &BigDecimal, precision: i64) -> UntaggedValue {
+    let (digits, scale) = value.as_bigint_and_exponent();
+
+    let rounded = if scale <= precision {
+        value.clone()
+    } else {
+        let divisor = pow10(scale - precision);
+        let mut quotient = &digits / &divisor;
+        let remainder = &digits % &divisor;
+
+        if remainder.abs() * BigInt::from(2) >= divisor {
+            if digits.is_negative() {
+                quotient -= BigInt::one();
+            } else {
+                quotient += BigInt::one();
+            }
+        }
+
+        BigDecimal::new(quotient, precision)
+    };
+
+    if precision > 0 {
+        return UntaggedValue::decimal(rounded);
+    }
+
+    let (digits, scale) = rounded.into_bigint_and_exponent();
+
+    if scale >= 0 {
+        UntaggedValue::int(digits / pow10(scale))
+    } else {
+        UntaggedValue::int(digits * pow10(-scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{round, SubCommand};
+    use nu_plugin::test_helpers::value::{decimal, int, string};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn rounds_to_an_int_by_default() {
+        assert_eq!(round(&decimal(3.4567), 0, Tag::unknown()).unwrap(), int(3));
+    }
+
+    #[test]
+    fn rounds_to_the_given_decimal_places() {
+        assert_eq!(
+            round(&decimal(2.3456), 2, Tag::unknown()).unwrap(),
+            decimal(2.35)
+        );
+    }
+
+    #[test]
+    fn rounds_half_away_from_zero() {
+        assert_eq!(round(&decimal(2.5), 0, Tag::unknown()).unwrap(), int(3));
+        assert_eq!(round(&decimal(-2.5), 0, Tag::unknown()).unwrap(), int(-3));
+    }
+
+    #[test]
+    fn leaves_ints_as_they_are() {
+        assert_eq!(round(&int(7), 2, Tag::unknown()).unwrap(), int(7));
+    }
+
+    #[test]
+    fn rounds_to_tens_given_negative_precision() {
+        assert_eq!(round(&int(1250), -2, Tag::unknown()).unwrap(), int(1300));
+        assert_eq!(
+            round(&decimal(-14.9), -1, Tag::unknown()).unwrap(),
+            int(-10)
+        );
+    }
+
+    #[test]
+    fn errors_given_non_numbers() {
+        assert!(round(&string("nu"), 0, Tag::unknown()).is_err());
+    }
+}
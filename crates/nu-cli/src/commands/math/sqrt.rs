@@ -0,0 +1,168 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math sqrt"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math sqrt").rest(
+            SyntaxShape::ColumnPath,
+            "optionally take the square root of the numbers at the column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Gets the square root of each number."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the square root of each number",
+            example: "echo [9 2.25] | math sqrt",
+            result: Some(vec![
+                UntaggedValue::decimal(3).into(),
+                UntaggedValue::decimal(1.5).into(),
+            ]),
+        }]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match sqrt(&v, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| sqrt(old, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+pub fn sqrt(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
+    let number = match &input.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => BigDecimal::new(i.clone(), 0),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.clone(),
+        other => {
+            let got = format!("got {}", other.type_name());
+            return Err(ShellError::labeled_error(
+                "value is not a number",
+                got,
+                tag.span,
+            ));
+        }
+    };
+
+    match number.sqrt() {
+        Some(root) => Ok(UntaggedValue::decimal(trim_trailing_zeros(root)).into_value(tag)),
+        None => Err(ShellError::labeled_error(
+            "Cannot take the square root of a negative number",
+            "value is negative",
+            tag.span,
+        )),
+    }
+}
+
+fn trim_trailing_zeros(number: BigDecimal) -> BigDecimal {
+    let (mut digits, mut scale) = number.into_bigint_and_exponent();
+    let ten = BigInt::from(10);
+
+    while scale > 0 && !digits.is_zero() && (&digits % &ten).is_zero() {
+        digits /= &ten;
+        scale -= 1;
+    }
+
+    BigDecimal::new(digits, scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sqrt, SubCommand};
+    use nu_plugin::test_helpers::value::{decimal, int, string};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn takes_the_square_root_of_ints() {
+        assert_eq!(sqrt(&int(9), Tag::unknown()).unwrap(), decimal(3));
+    }
+
+    #[test]
+    fn takes_the_square_root_of_decimals() {
+        assert_eq!(sqrt(&decimal(2.25), Tag::unknown()).unwrap(), decimal(1.5));
+    }
+
+    #[test]
+    fn errors_given_negative_numbers() {
+        assert!(sqrt(&int(-4), Tag::unknown()).is_err());
+    }
+
+    #[test]
+    fn errors_given_non_numbers() {
+        assert!(sqrt(&string("nu"), Tag::unknown()).is_err());
+    }
+}
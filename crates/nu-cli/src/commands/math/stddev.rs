@@ -0,0 +1,118 @@
+use crate::commands::math::utils::calculate;
+use crate::commands::math::variance::compute_variance;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    sample: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math stddev"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math stddev")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally compute the standard deviation of the values at the column paths",
+            )
+            .switch(
+                "sample",
+                "calculate the sample standard deviation (divides by the number of values minus one)",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Finds the standard deviation of the values."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+
+        let (Arguments { rest, sample }, mut input) = args.process(&registry).await?;
+        let values: Vec<Value> = input.drain_vec().await;
+
+        let result = if sample {
+            calculate(&values, &rest, &name, sample_stddev)?
+        } else {
+            calculate(&values, &rest, &name, population_stddev)?
+        };
+
+        Ok(OutputStream::one(ReturnSuccess::value(result)))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the standard deviation of a list of numbers",
+            example: "echo [2 4 4 4 5 5 7 9] | math stddev",
+            result: Some(vec![UntaggedValue::decimal(2).into()]),
+        }]
+    }
+}
+
+pub fn population_stddev(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    stddev(values, false, name)
+}
+
+pub fn sample_stddev(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    stddev(values, true, name)
+}
+
+fn stddev(values: &[Value], sample: bool, name: &Tag) -> Result<Value, ShellError> {
+    let variance = compute_variance(values, sample, name)?;
+
+    match variance.sqrt() {
+        Some(deviation) => Ok(UntaggedValue::decimal(deviation).into_value(name)),
+        None => Err(ShellError::labeled_error(
+            "Cannot compute the square root of the variance",
+            "could not calculate the standard deviation",
+            name,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{population_stddev, SubCommand};
+    use nu_plugin::test_helpers::value::{decimal, int};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn computes_the_population_standard_deviation() {
+        let values = vec![
+            int(2),
+            int(4),
+            int(4),
+            int(4),
+            int(5),
+            int(5),
+            int(7),
+            int(9),
+        ];
+
+        let actual = population_stddev(&values, &Tag::unknown()).unwrap();
+        assert_eq!(actual, decimal(2));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, Dictionary, UnspannedPathMember, UntaggedValue, Value};
+use nu_value_ext::ValueExt;
+
+use indexmap::map::IndexMap;
+use num_bigint::BigInt;
+
+pub type MathFunction = fn(values: &[Value], tag: &Tag) -> Result<Value, ShellError>;
+
+/// Applies a math function to the collected input.
+///
+/// Given column paths, the function is applied to the values found at each path. Otherwise a
+/// list of primitives is aggregated into one value and a table is aggregated column by column.
+pub fn calculate(
+    values: &[Value],
+    column_paths: &[ColumnPath],
+    name: &Tag,
+    mf: MathFunction,
+) -> Result<Value, ShellError> {
+    if !column_paths.is_empty() {
+        let mut column_totals = IndexMap::new();
+
+        for path in column_paths {
+            let column_values = values
+                .iter()
+                .map(|value| value.get_data_by_column_path(path, Box::new(move |(_, _, err)| err)))
+                .collect::<Result<Vec<Value>, ShellError>>()?;
+
+            let result = mf(&column_values, name)?;
+
+            if column_paths.len() == 1 {
+                return Ok(result);
+            }
+
+            column_totals.insert(column_name(path), result);
+        }
+
+        return Ok(UntaggedValue::Row(Dictionary {
+            entries: column_totals,
+        })
+        .into_value(name));
+    }
+
+    let all_primitives = values
+        .iter()
+        .all(|v| matches!(v.value, UntaggedValue::Primitive(_)));
+
+    if all_primitives {
+        mf(values, name)
+    } else {
+        let mut column_values = IndexMap::new();
+        for value in values {
+            if let UntaggedValue::Row(row_dict) = &value.value {
+                for (key, value) in row_dict.entries.iter() {
+                    column_values
+                        .entry(key.clone())
+                        .and_modify(|v: &mut Vec<Value>| v.push(value.clone()))
+                        .or_insert_with(|| vec![value.clone()]);
+                }
+            }
+        }
+
+        let mut column_totals = IndexMap::new();
+        for (col_name, col_vals) in column_values {
+            column_totals.insert(col_name, mf(&col_vals, name)?);
+        }
+
+        Ok(UntaggedValue::Row(Dictionary {
+            entries: column_totals,
+        })
+        .into_value(name))
+    }
+}
+
+fn column_name(path: &ColumnPath) -> String {
+    path.iter()
+        .map(|member| match &member.unspanned {
+            UnspannedPathMember::String(name) => name.clone(),
+            UnspannedPathMember::Int(index) => index.to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+/// Ten to the power of a non-negative exponent, as a `BigInt`.
+pub fn pow10(exponent: i64) -> BigInt {
+    num_traits::pow(BigInt::from(10), exponent.max(0) as usize)
+}
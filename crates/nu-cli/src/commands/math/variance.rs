@@ -0,0 +1,179 @@
+use crate::commands::math::utils::calculate;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use bigdecimal::FromPrimitive;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    sample: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "math variance"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("math variance")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally compute the variance of the values at the column paths",
+            )
+            .switch(
+                "sample",
+                "calculate the sample variance (divides by the number of values minus one)",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Finds the variance of the values."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+
+        let (Arguments { rest, sample }, mut input) = args.process(&registry).await?;
+        let values: Vec<Value> = input.drain_vec().await;
+
+        let result = if sample {
+            calculate(&values, &rest, &name, sample_variance)?
+        } else {
+            calculate(&values, &rest, &name, population_variance)?
+        };
+
+        Ok(OutputStream::one(ReturnSuccess::value(result)))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the variance of a list of numbers",
+                example: "echo [2 4 4 4 5 5 7 9] | math variance",
+                result: Some(vec![UntaggedValue::decimal(4).into()]),
+            },
+            Example {
+                description: "Get the sample variance of a list of numbers",
+                example: "echo [1 2 3 4 5] | math variance --sample",
+                result: Some(vec![UntaggedValue::decimal(2.5).into()]),
+            },
+        ]
+    }
+}
+
+pub fn population_variance(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    Ok(UntaggedValue::decimal(compute_variance(values, false, name)?).into_value(name))
+}
+
+pub fn sample_variance(values: &[Value], name: &Tag) -> Result<Value, ShellError> {
+    Ok(UntaggedValue::decimal(compute_variance(values, true, name)?).into_value(name))
+}
+
+/// Divides the sum of squared deviations from the mean by the number of values, or by the
+/// number of values minus one for the sample variance.
+pub fn compute_variance(
+    values: &[Value],
+    sample: bool,
+    name: &Tag,
+) -> Result<BigDecimal, ShellError> {
+    if values.is_empty() {
+        return Err(ShellError::labeled_error(
+            "Cannot compute the variance of an empty list",
+            "needs at least one value",
+            name,
+        ));
+    }
+
+    if sample && values.len() < 2 {
+        return Err(ShellError::labeled_error(
+            "Cannot compute the sample variance of a single value",
+            "needs at least two values",
+            name,
+        ));
+    }
+
+    let numbers = values
+        .iter()
+        .map(|value| match &value.value {
+            UntaggedValue::Primitive(Primitive::Int(int)) => Ok(BigDecimal::from(int.clone())),
+            UntaggedValue::Primitive(Primitive::Decimal(decimal)) => Ok(decimal.clone()),
+            _ => Err(ShellError::labeled_error(
+                "Attempted to compute the variance of a value that is not a number.",
+                "value appears here",
+                value.tag.span,
+            )),
+        })
+        .collect::<Result<Vec<BigDecimal>, ShellError>>()?;
+
+    let count = BigDecimal::from_usize(numbers.len()).expect("expected a usize-sized bigdecimal");
+
+    let total: BigDecimal = numbers.iter().fold(BigDecimal::from(0), |acc, n| acc + n);
+    let mean = total / &count;
+
+    let squared_deviations = numbers
+        .iter()
+        .fold(BigDecimal::from(0), |acc, n| acc + (n - &mean).square());
+
+    let divisor = if sample {
+        count - BigDecimal::from(1)
+    } else {
+        count
+    };
+
+    Ok(squared_deviations / divisor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute_variance, SubCommand};
+    use bigdecimal::BigDecimal;
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn computes_the_population_variance() {
+        let values = vec![
+            int(2),
+            int(4),
+            int(4),
+            int(4),
+            int(5),
+            int(5),
+            int(7),
+            int(9),
+        ];
+
+        let actual = compute_variance(&values, false, &Tag::unknown()).unwrap();
+        assert_eq!(actual, BigDecimal::from(4));
+    }
+
+    #[test]
+    fn errors_computing_the_sample_variance_of_a_single_value() {
+        assert!(compute_variance(&[int(1)], true, &Tag::unknown()).is_err());
+    }
+
+    #[test]
+    fn errors_given_values_that_are_not_numbers() {
+        assert!(compute_variance(&[int(1), string("a")], false, &Tag::unknown()).is_err());
+    }
+}
@@ -12,6 +12,7 @@ pub struct Merge;
 #[derive(Deserialize)]
 pub struct MergeArgs {
     block: Block,
+    strict: bool,
 }
 
 #[async_trait]
@@ -21,15 +22,21 @@ impl WholeStreamCommand for Merge {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("merge").required(
-            "block",
-            SyntaxShape::Block,
-            "the block to run and merge into the table",
-        )
+        Signature::build("merge")
+            .required(
+                "block",
+                SyntaxShape::Block,
+                "the block to run and merge into the table",
+            )
+            .switch(
+                "strict",
+                "error if the table and the block's table differ in length",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Merge a table."
+        "Merge a table row by row, with the block's columns overriding the table's."
     }
 
     async fn run(
@@ -57,6 +64,7 @@ fn merge(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
         let name_tag = raw_args.call_info.name_tag.clone();
         let (merge_args, mut input): (MergeArgs, _) = raw_args.process(&registry).await?;
         let block = merge_args.block;
+        let strict = merge_args.strict;
 
         let table: Option<Vec<Value>> = match run_block(&block,
                 &mut context,
@@ -64,7 +72,16 @@ fn merge(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
                 &scope.it,
                 &scope.vars,
                 &scope.env).await {
-            Ok(mut stream) => Some(stream.drain_vec().await),
+            Ok(mut stream) => {
+                let values = stream.drain_vec().await;
+
+                if let Some(error) = context.get_errors().first() {
+                    yield Err(error.clone());
+                    return;
+                }
+
+                Some(values)
+            }
             Err(err) => {
                 yield Err(err);
                 return;
@@ -74,7 +91,7 @@ fn merge(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
 
         let table = table.unwrap_or_else(|| vec![Value {
             value: UntaggedValue::row(IndexMap::default()),
-            tag: name_tag,
+            tag: name_tag.clone(),
         }]);
 
         let mut idx = 0;
@@ -92,16 +109,40 @@ fn merge(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
                         }
                     }
                 }
+                None if strict => {
+                    yield Err(length_mismatch(&name_tag));
+                    return;
+                }
                 None => yield ReturnSuccess::value(value),
             }
 
             idx += 1;
         }
+
+        // The rest of a longer block table is passed along as it is
+        if idx < table.len() {
+            if strict {
+                yield Err(length_mismatch(&name_tag));
+                return;
+            }
+
+            for value in table.into_iter().skip(idx) {
+                yield ReturnSuccess::value(value);
+            }
+        }
     };
 
     Ok(stream.to_output_stream())
 }
 
+fn length_mismatch(tag: &Tag) -> ShellError {
+    ShellError::labeled_error(
+        "Could not merge",
+        "the table and the block's table have different lengths",
+        tag,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::Merge;
@@ -0,0 +1,206 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{Dictionary, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+pub struct MoveColumn;
+
+#[derive(Deserialize)]
+pub struct Arguments {
+    rest: Vec<Tagged<String>>,
+    before: Option<Tagged<String>>,
+    after: Option<Tagged<String>>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for MoveColumn {
+    fn name(&self) -> &str {
+        "move"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("move")
+            .rest(SyntaxShape::String, "the columns to move")
+            .named(
+                "before",
+                SyntaxShape::String,
+                "the column the moved columns go in front of",
+                Some('b'),
+            )
+            .named(
+                "after",
+                SyntaxShape::String,
+                "the column the moved columns go behind",
+                Some('a'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Move columns before or after another column."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        move_columns(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Move the size column in front of the name column",
+                example: "ls | move size --before name",
+                result: None,
+            },
+            Example {
+                description: "Move the type and size columns to the end",
+                example: "ls | move type size --after modified",
+                result: None,
+            },
+        ]
+    }
+}
+
+enum Anchor {
+    Before(Tagged<String>),
+    After(Tagged<String>),
+}
+
+fn move_columns(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest: columns, before, after }, mut input) = args.process(&registry).await?;
+
+        let anchor = match (before, after) {
+            (Some(before), None) => Anchor::Before(before),
+            (None, Some(after)) => Anchor::After(after),
+            (Some(_), Some(_)) => {
+                yield Err(ShellError::labeled_error(
+                    "Too many anchors",
+                    "use either --before or --after, not both",
+                    &name,
+                ));
+                return;
+            }
+            (None, None) => {
+                yield Err(ShellError::labeled_error(
+                    "Missing anchor",
+                    "use --before or --after to say where the columns go",
+                    &name,
+                ));
+                return;
+            }
+        };
+
+        while let Some(item) = input.next().await {
+            match item {
+                Value {
+                    value: UntaggedValue::Row(row),
+                    tag,
+                } => match reorder(&row, &columns, &anchor) {
+                    Ok(row) => yield ReturnSuccess::value(UntaggedValue::Row(row).into_value(tag)),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                },
+                Value { tag, .. } => {
+                    yield Err(ShellError::labeled_error_with_secondary(
+                        "Expected a table",
+                        "requires table input",
+                        &name,
+                        "value originates from here",
+                        tag,
+                    ));
+                    return;
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+/// Rebuilds the row with the moved columns, kept in their original order, placed next to the anchor
+fn reorder(
+    row: &Dictionary,
+    columns: &[Tagged<String>],
+    anchor: &Anchor,
+) -> Result<Dictionary, ShellError> {
+    for column in columns {
+        if !row.entries.contains_key(&column.item) {
+            return Err(unknown_column(column));
+        }
+    }
+
+    let anchor_column = match anchor {
+        Anchor::Before(column) | Anchor::After(column) => column,
+    };
+
+    if !row.entries.contains_key(&anchor_column.item) {
+        return Err(unknown_column(anchor_column));
+    }
+
+    if columns
+        .iter()
+        .any(|column| column.item == anchor_column.item)
+    {
+        return Err(ShellError::labeled_error(
+            "Cannot move a column next to itself",
+            "this column is also being moved",
+            &anchor_column.tag,
+        ));
+    }
+
+    let is_moved = |key: &String| columns.iter().any(|column| &column.item == key);
+    let moved = row.entries.iter().filter(|(key, _)| is_moved(key));
+
+    let mut reordered = IndexMap::new();
+
+    for (key, value) in row.entries.iter().filter(|(key, _)| !is_moved(key)) {
+        if key == &anchor_column.item {
+            if let Anchor::After(_) = anchor {
+                reordered.insert(key.clone(), value.clone());
+            }
+
+            for (key, value) in moved.clone() {
+                reordered.insert(key.clone(), value.clone());
+            }
+
+            if let Anchor::Before(_) = anchor {
+                reordered.insert(key.clone(), value.clone());
+            }
+        } else {
+            reordered.insert(key.clone(), value.clone());
+        }
+    }
+
+    Ok(reordered.into())
+}
+
+fn unknown_column(column: &Tagged<String>) -> ShellError {
+    ShellError::labeled_error(
+        "Unknown column",
+        format!("there is no column named '{}'", column.item),
+        &column.tag,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MoveColumn;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(MoveColumn {})
+    }
+}
@@ -1,14 +1,17 @@
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
+use crate::data::value::range_bounds;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
-use nu_source::Tagged;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::{Tagged, TaggedItem};
+use num_traits::cast::ToPrimitive;
 
 #[derive(Deserialize)]
 struct NthArgs {
-    row_number: Tagged<u64>,
-    rest: Vec<Tagged<u64>>,
+    row_number: Value,
+    rest: Vec<Value>,
+    strict: bool,
 }
 
 pub struct Nth;
@@ -23,14 +26,19 @@ impl WholeStreamCommand for Nth {
         Signature::build("nth")
             .required(
                 "row number",
-                SyntaxShape::Int,
-                "the number of the row to return",
+                SyntaxShape::Any,
+                "the number of the row to return, or a range of rows like 1..3",
             )
             .rest(SyntaxShape::Any, "Optionally return more rows")
+            .switch(
+                "strict",
+                "error on row numbers past the end of the input instead of skipping them",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Return only the selected rows"
+        "Return only the selected rows, in the order they are asked for"
     }
 
     async fn run(
@@ -53,6 +61,16 @@ impl WholeStreamCommand for Nth {
                 example: "echo [first second third] | nth 0 2",
                 result: Some(vec![Value::from("first"), Value::from("third")]),
             },
+            Example {
+                description: "Get the third row and then the first",
+                example: "echo [first second third] | nth 2 0",
+                result: Some(vec![Value::from("third"), Value::from("first")]),
+            },
+            Example {
+                description: "Get a range of rows",
+                example: "echo [first second third fourth] | nth 1..2",
+                result: Some(vec![Value::from("second"), Value::from("third")]),
+            },
         ]
     }
 }
@@ -60,22 +78,41 @@ impl WholeStreamCommand for Nth {
 fn nth(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
     let stream = async_stream! {
-        let (NthArgs { row_number, rest: and_rows}, input) = args.process(&registry).await?;
-
-        let mut inp = input.enumerate();
-        while let Some((idx, item)) = inp.next().await {
-            let row_number = vec![row_number.clone()];
-
-            let row_numbers = vec![&row_number, &and_rows]
-                .into_iter()
-                .flatten()
-                .collect::<Vec<&Tagged<u64>>>();
-
-            if row_numbers
-                .iter()
-                .any(|requested| requested.item == idx as u64)
-            {
-                yield ReturnSuccess::value(item);
+        let (NthArgs { row_number, rest, strict }, input) = args.process(&registry).await?;
+
+        let mut requested = vec![];
+        for value in std::iter::once(&row_number).chain(rest.iter()) {
+            match row_numbers(value) {
+                Ok(row_numbers) => requested.push(row_numbers),
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        // Only as much of the input as the highest row number needs is read
+        let needed = requested
+            .iter()
+            .map(|rows| rows.item.0.max(rows.item.1).saturating_add(1))
+            .max()
+            .unwrap_or(0);
+        let rows: Vec<Value> = input.take(needed).collect().await;
+
+        for requested in requested {
+            let (from, to) = requested.item;
+
+            if strict && from.max(to) >= rows.len() {
+                yield Err(ShellError::labeled_error(
+                    "Row number too large",
+                    format!("the input only has {} rows", rows.len()),
+                    requested.tag,
+                ));
+                return;
+            }
+
+            for row in within(from, to, rows.len()) {
+                yield ReturnSuccess::value(rows[row].clone());
             }
         }
     };
@@ -83,9 +120,58 @@ fn nth(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, Sh
     Ok(stream.to_output_stream())
 }
 
+/// The first and last row numbers asked for, which are the same for a row number on its own
+fn row_numbers(value: &Value) -> Result<Tagged<(usize, usize)>, ShellError> {
+    if let UntaggedValue::Primitive(Primitive::Int(_)) = value.value {
+        let number = row_number(value)?;
+        return Ok((number.item, number.item).tagged(number.tag));
+    }
+
+    let (from, to) = range_bounds(value, None).map_err(|_| not_a_row_number(value))?;
+    let (from, to) = (row_number(&from)?, row_number(&to)?);
+
+    Ok((from.item, to.item).tagged(&value.tag))
+}
+
+/// The row numbers from first to last that are less than the number of rows, counting down if
+/// first is greater than last
+fn within(first: usize, last: usize, rows: usize) -> Vec<usize> {
+    if rows == 0 {
+        return vec![];
+    }
+
+    let end = rows - 1;
+
+    if first <= last {
+        (first..=last.min(end)).collect()
+    } else {
+        (last..=first.min(end)).rev().collect()
+    }
+}
+
+fn row_number(value: &Value) -> Result<Tagged<usize>, ShellError> {
+    let number = match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_usize(),
+        _ => None,
+    };
+
+    number
+        .map(|number| number.tagged(&value.tag))
+        .ok_or_else(|| not_a_row_number(value))
+}
+
+fn not_a_row_number(value: &Value) -> ShellError {
+    ShellError::labeled_error(
+        "Expected a row number",
+        "expected a positive integer or a range of them",
+        &value.tag,
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Nth;
+    use super::{row_numbers, within, Nth};
+    use nu_plugin::test_helpers::value::{int, string};
 
     #[test]
     fn examples_work_as_expected() {
@@ -93,4 +179,28 @@ mod tests {
 
         test_examples(Nth {})
     }
+
+    #[test]
+    fn counts_down_descending_ranges() {
+        let (first, last) = row_numbers(&string("3..1")).expect("valid range").item;
+        let numbers = within(first, last, 5);
+
+        assert_eq!(numbers, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn keeps_ranges_within_the_rows_read() {
+        let (first, last) = row_numbers(&string("0..999999999999999"))
+            .expect("valid range")
+            .item;
+
+        assert_eq!(within(first, last, 2), vec![0, 1]);
+        assert_eq!(within(5, 1, 3), vec![2, 1]);
+        assert!(within(3, 4, 3).is_empty());
+    }
+
+    #[test]
+    fn errors_on_negative_row_numbers() {
+        assert!(row_numbers(&int(-1)).is_err());
+    }
 }
@@ -46,11 +46,18 @@ impl WholeStreamCommand for Open {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Opens \"users.csv\" and creates a table from the data",
-            example: "open users.csv",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Opens \"users.csv\" and creates a table from the data",
+                example: "open users.csv",
+                result: None,
+            },
+            Example {
+                description: "Opens \"users.json\" as text, without parsing it",
+                example: "open users.json --raw",
+                result: None,
+            },
+        ]
     }
 }
 
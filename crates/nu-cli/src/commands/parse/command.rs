@@ -10,6 +10,7 @@ use regex::Regex;
 struct Arguments {
     pattern: Tagged<String>,
     regex: Tagged<bool>,
+    strict: bool,
 }
 
 pub struct Command;
@@ -28,6 +29,11 @@ impl WholeStreamCommand for Command {
                 "the pattern to match. Eg) \"{foo}: {bar}\"",
             )
             .switch("regex", "use full regex syntax for patterns", Some('r'))
+            .switch(
+                "strict",
+                "error on input that does not match the pattern instead of skipping it",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -48,7 +54,14 @@ pub async fn operate(
     registry: &CommandRegistry,
 ) -> Result<OutputStream, ShellError> {
     let name_tag = args.call_info.name_tag.clone();
-    let (Arguments { regex, pattern }, mut input) = args.process(&registry).await?;
+    let (
+        Arguments {
+            regex,
+            pattern,
+            strict,
+        },
+        mut input,
+    ) = args.process(registry).await?;
 
     let regex_pattern = if let Tagged { item: true, tag } = regex {
         Regex::new(&pattern.item)
@@ -67,9 +80,11 @@ pub async fn operate(
     while let Some(v) = input.next().await {
         match v.as_string() {
             Ok(s) => {
-                let results = regex_pattern.captures_iter(&s);
+                let mut matched = false;
+
+                for c in regex_pattern.captures_iter(&s) {
+                    matched = true;
 
-                for c in results {
                     let mut dict = TaggedDictBuilder::new(&v.tag);
 
                     for (column_name, cap) in columns.iter().zip(c.iter().skip(1)) {
@@ -79,6 +94,16 @@ pub async fn operate(
 
                     parsed.push_back(dict.into_value());
                 }
+
+                if strict && !matched {
+                    return Err(ShellError::labeled_error_with_secondary(
+                        "Could not parse input with the pattern",
+                        "input does not match the pattern",
+                        &name_tag,
+                        "value originated here",
+                        v.tag,
+                    ));
+                }
             }
             Err(_) => {
                 return Err(ShellError::labeled_error_with_secondary(
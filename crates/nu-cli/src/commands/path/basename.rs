@@ -0,0 +1,83 @@
+use super::operate;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+use std::path::Path;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "path basename"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path basename")
+            .switch(
+                "posix",
+                "use forward slashes as the separator on every platform",
+                Some('p'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally get the basename of paths by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "gets the last part of the path, or an empty string if there is none"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, basename)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the basename of a path",
+            example: "echo '/a/b/c.txt' | path basename",
+            result: Some(vec![Value::from("c.txt")]),
+        }]
+    }
+}
+
+fn basename(path: &Path) -> String {
+    match path.file_name() {
+        Some(part) => part.to_string_lossy().to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{basename, SubCommand};
+    use std::path::Path;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn gets_the_last_part() {
+        assert_eq!(basename(Path::new("/a/b/c.txt")), "c.txt");
+    }
+
+    #[test]
+    fn ignores_a_trailing_separator() {
+        assert_eq!(basename(Path::new("/a/b/")), "b");
+    }
+
+    #[test]
+    fn is_empty_for_the_root() {
+        assert_eq!(basename(Path::new("/")), "");
+    }
+}
@@ -0,0 +1,83 @@
+use super::operate;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+use std::path::Path;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "path dirname"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path dirname")
+            .switch(
+                "posix",
+                "use forward slashes as the separator on every platform",
+                Some('p'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally get the dirname of paths by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "gets the path without its last part, or an empty string if there is none"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, dirname)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the dirname of a path",
+            example: "echo '/a/b/c.txt' | path dirname",
+            result: Some(vec![Value::from("/a/b")]),
+        }]
+    }
+}
+
+fn dirname(path: &Path) -> String {
+    match path.parent() {
+        Some(part) => part.to_string_lossy().to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dirname, SubCommand};
+    use std::path::Path;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn gets_the_path_without_its_last_part() {
+        assert_eq!(dirname(Path::new("/a/b/c.txt")), "/a/b");
+    }
+
+    #[test]
+    fn is_empty_for_the_root() {
+        assert_eq!(dirname(Path::new("/")), "");
+    }
+
+    #[test]
+    fn is_empty_for_a_lone_name() {
+        assert_eq!(dirname(Path::new("c.txt")), "");
+    }
+}
@@ -0,0 +1,88 @@
+use super::operate;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+use std::path::Path;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "path extension"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path extension")
+            .switch(
+                "posix",
+                "use forward slashes as the separator on every platform",
+                Some('p'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally get the extension of paths by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "gets the extension of the path without the dot, or an empty string if there is none"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, extension)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the extension of a path",
+            example: "echo '/a/b/c.txt' | path extension",
+            result: Some(vec![Value::from("txt")]),
+        }]
+    }
+}
+
+fn extension(path: &Path) -> String {
+    match path.extension() {
+        Some(part) => part.to_string_lossy().to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extension, SubCommand};
+    use std::path::Path;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn gets_the_extension_without_the_dot() {
+        assert_eq!(extension(Path::new("/a/b/c.txt")), "txt");
+    }
+
+    #[test]
+    fn gets_only_the_last_extension() {
+        assert_eq!(extension(Path::new("c.tar.gz")), "gz");
+    }
+
+    #[test]
+    fn is_empty_without_an_extension() {
+        assert_eq!(extension(Path::new("/a/b/c")), "");
+    }
+
+    #[test]
+    fn is_empty_for_dotfiles() {
+        assert_eq!(extension(Path::new(".bashrc")), "");
+    }
+}
@@ -0,0 +1,97 @@
+use super::{as_path, separated};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use std::path::PathBuf;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<Tagged<String>>,
+    posix: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "path join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path join")
+            .switch(
+                "posix",
+                "use forward slashes as the separator on every platform",
+                Some('p'),
+            )
+            .rest(
+                SyntaxShape::String,
+                "more parts to join after the ones from the input",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "joins the input paths, followed by any given parts, into one path"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        join(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Join a list of parts into a path",
+                example: "echo ['/a' 'b'] | path join --posix",
+                result: Some(vec![Value::from("/a/b")]),
+            },
+            Example {
+                description: "Append parts to a path",
+                example: "echo '/a' | path join b c.txt --posix",
+                result: Some(vec![Value::from("/a/b/c.txt")]),
+            },
+        ]
+    }
+}
+
+async fn join(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (Arguments { rest, posix }, input) = args.process(&registry).await?;
+    let values: Vec<Value> = input.collect().await;
+
+    let mut path = PathBuf::new();
+
+    for value in &values {
+        path.push(as_path(value, &value.tag)?);
+    }
+
+    for part in &rest {
+        path.push(&part.item);
+    }
+
+    let joined = separated(path.to_string_lossy().to_string(), posix);
+
+    Ok(OutputStream::one(ReturnSuccess::value(
+        UntaggedValue::string(joined).into_value(name),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
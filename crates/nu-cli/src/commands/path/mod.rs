@@ -0,0 +1,110 @@
+mod basename;
+mod command;
+mod dirname;
+mod exists;
+mod extension;
+mod join;
+mod type_;
+
+pub use basename::SubCommand as PathBasename;
+pub use command::Command as PathCommand;
+pub use dirname::SubCommand as PathDirname;
+pub use exists::SubCommand as PathExists;
+pub use extension::SubCommand as PathExtension;
+pub use join::SubCommand as PathJoin;
+pub use type_::SubCommand as PathType;
+
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, UntaggedValue, Value};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    posix: bool,
+}
+
+pub fn operate(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+    path_operation: fn(&std::path::Path) -> String,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest, posix }, mut input) = args.process(&registry).await?;
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match action(&v, v.tag(), path_operation, posix) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, old.tag(), path_operation, posix)));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+pub fn action(
+    input: &Value,
+    tag: impl Into<Tag>,
+    path_operation: fn(&std::path::Path) -> String,
+    posix: bool,
+) -> Result<Value, ShellError> {
+    let tag = tag.into();
+    let path = as_path(input, &tag)?;
+
+    Ok(UntaggedValue::string(separated(path_operation(&path), posix)).into_value(tag))
+}
+
+pub fn as_path(input: &Value, tag: &Tag) -> Result<std::path::PathBuf, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => Ok(std::path::PathBuf::from(s)),
+        UntaggedValue::Primitive(Primitive::Path(p)) => Ok(p.clone()),
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not a path",
+                got,
+                tag.span,
+            ))
+        }
+    }
+}
+
+/// Uses forward slashes as the separator, whatever the platform's is, when asked to
+/// with --posix
+pub fn separated(path: String, posix: bool) -> String {
+    if posix && std::path::MAIN_SEPARATOR != '/' {
+        path.replace(std::path::MAIN_SEPARATOR, "/")
+    } else {
+        path
+    }
+}
@@ -0,0 +1,155 @@
+use super::as_path;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "follow-symlinks"))]
+    follow_symlinks: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "path type"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path type")
+            .switch(
+                "follow-symlinks",
+                "give the type of what symlinks point to instead of symlink",
+                Some('f'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally get the type of paths by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "gets the type of paths: file, dir, symlink or other"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the type of a path",
+                example: "echo 'Cargo.toml' | path type",
+                result: None,
+            },
+            Example {
+                description: "Get the type of the files listed",
+                example: "ls | path type name",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest, follow_symlinks }, mut input) = args.process(&registry).await?;
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match action(&v, follow_symlinks, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, follow_symlinks, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn action(input: &Value, follow_symlinks: bool, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    let tag = tag.into();
+    let path = as_path(input, &tag)?;
+
+    let metadata = if follow_symlinks {
+        std::fs::metadata(&path)
+    } else {
+        std::fs::symlink_metadata(&path)
+    };
+
+    let metadata = metadata.map_err(|err| {
+        ShellError::labeled_error(
+            "Could not get the type of the path",
+            err.to_string(),
+            tag.span,
+        )
+    })?;
+
+    let file_type = metadata.file_type();
+
+    let name = if file_type.is_symlink() {
+        "symlink"
+    } else if file_type.is_dir() {
+        "dir"
+    } else if file_type.is_file() {
+        "file"
+    } else {
+        "other"
+    };
+
+    Ok(UntaggedValue::string(name).into_value(tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use nu_plugin::test_helpers::value::string;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn errors_for_missing_paths() {
+        assert!(action(&string("this/does/not/exist.txt"), false, Tag::unknown()).is_err());
+    }
+}
@@ -53,6 +53,26 @@ impl WholeStreamCommand for Pivot {
     ) -> Result<OutputStream, ShellError> {
         pivot(args, registry)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pivot a row into a table of column names and values",
+                example: r#"echo '{"a": 1, "b": 2}' | from json | pivot"#,
+                result: None,
+            },
+            Example {
+                description: "Pivot a row giving names to the new columns",
+                example: r#"echo '{"a": 1, "b": 2}' | from json | pivot key value"#,
+                result: None,
+            },
+            Example {
+                description: "Pivot rows with different columns, filling the gaps with nothing",
+                example: r#"echo '[{"a": 1}, {"b": 2}]' | from json | pivot"#,
+                result: None,
+            },
+        ]
+    }
 }
 
 pub fn pivot(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
@@ -66,7 +86,7 @@ pub fn pivot(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStre
 
         let mut headers: Vec<String> = vec![];
 
-        if args.rest.len() > 0 && args.header_row {
+        if !args.rest.is_empty() && args.header_row {
             yield Err(ShellError::labeled_error("Can not provide header names and use header row", "using header row", name));
             return;
         }
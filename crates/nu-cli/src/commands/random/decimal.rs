@@ -0,0 +1,164 @@
+use crate::commands::random::utils::{invalid_range, rng};
+use crate::commands::WholeStreamCommand;
+use crate::data::value::range_bounds;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use num_traits::cast::ToPrimitive;
+use rand::distributions::{Distribution, Uniform};
+
+#[derive(Deserialize)]
+struct Arguments {
+    range: Value,
+    max: Option<Value>,
+    seed: Option<Tagged<u64>>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "random decimal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("random decimal")
+            .required(
+                "range",
+                SyntaxShape::Any,
+                "the inclusive range, eg. 1..10 or '0.5..1.5', or the minimum when a maximum follows",
+            )
+            .optional(
+                "max",
+                SyntaxShape::Number,
+                "the inclusive maximum when a minimum is given",
+            )
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "seed the generator to get the same result every time",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "generate a random decimal within an inclusive range"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (Arguments { range, max, seed }, _) = args.process(&registry).await?;
+
+        let (min, max) = range_bounds(&range, max.as_ref())?;
+        let value = random_decimal(&min, &max, seed.map(|seed| seed.item))?;
+
+        Ok(OutputStream::one(ReturnSuccess::value(
+            value.into_value(range.tag),
+        )))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate a random decimal between 1 and 10",
+                example: "random decimal 1..10",
+                result: None,
+            },
+            Example {
+                description: "Generate a random decimal given a minimum and a maximum",
+                example: "random decimal 0.5 1.5",
+                result: None,
+            },
+            Example {
+                description: "Generate the same decimal on every run",
+                example: "random decimal 0..1 --seed 42",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn to_f64(value: &Value) -> Result<f64, ShellError> {
+    let number = match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(int)) => int.to_f64(),
+        UntaggedValue::Primitive(Primitive::Decimal(decimal)) => decimal.to_f64(),
+        _ => {
+            return Err(ShellError::labeled_error(
+                "expected a number",
+                "the bounds must be numbers",
+                value.tag.span,
+            ))
+        }
+    };
+
+    number.ok_or_else(|| {
+        ShellError::labeled_error(
+            "number out of range",
+            "does not fit in a 64-bit float",
+            value.tag.span,
+        )
+    })
+}
+
+fn random_decimal(
+    min: &Value,
+    max: &Value,
+    seed: Option<u64>,
+) -> Result<UntaggedValue, ShellError> {
+    let low = to_f64(min)?;
+    let high = to_f64(max)?;
+
+    if low > high {
+        return Err(invalid_range(min, max));
+    }
+
+    let value = Uniform::new_inclusive(low, high).sample(&mut rng(seed));
+
+    Ok(UntaggedValue::Primitive(value.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{random_decimal, SubCommand};
+    use nu_plugin::test_helpers::value::{decimal, int};
+    use nu_protocol::{Primitive, UntaggedValue};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn generates_decimals_within_the_range() {
+        for _ in 0..100 {
+            match random_decimal(&decimal(0.5), &int(2), None) {
+                Ok(UntaggedValue::Primitive(Primitive::Decimal(n))) => {
+                    let n: f64 = n.to_string().parse().unwrap();
+                    assert!((0.5..=2.0).contains(&n))
+                }
+                _ => panic!("expected a decimal"),
+            }
+        }
+    }
+
+    #[test]
+    fn generates_the_same_decimal_given_the_same_seed() {
+        let first = random_decimal(&int(0), &int(1), Some(42)).unwrap();
+        let second = random_decimal(&int(0), &int(1), Some(42)).unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn errors_given_a_minimum_greater_than_the_maximum() {
+        assert!(random_decimal(&decimal(1.5), &int(1), None).is_err());
+    }
+}
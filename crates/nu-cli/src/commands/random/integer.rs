@@ -0,0 +1,165 @@
+use crate::commands::random::utils::{invalid_range, rng};
+use crate::commands::WholeStreamCommand;
+use crate::data::value::range_bounds;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use num_traits::cast::ToPrimitive;
+use rand::distributions::{Distribution, Uniform};
+
+#[derive(Deserialize)]
+struct Arguments {
+    range: Value,
+    max: Option<Value>,
+    seed: Option<Tagged<u64>>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "random integer"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("random integer")
+            .required(
+                "range",
+                SyntaxShape::Any,
+                "the inclusive range, eg. 1..10, or the minimum when a maximum follows",
+            )
+            .optional(
+                "max",
+                SyntaxShape::Number,
+                "the inclusive maximum when a minimum is given",
+            )
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "seed the generator to get the same result every time",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "generate a random integer within an inclusive range"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let (Arguments { range, max, seed }, _) = args.process(&registry).await?;
+
+        let (min, max) = range_bounds(&range, max.as_ref())?;
+        let value = random_integer(&min, &max, seed.map(|seed| seed.item))?;
+
+        Ok(OutputStream::one(ReturnSuccess::value(
+            value.into_value(range.tag),
+        )))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate a random integer between 1 and 10",
+                example: "random integer 1..10",
+                result: None,
+            },
+            Example {
+                description: "Generate a random integer given a minimum and a maximum",
+                example: "random integer 1 10",
+                result: None,
+            },
+            Example {
+                description: "Generate the same integer on every run",
+                example: "random integer 1..10 --seed 42",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn to_i64(value: &Value) -> Result<i64, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(int)) => int.to_i64().ok_or_else(|| {
+            ShellError::labeled_error(
+                "integer out of range",
+                "does not fit in a 64-bit integer",
+                value.tag.span,
+            )
+        }),
+        _ => Err(ShellError::labeled_error(
+            "expected an integer",
+            "the bounds must be integers",
+            value.tag.span,
+        )),
+    }
+}
+
+fn random_integer(
+    min: &Value,
+    max: &Value,
+    seed: Option<u64>,
+) -> Result<UntaggedValue, ShellError> {
+    let low = to_i64(min)?;
+    let high = to_i64(max)?;
+
+    if low > high {
+        return Err(invalid_range(min, max));
+    }
+
+    let value = Uniform::new_inclusive(low, high).sample(&mut rng(seed));
+
+    Ok(UntaggedValue::int(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{random_integer, SubCommand};
+    use nu_plugin::test_helpers::value::int;
+    use nu_protocol::UntaggedValue;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn generates_integers_within_the_range() {
+        for _ in 0..100 {
+            match random_integer(&int(1), &int(3), None) {
+                Ok(UntaggedValue::Primitive(nu_protocol::Primitive::Int(n))) => {
+                    assert!(n >= 1.into() && n <= 3.into())
+                }
+                _ => panic!("expected an integer"),
+            }
+        }
+    }
+
+    #[test]
+    fn generates_the_same_integer_given_the_same_seed() {
+        let first = random_integer(&int(0), &int(1_000_000), Some(42)).unwrap();
+        let second = random_integer(&int(0), &int(1_000_000), Some(42)).unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn generates_the_bound_given_a_range_of_one_integer() {
+        let actual = random_integer(&int(7), &int(7), None).unwrap();
+
+        assert_eq!(actual, UntaggedValue::int(7));
+    }
+
+    #[test]
+    fn errors_given_a_minimum_greater_than_the_maximum() {
+        assert!(random_integer(&int(10), &int(1), None).is_err());
+    }
+}
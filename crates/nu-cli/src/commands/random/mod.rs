@@ -0,0 +1,10 @@
+mod command;
+mod decimal;
+mod integer;
+pub(crate) mod utils;
+mod uuid;
+
+pub use self::uuid::SubCommand as RandomUUID;
+pub use command::Command as Random;
+pub use decimal::SubCommand as RandomDecimal;
+pub use integer::SubCommand as RandomInteger;
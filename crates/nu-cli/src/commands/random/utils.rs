@@ -0,0 +1,30 @@
+use nu_errors::ShellError;
+use nu_protocol::Value;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+pub fn invalid_range(min: &Value, max: &Value) -> ShellError {
+    if min.tag.span == max.tag.span {
+        return ShellError::labeled_error(
+            "invalid range",
+            "the minimum is greater than the maximum",
+            min.tag.span,
+        );
+    }
+
+    ShellError::labeled_error_with_secondary(
+        "invalid range",
+        "the minimum is greater than the maximum",
+        min.tag.span,
+        "the maximum is here",
+        max.tag.span,
+    )
+}
+
+/// Seeded generators give the same sequence every time, otherwise the OS entropy source is used
+pub fn rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
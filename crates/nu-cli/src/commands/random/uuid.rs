@@ -0,0 +1,110 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
+use nu_source::Tagged;
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+struct Arguments {
+    count: Option<Tagged<u64>>,
+    uppercase: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "random uuid"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("random uuid")
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "the number of uuids to generate (defaults to 1)",
+                Some('c'),
+            )
+            .switch("uppercase", "use uppercase hexadecimal digits", Some('u'))
+    }
+
+    fn usage(&self) -> &str {
+        "generate random v4 uuids"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let tag = args.call_info.name_tag.clone();
+        let (Arguments { count, uppercase }, _) = args.process(&registry).await?;
+
+        let count = count.map(|count| count.item).unwrap_or(1);
+
+        let uuids = (0..count).map(move |_| {
+            ReturnSuccess::value(UntaggedValue::string(uuid(uppercase)).into_value(&tag))
+        });
+
+        Ok(futures::stream::iter(uuids).to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate a uuid",
+                example: "random uuid",
+                result: None,
+            },
+            Example {
+                description: "Generate three uppercase uuids",
+                example: "random uuid --count 3 --uppercase",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn uuid(uppercase: bool) -> String {
+    let uuid = Uuid::new_v4().to_hyphenated().to_string();
+
+    if uppercase {
+        uuid.to_uppercase()
+    } else {
+        uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{uuid, SubCommand};
+    use regex::Regex;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn generates_lowercase_hyphenated_v4_uuids() {
+        let format =
+            Regex::new("^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$")
+                .unwrap();
+
+        assert!(format.is_match(&uuid(false)));
+    }
+
+    #[test]
+    fn generates_uppercase_uuids() {
+        let format =
+            Regex::new("^[0-9A-F]{8}-[0-9A-F]{4}-4[0-9A-F]{3}-[89AB][0-9A-F]{3}-[0-9A-F]{12}$")
+                .unwrap();
+
+        assert!(format.is_match(&uuid(true)));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+
+use futures::stream::once;
+use nu_errors::ShellError;
+use nu_protocol::{hir::Block, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct Reduce;
+
+#[derive(Deserialize)]
+pub struct ReduceArgs {
+    block: Block,
+    fold: Option<Value>,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Reduce {
+    fn name(&self) -> &str {
+        "reduce"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("reduce")
+            .required(
+                "block",
+                SyntaxShape::Math,
+                "the block to combine the accumulator ($acc) with each row ($it)",
+            )
+            .named(
+                "fold",
+                SyntaxShape::Any,
+                "the starting value of the accumulator (defaults to the first row)",
+                Some('f'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Combine the rows of the table into a single value by running a block on each one."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        reduce(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Sum the integers",
+                example: "echo [1 2 3] | reduce {$acc + $it}",
+                result: Some(vec![UntaggedValue::int(6).into()]),
+            },
+            Example {
+                description: "Multiply the integers starting from a given value",
+                example: "echo [1 2 3] | reduce --fold 10 {$acc * $it}",
+                result: Some(vec![UntaggedValue::int(60).into()]),
+            },
+        ]
+    }
+}
+
+async fn reduce(
+    raw_args: CommandArgs,
+    registry: &CommandRegistry,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let scope = raw_args.call_info.scope.clone();
+    let name = raw_args.call_info.name_tag.clone();
+    let mut context = Context::from_raw(&raw_args, &registry);
+    let (ReduceArgs { block, fold }, mut input) = raw_args.process(&registry).await?;
+
+    // Without a starting value the first row seeds the accumulator
+    let mut acc = match fold {
+        Some(fold) => fold,
+        None => match input.next().await {
+            Some(first) => first,
+            None => {
+                return Err(ShellError::labeled_error(
+                    "Expected input",
+                    "needs input to reduce when no --fold is given",
+                    name,
+                ))
+            }
+        },
+    };
+
+    while let Some(item) = input.next().await {
+        let mut vars = scope.vars.clone();
+        vars.insert("$acc".to_string(), acc);
+
+        let for_block = item.clone();
+        let input_stream = once(async { Ok(for_block) }).to_input_stream();
+
+        let mut result =
+            run_block(&block, &mut context, input_stream, &item, &vars, &scope.env).await?;
+        let values = result.drain_vec().await;
+
+        if let Some(error) = context.get_errors().first() {
+            return Err(error.clone());
+        }
+
+        acc = match values.as_slice() {
+            [] => UntaggedValue::nothing().into_value(&item.tag),
+            [Value {
+                value: UntaggedValue::Error(error),
+                ..
+            }] => return Err(error.clone()),
+            [value] => value.clone(),
+            _ => UntaggedValue::table(&values).into_value(&item.tag),
+        };
+    }
+
+    Ok(OutputStream::one(ReturnSuccess::value(acc)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reduce;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Reduce {})
+    }
+}
@@ -1,13 +1,16 @@
 use crate::commands::WholeStreamCommand;
-use crate::data::base::reject_fields;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
-use nu_source::Tagged;
+use nu_protocol::{
+    ColumnPath, PathMember, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder,
+    UnspannedPathMember, UntaggedValue, Value,
+};
+use nu_source::span_for_spanned_list;
 
 #[derive(Deserialize)]
 pub struct RejectArgs {
-    rest: Vec<Tagged<String>>,
+    rest: Vec<ColumnPath>,
+    strict: bool,
 }
 
 pub struct Reject;
@@ -19,7 +22,16 @@ impl WholeStreamCommand for Reject {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("reject").rest(SyntaxShape::String, "the names of columns to remove")
+        Signature::build("reject")
+            .switch(
+                "strict",
+                "error when a column to remove does not exist",
+                Some('s'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "the names (or column paths) of columns to remove",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -35,11 +47,18 @@ impl WholeStreamCommand for Reject {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Lists the files in a directory without showing the modified column",
-            example: "ls | reject modified",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Lists the files in a directory without showing the modified column",
+                example: "ls | reject modified",
+                result: None,
+            },
+            Example {
+                description: "Remove a nested column, keeping the rest of its row",
+                example: r#"echo '{"name": "Ada", "address": {"city": "London", "zip": "N1"}}' | from json | reject address.zip"#,
+                result: None,
+            },
+        ]
     }
 }
 
@@ -47,7 +66,7 @@ fn reject(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream,
     let registry = registry.clone();
     let stream = async_stream! {
         let name = args.call_info.name_tag.clone();
-        let (RejectArgs { rest: fields }, mut input) = args.process(&registry).await?;
+        let (RejectArgs { rest: fields, strict }, mut input) = args.process(&registry).await?;
         if fields.is_empty() {
             yield Err(ShellError::labeled_error(
                 "Reject requires fields",
@@ -57,19 +76,95 @@ fn reject(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream,
             return;
         }
 
-        let fields: Vec<_> = fields.iter().map(|f| f.item.clone()).collect();
-
         while let Some(item) = input.next().await {
-            yield ReturnSuccess::value(reject_fields(&item, &fields, &item.tag));
+            let mut out = item;
+
+            for field in &fields {
+                match reject_path(&out, field.members()) {
+                    Some(rejected) => out = rejected,
+                    None if strict => {
+                        yield Err(ShellError::labeled_error(
+                            "Unknown column",
+                            "there isn't a column to remove here",
+                            span_for_spanned_list(field.members().iter().map(|p| p.span)),
+                        ));
+                        return;
+                    }
+                    None => {}
+                }
+            }
+
+            yield ReturnSuccess::value(out);
         }
     };
 
     Ok(stream.to_output_stream())
 }
 
+/// Removes the column at the end of the path, giving back nothing when the path can't be found.
+/// Tables along the way have the rest of the path removed from each of their rows.
+fn reject_path(value: &Value, path: &[PathMember]) -> Option<Value> {
+    let (first, rest) = path.split_first()?;
+
+    match &value.value {
+        UntaggedValue::Row(dict) => {
+            let column = match &first.unspanned {
+                UnspannedPathMember::String(column) => column,
+                UnspannedPathMember::Int(_) => return None,
+            };
+
+            let found = dict.entries.get(column)?;
+
+            let replacement = if rest.is_empty() {
+                None
+            } else {
+                Some(reject_path(found, rest)?)
+            };
+
+            let mut out = TaggedDictBuilder::new(&value.tag);
+
+            for (key, cell) in dict.entries.iter() {
+                if key != column {
+                    out.insert_value(key, cell.clone());
+                } else if let Some(replacement) = &replacement {
+                    out.insert_value(key, replacement.clone());
+                }
+            }
+
+            Some(out.into_value())
+        }
+        UntaggedValue::Table(rows) => {
+            let mut found = false;
+
+            let rows: Vec<_> = rows
+                .iter()
+                .map(|row| match reject_path(row, path) {
+                    Some(rejected) => {
+                        found = true;
+                        rejected
+                    }
+                    None => row.clone(),
+                })
+                .collect();
+
+            if found {
+                Some(UntaggedValue::Table(rows).into_value(&value.tag))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Reject;
+    use super::{reject_path, Reject};
+    use indexmap::indexmap;
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::{PathMember, UntaggedValue};
+    use nu_source::Span;
+    use nu_value_ext::ValueExt;
 
     #[test]
     fn examples_work_as_expected() {
@@ -77,4 +172,43 @@ mod tests {
 
         test_examples(Reject {})
     }
+
+    #[test]
+    fn gives_back_nothing_when_the_column_is_missing() {
+        let row =
+            UntaggedValue::row(indexmap! {"name".into() => string("Ada")}).into_untagged_value();
+
+        assert_eq!(
+            reject_path(&row, &[PathMember::string("password", Span::unknown())]),
+            None
+        );
+    }
+
+    #[test]
+    fn removes_only_the_leaf_of_a_nested_path() {
+        let row = UntaggedValue::row(indexmap! {
+            "name".into() => string("Ada"),
+            "address".into() => UntaggedValue::row(indexmap! {
+                "city".into() => string("London"),
+                "zip".into() => string("N1"),
+            }).into_untagged_value(),
+        })
+        .into_untagged_value();
+
+        let path = vec![
+            PathMember::string("address", Span::unknown()),
+            PathMember::string("zip", Span::unknown()),
+        ];
+
+        let rejected = reject_path(&row, &path).unwrap();
+
+        assert_eq!(
+            rejected.data_descriptors(),
+            vec!["name".to_string(), "address".to_string()]
+        );
+        assert_eq!(
+            rejected.get_data("address").borrow().data_descriptors(),
+            vec!["city".to_string()]
+        );
+    }
 }
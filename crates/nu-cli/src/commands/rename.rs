@@ -3,14 +3,17 @@ use crate::prelude::*;
 use indexmap::IndexMap;
 use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
-use nu_source::Tagged;
+use nu_source::{Tag, Tagged};
 
 pub struct Rename;
 
 #[derive(Deserialize)]
 pub struct Arguments {
-    column_name: Tagged<String>,
+    column_name: Option<Tagged<String>>,
     rest: Vec<Tagged<String>>,
+    column: Option<Value>,
+    #[serde(rename(deserialize = "ignore-missing"))]
+    ignore_missing: bool,
 }
 
 #[async_trait]
@@ -21,12 +24,23 @@ impl WholeStreamCommand for Rename {
 
     fn signature(&self) -> Signature {
         Signature::build("rename")
-            .required(
+            .optional(
                 "column_name",
                 SyntaxShape::String,
                 "the new name for the first column",
             )
             .rest(SyntaxShape::String, "the new name for additional columns")
+            .named(
+                "column",
+                SyntaxShape::Any,
+                "the old:new name mappings, eg. name:title or [name:title size:bytes]",
+                Some('c'),
+            )
+            .switch(
+                "ignore-missing",
+                "skip mappings whose old name isn't a column instead of failing",
+                Some('i'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -53,19 +67,47 @@ impl WholeStreamCommand for Rename {
                 example: r#"echo "{a: 1, b: 2, c: 3}" | from json | rename spam eggs cars"#,
                 result: None,
             },
+            Example {
+                description: "Rename columns by their old names",
+                example: r#"echo "{a: 1, b: 2, c: 3}" | from json | rename --column [a:spam c:cars]"#,
+                result: None,
+            },
         ]
     }
 }
 
+struct Mapping {
+    old: String,
+    new: Tagged<String>,
+}
+
 pub fn rename(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
     let name = args.call_info.name_tag.clone();
     let stream = async_stream! {
-        let (Arguments { column_name, rest }, mut input) = args.process(&registry).await?;
-        let mut new_column_names = vec![vec![column_name]];
-        new_column_names.push(rest);
-
-        let new_column_names = new_column_names.into_iter().flatten().collect::<Vec<_>>();
+        let (Arguments { column_name, rest, column, ignore_missing }, mut input) = args.process(&registry).await?;
+
+        let mappings = match column {
+            Some(column) => match mappings(&column) {
+                Ok(mappings) => Some(mappings),
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        let new_column_names = column_name.into_iter().chain(rest).collect::<Vec<_>>();
+
+        if mappings.is_none() && new_column_names.is_empty() {
+            yield Err(ShellError::labeled_error(
+                "rename needs the new column names",
+                "give the new names or a --column mapping",
+                &name,
+            ));
+            return;
+        }
 
         while let Some(item) = input.next().await {
             if let Value {
@@ -73,16 +115,49 @@ pub fn rename(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
                 tag,
             } = item
             {
-                let mut renamed_row = IndexMap::new();
+                // Each key is paired with the tag of the new name given for it, if any
+                let mut keys: Vec<(String, Option<Tag>)> =
+                    row.entries.keys().map(|key| (key.clone(), None)).collect();
+
+                match &mappings {
+                    Some(mappings) => {
+                        for mapping in mappings {
+                            match row.entries.get_full(&mapping.old) {
+                                Some((idx, _, _)) => {
+                                    keys[idx] = (mapping.new.item.clone(), Some(mapping.new.tag.clone()))
+                                }
+                                None if ignore_missing => {}
+                                None => {
+                                    yield Err(ShellError::labeled_error(
+                                        "Unknown column",
+                                        format!("there is no column named '{}'", mapping.old),
+                                        &mapping.new.tag,
+                                    ));
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                    None => {
+                        for (key, new_name) in keys.iter_mut().zip(new_column_names.iter()) {
+                            *key = (new_name.item.clone(), Some(new_name.tag.clone()));
+                        }
+                    }
+                }
 
-                for (idx, (key, value)) in row.entries.iter().enumerate() {
-                    let key = if idx < new_column_names.len() {
-                        &new_column_names[idx].item
-                    } else {
-                        key
-                    };
+                let mut renamed_row = IndexMap::new();
 
-                    renamed_row.insert(key.clone(), value.clone());
+                for ((key, renamed_by), value) in keys.into_iter().zip(row.entries.values()) {
+                    if renamed_row.contains_key(&key) {
+                        yield Err(ShellError::labeled_error(
+                            "Column already exists",
+                            format!("renaming would overwrite the '{}' column", key),
+                            renamed_by.unwrap_or_else(|| name.clone()),
+                        ));
+                        return;
+                    }
+
+                    renamed_row.insert(key, value.clone());
                 }
 
                 let out = UntaggedValue::Row(renamed_row.into()).into_value(tag);
@@ -104,6 +179,30 @@ pub fn rename(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
     Ok(stream.to_output_stream())
 }
 
+fn mappings(column: &Value) -> Result<Vec<Mapping>, ShellError> {
+    match &column.value {
+        UntaggedValue::Table(values) => values.iter().map(mapping).collect(),
+        _ => Ok(vec![mapping(column)?]),
+    }
+}
+
+fn mapping(value: &Value) -> Result<Mapping, ShellError> {
+    let text = value.as_string()?;
+    let mut parts = text.splitn(2, ':');
+
+    match (parts.next(), parts.next()) {
+        (Some(old), Some(new)) if !old.is_empty() && !new.is_empty() => Ok(Mapping {
+            old: old.to_string(),
+            new: new.to_string().tagged(&value.tag),
+        }),
+        _ => Err(ShellError::labeled_error(
+            "Expected a column mapping",
+            "expected old:new",
+            &value.tag,
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Rename;
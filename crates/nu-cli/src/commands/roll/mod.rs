@@ -0,0 +1,12 @@
+mod command;
+mod down;
+mod left;
+mod right;
+mod up;
+mod utils;
+
+pub use command::Command as Roll;
+pub use down::SubCommand as RollDown;
+pub use left::SubCommand as RollLeft;
+pub use right::SubCommand as RollRight;
+pub use up::SubCommand as RollUp;
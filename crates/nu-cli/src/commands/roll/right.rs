@@ -0,0 +1,69 @@
+use crate::commands::roll::utils::{rotate_columns, Direction};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct Arguments {
+    by: Option<Tagged<u64>>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "roll right"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("roll right").named(
+            "by",
+            SyntaxShape::Int,
+            "the number of times to roll (defaults to 1)",
+            Some('b'),
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Rolls the columns right, moving the last columns to the start"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+
+        let (Arguments { by }, mut input) = args.process(&registry).await?;
+        let values: Vec<Value> = input.drain_vec().await;
+
+        let by = by.map(|by| by.item as usize).unwrap_or(1);
+        let rolled = rotate_columns(values, by, Direction::Backward);
+
+        Ok(futures::stream::iter(rolled.into_iter().map(ReturnSuccess::value)).to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Roll the columns right by one",
+            example: r#"echo '{"a": 1, "b": 2, "c": 3}' | from json | roll right"#,
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
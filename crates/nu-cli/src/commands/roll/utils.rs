@@ -0,0 +1,86 @@
+use nu_protocol::{merge_descriptors, TaggedDictBuilder, UntaggedValue, Value};
+
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+/// Rotates the items, wrapping the count around the number of items
+pub fn rotate<T>(items: &mut [T], by: usize, direction: Direction) {
+    if items.is_empty() {
+        return;
+    }
+
+    let by = by % items.len();
+
+    match direction {
+        Direction::Forward => items.rotate_left(by),
+        Direction::Backward => items.rotate_right(by),
+    }
+}
+
+/// Rotates the columns of every row using the same column order so all rows stay aligned
+pub fn rotate_columns(values: Vec<Value>, by: usize, direction: Direction) -> Vec<Value> {
+    let mut headers = merge_descriptors(&values);
+    rotate(&mut headers, by, direction);
+
+    values
+        .into_iter()
+        .map(|value| match &value.value {
+            UntaggedValue::Row(dict) => {
+                let mut out = TaggedDictBuilder::new(&value.tag);
+
+                for header in &headers {
+                    if let Some(cell) = dict.entries.get(header) {
+                        out.insert_value(header, cell.clone());
+                    }
+                }
+
+                out.into_value()
+            }
+            _ => value,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rotate, rotate_columns, Direction};
+    use indexmap::{indexmap, IndexMap};
+    use nu_plugin::test_helpers::value::int;
+    use nu_protocol::{UntaggedValue, Value};
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    #[test]
+    fn rotates_by_more_than_the_length() {
+        let mut items = vec![1, 2, 3];
+
+        rotate(&mut items, 4, Direction::Backward);
+
+        assert_eq!(items, vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn rotates_columns_of_every_row_alike() {
+        let values = vec![
+            row(indexmap! {"a".into() => int(1), "b".into() => int(2), "c".into() => int(3)}),
+            row(indexmap! {"c".into() => int(6), "a".into() => int(4)}),
+        ];
+
+        let rotated: Vec<_> = rotate_columns(values, 1, Direction::Forward)
+            .iter()
+            .map(|value| value.data_descriptors())
+            .collect();
+
+        assert_eq!(
+            rotated,
+            vec![
+                vec!["b".to_string(), "c".to_string(), "a".to_string()],
+                vec!["c".to_string(), "a".to_string()],
+            ]
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    merge_descriptors, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
+};
+use nu_source::{SpannedItem, Tagged, TaggedItem};
+use nu_value_ext::get_data_by_key;
+
+pub struct Rotate;
+
+#[derive(Deserialize)]
+pub struct RotateArgs {
+    rest: Vec<Tagged<String>>,
+    ccw: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Rotate {
+    fn name(&self) -> &str {
+        "rotate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("rotate")
+            .switch(
+                "ccw",
+                "rotate counter clockwise instead of clockwise",
+                Some('c'),
+            )
+            .rest(
+                SyntaxShape::String,
+                "the names to give columns once rotated",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Rotates the table a quarter turn clockwise, so columns become rows and rows become columns."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        rotate(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Rotate a table clockwise, the column names end up in the last column",
+                example: r#"echo '[{"a": 1, "b": 2}, {"a": 3, "b": 4}]' | from json | rotate"#,
+                result: None,
+            },
+            Example {
+                description:
+                    "Rotate a table counter clockwise, the column names end up in the first column",
+                example: r#"echo '[{"a": 1, "b": 2}, {"a": 3, "b": 4}]' | from json | rotate --ccw"#,
+                result: None,
+            },
+            Example {
+                description: "Rotate a table giving names to the new columns",
+                example: r#"echo '[{"a": 1, "b": 2}]' | from json | rotate value key"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+async fn rotate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (RotateArgs { rest, ccw }, input) = args.process(&registry).await?;
+    let input: Vec<Value> = input.collect().await;
+
+    if let Some(value) = input
+        .iter()
+        .find(|value| !matches!(value.value, UntaggedValue::Row(_)))
+    {
+        return Err(ShellError::labeled_error_with_secondary(
+            "Expected a table of rows to rotate",
+            "requires rows as input",
+            &name,
+            "value originates from here",
+            &value.tag,
+        ));
+    }
+
+    let descs = merge_descriptors(&input);
+
+    // One new column per input row, plus one for the old column names
+    let headers: Vec<Tagged<String>> = (0..=input.len())
+        .map(|i| match rest.get(i) {
+            Some(header) => header.clone(),
+            None => format!("Column{}", i).tagged(&name),
+        })
+        .collect();
+
+    for (idx, header) in headers.iter().enumerate() {
+        if headers[..idx].iter().any(|seen| seen.item == header.item) {
+            return Err(ShellError::labeled_error(
+                "Column already exists",
+                format!("the '{}' column is named more than once", header.item),
+                &header.tag,
+            ));
+        }
+    }
+
+    let headers: Vec<String> = headers.into_iter().map(|header| header.item).collect();
+
+    let rotated = rotated(&input, &descs, &headers, ccw, &name);
+
+    Ok(futures::stream::iter(rotated.into_iter().map(ReturnSuccess::value)).to_output_stream())
+}
+
+/// Turns each column into a row. Clockwise, the rows are read from the bottom up and
+/// the column name goes last, counter clockwise the columns are read from the right
+/// and the column name goes first.
+fn rotated(
+    input: &[Value],
+    descs: &[String],
+    headers: &[String],
+    ccw: bool,
+    tag: impl Into<Tag>,
+) -> Vec<Value> {
+    let tag = tag.into();
+
+    let descs: Vec<&String> = if ccw {
+        descs.iter().rev().collect()
+    } else {
+        descs.iter().collect()
+    };
+
+    descs
+        .into_iter()
+        .map(|desc| {
+            let mut cells: Vec<Value> = input
+                .iter()
+                .map(
+                    |row| match get_data_by_key(row, desc[..].spanned_unknown()) {
+                        Some(x) => x,
+                        None => UntaggedValue::nothing().into_value(&tag),
+                    },
+                )
+                .collect();
+
+            let title = UntaggedValue::string(desc.clone()).into_value(&tag);
+
+            if ccw {
+                cells.insert(0, title);
+            } else {
+                cells.reverse();
+                cells.push(title);
+            }
+
+            let mut dict = TaggedDictBuilder::new(&tag);
+
+            for (header, cell) in headers.iter().zip(cells) {
+                dict.insert_value(header.clone(), cell);
+            }
+
+            dict.into_value()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rotated, Rotate};
+    use indexmap::indexmap;
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_protocol::{UntaggedValue, Value};
+    use nu_source::Tag;
+
+    fn headers() -> Vec<String> {
+        vec!["Column0".into(), "Column1".into(), "Column2".into()]
+    }
+
+    fn table() -> Vec<Value> {
+        vec![
+            UntaggedValue::row(indexmap! {"a".into() => int(1), "b".into() => int(2)})
+                .into_untagged_value(),
+            UntaggedValue::row(indexmap! {"a".into() => int(3), "b".into() => int(4)})
+                .into_untagged_value(),
+        ]
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Rotate {})
+    }
+
+    #[test]
+    fn rotates_clockwise() {
+        let descs = vec!["a".to_string(), "b".to_string()];
+
+        let actual = rotated(&table(), &descs, &headers(), false, Tag::unknown());
+
+        assert_eq!(
+            actual,
+            vec![
+                UntaggedValue::row(indexmap! {
+                    "Column0".into() => int(3),
+                    "Column1".into() => int(1),
+                    "Column2".into() => string("a"),
+                })
+                .into_untagged_value(),
+                UntaggedValue::row(indexmap! {
+                    "Column0".into() => int(4),
+                    "Column1".into() => int(2),
+                    "Column2".into() => string("b"),
+                })
+                .into_untagged_value(),
+            ]
+        );
+    }
+
+    #[test]
+    fn rotates_counter_clockwise() {
+        let descs = vec!["a".to_string(), "b".to_string()];
+
+        let actual = rotated(&table(), &descs, &headers(), true, Tag::unknown());
+
+        assert_eq!(
+            actual,
+            vec![
+                UntaggedValue::row(indexmap! {
+                    "Column0".into() => string("b"),
+                    "Column1".into() => int(2),
+                    "Column2".into() => int(4),
+                })
+                .into_untagged_value(),
+                UntaggedValue::row(indexmap! {
+                    "Column0".into() => string("a"),
+                    "Column1".into() => int(1),
+                    "Column2".into() => int(3),
+                })
+                .into_untagged_value(),
+            ]
+        );
+    }
+}
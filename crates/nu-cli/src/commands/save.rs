@@ -3,6 +3,7 @@ use crate::prelude::*;
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
 pub struct Save;
@@ -127,6 +128,7 @@ macro_rules! process_binary_return_success {
 pub struct SaveArgs {
     path: Option<Tagged<PathBuf>>,
     raw: bool,
+    append: bool,
 }
 
 #[async_trait]
@@ -143,6 +145,11 @@ impl WholeStreamCommand for Save {
                 "treat values as-is rather than auto-converting based on file extension",
                 Some('r'),
             )
+            .switch(
+                "append",
+                "add to the end of the file instead of overwriting it",
+                Some('a'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -156,6 +163,21 @@ impl WholeStreamCommand for Save {
     ) -> Result<OutputStream, ShellError> {
         save(args, registry)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Save a string to a file as it is",
+                example: "echo 'started' | save --raw log.txt",
+                result: None,
+            },
+            Example {
+                description: "Add a line to the end of a log file",
+                example: "echo 'finished' | save --raw --append log.txt",
+                result: None,
+            },
+        ]
+    }
 }
 
 fn save(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
@@ -171,7 +193,7 @@ fn save(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
 
     let stream = async_stream! {
         let head = raw_args.call_info.args.head.clone();
-        let (SaveArgs { path, raw: save_raw }, mut input) = raw_args.process(&registry).await?;
+        let (SaveArgs { path, raw: save_raw, append }, mut input) = raw_args.process(&registry).await?;
         let input: Vec<Value> = input.collect().await;
         if path.is_none() {
             // If there is no filename, check the metadata for the anchor filename
@@ -248,13 +270,15 @@ fn save(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
                 } else {
                     process_unknown!('scope, input, name_tag)
                 }
+            } else if is_binary(&input) {
+                process_binary!('scope, input, name_tag)
             } else {
                 Ok(string_from(&input).into_bytes())
             };
         };
 
         match content {
-            Ok(save_data) => match std::fs::write(full_path, save_data) {
+            Ok(save_data) => match write(&full_path, &save_data, append) {
                 Ok(o) => o,
                 Err(e) => yield Err(ShellError::labeled_error(e.to_string(), "IO error while saving", name)),
             },
@@ -266,6 +290,29 @@ fn save(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStrea
     Ok(OutputStream::new(stream))
 }
 
+fn is_binary(input: &[Value]) -> bool {
+    matches!(
+        input.first(),
+        Some(Value {
+            value: UntaggedValue::Primitive(Primitive::Binary(_)),
+            ..
+        })
+    )
+}
+
+/// Writes the data to the file, creating it if needed. Appending keeps what the file already has.
+fn write(path: &Path, data: &[u8], append: bool) -> std::io::Result<()> {
+    if append {
+        std::fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(path)?
+            .write_all(data)
+    } else {
+        std::fs::write(path, data)
+    }
+}
+
 fn string_from(input: &[Value]) -> String {
     let mut save_data = String::new();
 
@@ -0,0 +1,212 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value};
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+
+#[derive(Deserialize)]
+struct SeqArgs {
+    start: Value,
+    end: Value,
+    step: Option<Value>,
+}
+
+pub struct Seq;
+
+#[async_trait]
+impl WholeStreamCommand for Seq {
+    fn name(&self) -> &str {
+        "seq"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("seq")
+            .required("start", SyntaxShape::Number, "the first number")
+            .required("end", SyntaxShape::Number, "the last number (included)")
+            .named(
+                "step",
+                SyntaxShape::Number,
+                "the distance between the numbers (default: 1)",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Generates a sequence of numbers from start to end, counting down if start is greater than end."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        seq(args, registry).await
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate the numbers from 1 to 5",
+                example: "seq 1 5",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(4).into(),
+                    UntaggedValue::int(5).into(),
+                ]),
+            },
+            Example {
+                description: "Generate every other number from 1 to 5",
+                example: "seq 1 5 --step 2",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(5).into(),
+                ]),
+            },
+            Example {
+                description: "Count down from 3 to 1",
+                example: "seq 3 1",
+                result: Some(vec![
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(1).into(),
+                ]),
+            },
+            Example {
+                description: "Generate decimals from 0 to 1",
+                example: "seq 0 1 --step 0.5",
+                result: Some(vec![
+                    UntaggedValue::decimal(0).into(),
+                    UntaggedValue::decimal(0.5).into(),
+                    UntaggedValue::decimal(1).into(),
+                ]),
+            },
+        ]
+    }
+}
+
+async fn seq(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let tag = args.call_info.name_tag.clone();
+    let (SeqArgs { start, end, step }, _) = args.process(&registry).await?;
+
+    if let Some(step) = &step {
+        if is_zero(step) {
+            return Err(ShellError::labeled_error(
+                "Step can not be zero",
+                "step must not be zero",
+                &step.tag,
+            ));
+        }
+    }
+
+    let is_decimal = [Some(&start), Some(&end), step.as_ref()]
+        .iter()
+        .flatten()
+        .any(|value| matches!(value.value, UntaggedValue::Primitive(Primitive::Decimal(_))));
+
+    if is_decimal {
+        let step = match &step {
+            Some(step) => as_decimal(step)?,
+            None => BigDecimal::one(),
+        };
+
+        let numbers = sequence(as_decimal(&start)?, as_decimal(&end)?, step)
+            .map(move |d| UntaggedValue::decimal(d).into_value(&tag));
+
+        Ok(futures::stream::iter(numbers).to_output_stream())
+    } else {
+        let step = match &step {
+            Some(step) => as_int(step)?,
+            None => BigInt::one(),
+        };
+
+        let numbers = sequence(as_int(&start)?, as_int(&end)?, step)
+            .map(move |i| UntaggedValue::int(i).into_value(&tag));
+
+        Ok(futures::stream::iter(numbers).to_output_stream())
+    }
+}
+
+/// Lazily counts from start to end. Only the size of the step is used, the direction comes from
+/// comparing start and end.
+fn sequence<T>(start: T, end: T, step: T) -> impl Iterator<Item = T>
+where
+    T: Clone + PartialOrd + Signed,
+{
+    let descending = start > end;
+    let step = if descending { -step.abs() } else { step.abs() };
+
+    std::iter::successors(Some(start), move |n| Some(n.clone() + step.clone())).take_while(
+        move |n| {
+            if descending {
+                *n >= end
+            } else {
+                *n <= end
+            }
+        },
+    )
+}
+
+fn is_zero(value: &Value) -> bool {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.is_zero(),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => d.is_zero(),
+        _ => false,
+    }
+}
+
+fn as_int(value: &Value) -> Result<BigInt, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => Ok(i.clone()),
+        other => Err(not_a_number(value, other)),
+    }
+}
+
+fn as_decimal(value: &Value) -> Result<BigDecimal, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(i)) => Ok(BigDecimal::new(i.clone(), 0)),
+        UntaggedValue::Primitive(Primitive::Decimal(d)) => Ok(d.clone()),
+        other => Err(not_a_number(value, other)),
+    }
+}
+
+fn not_a_number(value: &Value, other: &UntaggedValue) -> ShellError {
+    ShellError::labeled_error(
+        "value is not a number",
+        format!("got {}", other.type_name()),
+        value.tag.span,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sequence, Seq};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Seq {})
+    }
+
+    #[test]
+    fn uses_the_size_of_the_step_only() {
+        let down: Vec<i64> = sequence(5, 1, 2).collect();
+        let up: Vec<i64> = sequence(1, 5, -2).collect();
+
+        assert_eq!(down, vec![5, 3, 1]);
+        assert_eq!(up, vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn stops_before_passing_the_end() {
+        let numbers: Vec<i64> = sequence(0, 10, 4).collect();
+
+        assert_eq!(numbers, vec![0, 4, 8]);
+    }
+}
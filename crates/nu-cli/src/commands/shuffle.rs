@@ -1,22 +1,37 @@
+use crate::commands::random::utils::rng;
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, ReturnValue, Value};
+use nu_protocol::{ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
 
 use rand::seq::SliceRandom;
-use rand::thread_rng;
 
 pub struct Shuffle;
 
+#[derive(Deserialize)]
+struct ShuffleArgs {
+    seed: Option<Tagged<u64>>,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Shuffle {
     fn name(&self) -> &str {
         "shuffle"
     }
 
+    fn signature(&self) -> Signature {
+        Signature::build("shuffle").named(
+            "seed",
+            SyntaxShape::Int,
+            "seed the generator to get the same order every time",
+            Some('s'),
+        )
+    }
+
     fn usage(&self) -> &str {
-        "Shuffle rows randomly."
+        "Shuffle rows randomly. The whole input is collected before any row is shuffled out."
     }
 
     async fn run(
@@ -26,19 +41,38 @@ impl WholeStreamCommand for Shuffle {
     ) -> Result<OutputStream, ShellError> {
         shuffle(args, registry)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Shuffle the rows of a list",
+                example: "echo [1 2 3 4 5] | shuffle",
+                result: None,
+            },
+            Example {
+                description: "Shuffle the rows the same way every time",
+                example: "echo [1 2 3 4 5] | shuffle --seed 42",
+                result: Some(vec![
+                    UntaggedValue::int(4).into(),
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(5).into(),
+                ]),
+            },
+        ]
+    }
 }
 
-fn shuffle(args: CommandArgs, _registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+fn shuffle(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
     let stream = async_stream! {
-        let mut input = args.input;
+        let (ShuffleArgs { seed }, mut input) = args.process(&registry).await?;
         let mut values: Vec<Value> = input.collect().await;
 
-        let out = {
-            values.shuffle(&mut thread_rng());
-            values.clone()
-        };
+        values.shuffle(&mut rng(seed.map(|seed| seed.item)));
 
-        for val in out.into_iter() {
+        for val in values.into_iter() {
             yield ReturnSuccess::value(val);
         }
     };
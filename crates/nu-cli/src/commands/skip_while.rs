@@ -7,10 +7,10 @@ use nu_protocol::{
     hir::ClassifiedCommand, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
 };
 
-pub struct SkipWhile;
+pub struct SkipWhileHyphenated;
 
 #[async_trait]
-impl WholeStreamCommand for SkipWhile {
+impl WholeStreamCommand for SkipWhileHyphenated {
     fn name(&self) -> &str {
         "skip-while"
     }
@@ -34,39 +34,77 @@ impl WholeStreamCommand for SkipWhile {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        let registry = registry.clone();
-        let scope = args.call_info.scope.clone();
-        let stream = async_stream! {
-            let mut call_info = args.evaluate_once(&registry).await?;
+        skip_while(args, registry)
+    }
+}
 
-            let block = call_info.args.expect_nth(0)?.clone();
+pub struct SkipWhile;
 
-            let condition = match block {
-                Value {
-                    value: UntaggedValue::Block(block),
-                    tag,
-                } => {
-                    if block.block.len() != 1 {
-                        yield Err(ShellError::labeled_error(
-                            "Expected a condition",
-                            "expected a condition",
-                            tag,
-                        ));
-                        return;
-                    }
-                    match block.block[0].list.get(0) {
-                        Some(item) => match item {
-                            ClassifiedCommand::Expr(expr) => expr.clone(),
-                            _ => {
-                                yield Err(ShellError::labeled_error(
-                                    "Expected a condition",
-                                    "expected a condition",
-                                    tag,
-                                ));
-                                return;
-                            }
-                        },
-                        None => {
+#[async_trait]
+impl WholeStreamCommand for SkipWhile {
+    fn name(&self) -> &str {
+        "skip while"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("skip while")
+            .required(
+                "condition",
+                SyntaxShape::Math,
+                "the condition that must be met to continue skipping",
+            )
+            .filter()
+    }
+
+    fn usage(&self) -> &str {
+        "Skips rows from the start while the condition matches and passes on the rest."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        skip_while(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Skip the leading positive numbers",
+            example: "echo [1 2 3 0 4] | skip while {$it > 0}",
+            result: Some(vec![
+                UntaggedValue::int(0).into(),
+                UntaggedValue::int(4).into(),
+            ]),
+        }]
+    }
+}
+
+fn skip_while(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let scope = args.call_info.scope.clone();
+    let stream = async_stream! {
+        let mut call_info = args.evaluate_once(&registry).await?;
+
+        let block = call_info.args.expect_nth(0)?.clone();
+
+        let condition = match block {
+            Value {
+                value: UntaggedValue::Block(block),
+                tag,
+            } => {
+                if block.block.len() != 1 {
+                    yield Err(ShellError::labeled_error(
+                        "Expected a condition",
+                        "expected a condition",
+                        tag,
+                    ));
+                    return;
+                }
+                match block.block[0].list.get(0) {
+                    Some(item) => match item {
+                        ClassifiedCommand::Expr(expr) => expr.clone(),
+                        _ => {
                             yield Err(ShellError::labeled_error(
                                 "Expected a condition",
                                 "expected a condition",
@@ -74,54 +112,65 @@ impl WholeStreamCommand for SkipWhile {
                             ));
                             return;
                         }
+                    },
+                    None => {
+                        yield Err(ShellError::labeled_error(
+                            "Expected a condition",
+                            "expected a condition",
+                            tag,
+                        ));
+                        return;
                     }
                 }
-                Value { tag, .. } => {
-                    yield Err(ShellError::labeled_error(
-                        "Expected a condition",
-                        "expected a condition",
-                        tag,
-                    ));
-                    return;
-                }
-            };
+            }
+            Value { tag, .. } => {
+                yield Err(ShellError::labeled_error(
+                    "Expected a condition",
+                    "expected a condition",
+                    tag,
+                ));
+                return;
+            }
+        };
 
-            let mut skipping = true;
-            while let Some(item) = call_info.input.next().await {
-                let condition = condition.clone();
-                trace!("ITEM = {:?}", item);
-                let result =
-                    evaluate_baseline_expr(&*condition, &registry, &item, &scope.vars, &scope.env)
-                        .await;
-                trace!("RESULT = {:?}", result);
-
-                let return_value = match result {
-                    Ok(ref v) if v.is_true() => false,
-                    _ => true,
-                };
-
-                if return_value {
-                    skipping = false;
-                }
+        let mut skipping = true;
+        while let Some(item) = call_info.input.next().await {
+            if !skipping {
+                yield ReturnSuccess::value(item);
+                continue;
+            }
 
-                if !skipping {
-                    yield ReturnSuccess::value(item);
-                }
+            let condition = condition.clone();
+            trace!("ITEM = {:?}", item);
+            let result =
+                evaluate_baseline_expr(&*condition, &registry, &item, &scope.vars, &scope.env)
+                    .await;
+            trace!("RESULT = {:?}", result);
+
+            let return_value = match result {
+                Ok(ref v) if v.is_true() => false,
+                _ => true,
+            };
+
+            if return_value {
+                skipping = false;
+                yield ReturnSuccess::value(item);
             }
-        };
+        }
+    };
 
-        Ok(stream.to_output_stream())
-    }
+    Ok(stream.to_output_stream())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::SkipWhile;
+    use super::{SkipWhile, SkipWhileHyphenated};
 
     #[test]
     fn examples_work_as_expected() {
         use crate::examples::test as test_examples;
 
-        test_examples(SkipWhile {})
+        test_examples(SkipWhile {});
+        test_examples(SkipWhileHyphenated {})
     }
 }
@@ -1,15 +1,25 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{Primitive, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_source::Tagged;
 use nu_value_ext::get_data_by_key;
+use std::cmp::Ordering;
 
 pub struct SortBy;
 
 #[derive(Deserialize)]
 pub struct SortByArgs {
     rest: Vec<Tagged<String>>,
+    reverse: bool,
+    insensitive: bool,
+    natural: bool,
+}
+
+#[derive(Clone, Copy)]
+struct SortOptions {
+    insensitive: bool,
+    natural: bool,
 }
 
 #[async_trait]
@@ -19,7 +29,19 @@ impl WholeStreamCommand for SortBy {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("sort-by").rest(SyntaxShape::String, "the column(s) to sort by")
+        Signature::build("sort-by")
+            .switch("reverse", "sort in decreasing order", Some('r'))
+            .switch(
+                "insensitive",
+                "sort strings without regard to case",
+                Some('i'),
+            )
+            .switch(
+                "natural",
+                "sort numbers embedded in strings by their value, eg) file2 before file10",
+                Some('n'),
+            )
+            .rest(SyntaxShape::String, "the column(s) to sort by")
     }
 
     fn usage(&self) -> &str {
@@ -46,6 +68,25 @@ impl WholeStreamCommand for SortBy {
                     UntaggedValue::int(4).into(),
                 ]),
             },
+            Example {
+                description: "Sort list by decreasing value",
+                example: "echo [4 2 3 1] | sort-by --reverse",
+                result: Some(vec![
+                    UntaggedValue::int(4).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(1).into(),
+                ]),
+            },
+            Example {
+                description: "Sort strings comparing the numbers in them by value",
+                example: "echo [file2 file10 file1] | sort-by --natural",
+                result: Some(vec![
+                    UntaggedValue::string("file1").into(),
+                    UntaggedValue::string("file2").into(),
+                    UntaggedValue::string("file10").into(),
+                ]),
+            },
             Example {
                 description: "Sort output by increasing file size",
                 example: "ls | sort-by size",
@@ -67,7 +108,15 @@ async fn sort_by(
     let registry = registry.clone();
     let tag = args.call_info.name_tag.clone();
 
-    let (SortByArgs { rest }, mut input) = args.process(&registry).await?;
+    let (
+        SortByArgs {
+            rest,
+            reverse,
+            insensitive,
+            natural,
+        },
+        mut input,
+    ) = args.process(&registry).await?;
     let mut vec = input.drain_vec().await;
 
     if vec.is_empty() {
@@ -89,12 +138,25 @@ async fn sort_by(
         }
     }
 
+    let options = SortOptions {
+        insensitive,
+        natural,
+    };
+
+    let ordered = |ordering: Ordering| {
+        if reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    };
+
     match &vec[0] {
         Value {
             value: UntaggedValue::Primitive(_),
             ..
         } => {
-            vec.sort();
+            vec.sort_by(|left, right| ordered(compare_values(left, right, options)));
         }
         _ => {
             let calc_key = |item: &Value| {
@@ -102,7 +164,14 @@ async fn sort_by(
                     .map(|f| get_data_by_key(item, f.borrow_spanned()))
                     .collect::<Vec<Option<Value>>>()
             };
-            vec.sort_by_cached_key(calc_key);
+
+            let mut keyed: Vec<_> = vec
+                .into_iter()
+                .map(|item| (calc_key(&item), item))
+                .collect();
+            keyed.sort_by(|(left, _), (right, _)| ordered(compare_keys(left, right, options)));
+
+            vec = keyed.into_iter().map(|(_, item)| item).collect();
         }
     };
 
@@ -115,9 +184,111 @@ async fn sort_by(
     Ok(futures::stream::iter(values_vec_deque).to_output_stream())
 }
 
+fn compare_keys(left: &[Option<Value>], right: &[Option<Value>], options: SortOptions) -> Ordering {
+    for (left, right) in left.iter().zip(right) {
+        let ordering = match (left, right) {
+            (Some(left), Some(right)) => compare_values(left, right, options),
+            (left, right) => left.cmp(right),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+fn compare_values(left: &Value, right: &Value, options: SortOptions) -> Ordering {
+    match (string_of(left), string_of(right)) {
+        (Some(left), Some(right)) if options.insensitive || options.natural => {
+            let (left, right) = if options.insensitive {
+                (left.to_lowercase(), right.to_lowercase())
+            } else {
+                (left.to_string(), right.to_string())
+            };
+
+            if options.natural {
+                natural_compare(&left, &right)
+            } else {
+                left.cmp(&right)
+            }
+        }
+        _ => left.cmp(right),
+    }
+}
+
+fn string_of(value: &Value) -> Option<&str> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s))
+        | UntaggedValue::Primitive(Primitive::Line(s)) => Some(s),
+        _ => None,
+    }
+}
+
+/// Compares strings chunk by chunk, where runs of digits compare by their numeric value
+/// and everything else compares as text.
+fn natural_compare(left: &str, right: &str) -> Ordering {
+    let left_chunks = chunks(left);
+    let right_chunks = chunks(right);
+
+    for (l, r) in left_chunks
+        .iter()
+        .copied()
+        .zip(right_chunks.iter().copied())
+    {
+        let is_number = |chunk: &str| chunk.starts_with(|c: char| c.is_ascii_digit());
+
+        let ordering = if is_number(l) && is_number(r) {
+            let l_digits = l.trim_start_matches('0');
+            let r_digits = r.trim_start_matches('0');
+
+            l_digits
+                .len()
+                .cmp(&r_digits.len())
+                .then_with(|| l_digits.cmp(r_digits))
+        } else {
+            l.cmp(r)
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    left_chunks
+        .len()
+        .cmp(&right_chunks.len())
+        .then_with(|| left.cmp(right))
+}
+
+fn chunks(s: &str) -> Vec<&str> {
+    let mut chunks = vec![];
+    let mut start = 0;
+    let mut in_digits = None;
+
+    for (idx, c) in s.char_indices() {
+        let is_digit = c.is_ascii_digit();
+
+        if in_digits.is_some() && in_digits != Some(is_digit) {
+            chunks.push(&s[start..idx]);
+            start = idx;
+        }
+
+        in_digits = Some(is_digit);
+    }
+
+    if start < s.len() {
+        chunks.push(&s[start..]);
+    }
+
+    chunks
+}
+
 #[cfg(test)]
 mod tests {
-    use super::SortBy;
+    use super::{chunks, natural_compare, SortBy};
+    use std::cmp::Ordering;
 
     #[test]
     fn examples_work_as_expected() {
@@ -125,4 +296,22 @@ mod tests {
 
         test_examples(SortBy {})
     }
+
+    #[test]
+    fn chunks_digits_apart_from_text() {
+        assert_eq!(chunks("file10.txt"), vec!["file", "10", ".txt"]);
+    }
+
+    #[test]
+    fn compares_embedded_numbers_by_value() {
+        assert_eq!(natural_compare("file2", "file10"), Ordering::Less);
+        assert_eq!(natural_compare("file10", "file9"), Ordering::Greater);
+        assert_eq!(natural_compare("a1b2", "a1b10"), Ordering::Less);
+    }
+
+    #[test]
+    fn compares_text_as_text() {
+        assert_eq!(natural_compare("apple", "banana"), Ordering::Less);
+        assert_eq!(natural_compare("file", "file1"), Ordering::Less);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use indexmap::indexmap;
 use log::trace;
 use nu_errors::ShellError;
 use nu_protocol::{
@@ -45,6 +46,31 @@ impl WholeStreamCommand for SubCommand {
     ) -> Result<OutputStream, ShellError> {
         split_column(args, registry)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Split a string into columns by the specified separator",
+                example: "echo 'a--b--c' | split column '--'",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "Column1".to_string() => UntaggedValue::string("a").into(),
+                    "Column2".to_string() => UntaggedValue::string("b").into(),
+                    "Column3".to_string() => UntaggedValue::string("c").into(),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Split a string into named columns, filling in the ones missing",
+                example: "echo 'a,b' | split column ',' first second third",
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "first".to_string() => UntaggedValue::string("a").into(),
+                    "second".to_string() => UntaggedValue::string("b").into(),
+                    "third".to_string() => UntaggedValue::string("").into(),
+                })
+                .into()]),
+            },
+        ]
+    }
 }
 
 fn split_column(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
@@ -82,7 +108,9 @@ fn split_column(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputS
                     yield ReturnSuccess::value(dict.into_value());
                 } else {
                     let mut dict = TaggedDictBuilder::new(&v.tag);
-                    for (&k, v) in split_result.iter().zip(positional.iter()) {
+                    for (i, v) in positional.iter().enumerate() {
+                        // Rows with fewer fields than names get empty strings for the rest
+                        let k = split_result.get(i).copied().unwrap_or("");
                         dict.insert_untagged(
                             v,
                             UntaggedValue::Primitive(Primitive::String(k.into())),
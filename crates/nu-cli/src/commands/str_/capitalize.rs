@@ -7,6 +7,7 @@ use nu_protocol::{
 };
 use nu_source::Tag;
 use nu_value_ext::ValueExt;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Deserialize)]
 struct Arguments {
@@ -29,7 +30,7 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn usage(&self) -> &str {
-        "capitalizes text"
+        "capitalizes the first letter of text, leaving the rest unchanged"
     }
 
     async fn run(
@@ -96,17 +97,12 @@ fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
         | UntaggedValue::Primitive(Primitive::String(s)) => {
-            let mut capitalized = String::new();
+            let mut graphemes = s.graphemes(true);
 
-            for (idx, character) in s.chars().enumerate() {
-                let out = if idx == 0 {
-                    character.to_uppercase().to_string()
-                } else {
-                    character.to_lowercase().to_string()
-                };
-
-                capitalized.push_str(&out);
-            }
+            let capitalized = match graphemes.next() {
+                Some(first) => format!("{}{}", first.to_uppercase(), graphemes.as_str()),
+                None => String::new(),
+            };
 
             Ok(UntaggedValue::string(capitalized).into_value(tag))
         }
@@ -142,4 +138,31 @@ mod tests {
         let actual = action(&word, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn leaves_the_rest_unchanged() {
+        let word = string("hello WORLD");
+        let expected = string("Hello WORLD");
+
+        let actual = action(&word, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn uppercases_into_multiple_characters() {
+        let word = string("ßtraße");
+        let expected = string("SStraße");
+
+        let actual = action(&word, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn passes_empty_strings_through() {
+        let word = string("");
+        let expected = string("");
+
+        let actual = action(&word, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
 }
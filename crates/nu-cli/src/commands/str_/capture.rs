@@ -0,0 +1,177 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use indexmap::indexmap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::Tagged;
+
+use regex::Regex;
+
+#[derive(Deserialize)]
+struct Arguments {
+    pattern: Tagged<String>,
+    strict: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str capture"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str capture")
+            .required(
+                "pattern",
+                SyntaxShape::String,
+                "the regular expression, named groups like (?P<name>...) become columns",
+            )
+            .switch(
+                "strict",
+                "error on strings that don't match instead of skipping them",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "captures the groups of a regular expression into the columns of a row"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Capture the year and month of a date",
+                example: r#"echo "2020-01" | str capture '(?P<year>\d{4})-(?P<month>\d{2})'"#,
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "year".to_string() => Value::from("2020"),
+                    "month".to_string() => Value::from("01"),
+                })
+                .into()]),
+            },
+            Example {
+                description: "Unnamed groups are numbered",
+                example: r#"echo "key=value" | str capture '(\w+)=(\w+)'"#,
+                result: Some(vec![UntaggedValue::row(indexmap! {
+                    "capture1".to_string() => Value::from("key"),
+                    "capture2".to_string() => Value::from("value"),
+                })
+                .into()]),
+            },
+        ]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name_tag = args.call_info.name_tag.clone();
+
+    let stream = async_stream! {
+        let (Arguments { pattern, strict }, mut input) = args.process(&registry).await?;
+        let regex = match Regex::new(&pattern.item) {
+            Ok(regex) => regex,
+            Err(err) => {
+                yield Err(ShellError::labeled_error(
+                    "Invalid regular expression",
+                    err.to_string(),
+                    pattern.tag.span,
+                ));
+                return;
+            }
+        };
+        let columns = column_names(&regex);
+
+        while let Some(v) = input.next().await {
+            match v.as_string() {
+                Ok(s) => match capture(&regex, &columns, &s, &v.tag) {
+                    Some(row) => yield ReturnSuccess::value(row),
+                    None if strict => {
+                        yield Err(ShellError::labeled_error_with_secondary(
+                            "Could not capture from the input",
+                            "input does not match the pattern",
+                            &name_tag,
+                            "value originated here",
+                            v.tag,
+                        ));
+                        return;
+                    }
+                    None => {}
+                },
+                Err(_) => {
+                    yield Err(ShellError::labeled_error_with_secondary(
+                        "Expected string input",
+                        "expected string input",
+                        &name_tag,
+                        "value originated here",
+                        v.tag,
+                    ));
+                    return;
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn column_names(regex: &Regex) -> Vec<String> {
+    regex
+        .capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(i, name)| {
+            name.map(String::from)
+                .unwrap_or_else(|| format!("capture{}", i))
+        })
+        .collect()
+}
+
+fn capture(regex: &Regex, columns: &[String], input: &str, tag: &Tag) -> Option<Value> {
+    let captures = regex.captures(input)?;
+    let mut row = TaggedDictBuilder::new(tag);
+
+    for (column, group) in columns.iter().zip(captures.iter().skip(1)) {
+        let text = group.map(|m| m.as_str()).unwrap_or("");
+        row.insert_untagged(column, UntaggedValue::string(text));
+    }
+
+    Some(row.into_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{capture, column_names, SubCommand};
+    use nu_source::Tag;
+    use regex::Regex;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn numbers_unnamed_groups_by_position() {
+        let regex = Regex::new(r"(?P<key>\w+)=(\w+)").expect("valid regex");
+
+        assert_eq!(column_names(&regex), vec!["key", "capture2"]);
+    }
+
+    #[test]
+    fn does_not_capture_from_strings_that_do_not_match() {
+        let regex = Regex::new(r"(?P<year>\d{4})").expect("valid regex");
+        let columns = column_names(&regex);
+
+        assert!(capture(&regex, &columns, "no year here", &Tag::unknown()).is_none());
+    }
+}
@@ -0,0 +1,93 @@
+use super::{capitalize, operate, words};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str camel-case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str camel-case").rest(
+            SyntaxShape::ColumnPath,
+            "optionally convert text to camelCase by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "converts text to camelCase"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, to_camel_case)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "convert a string to camelCase",
+                example: "echo 'NuShell' | str camel-case",
+                result: Some(vec![Value::from("nuShell")]),
+            },
+            Example {
+                description: "convert a snake_case string to camelCase",
+                example: "echo 'this_is_the_first_case' | str camel-case",
+                result: Some(vec![Value::from("thisIsTheFirstCase")]),
+            },
+        ]
+    }
+}
+
+fn to_camel_case(s: &str) -> String {
+    words(s)
+        .iter()
+        .enumerate()
+        .map(|(idx, word)| {
+            if idx == 0 {
+                word.to_lowercase()
+            } else {
+                capitalize(word)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_camel_case, SubCommand};
+    use crate::commands::str_::case::action;
+    use nu_plugin::test_helpers::value::string;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn camel_cases_words() {
+        let word = string("hello world");
+        let expected = string("helloWorld");
+
+        let actual = action(&word, Tag::unknown(), to_camel_case).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn camel_cases_acronyms() {
+        assert_eq!(to_camel_case("HTTPServer"), "httpServer");
+        assert_eq!(to_camel_case("get-HTTP-response2"), "getHttpResponse2");
+        assert_eq!(to_camel_case("XMLHttpRequest"), "xmlHttpRequest");
+    }
+}
@@ -0,0 +1,87 @@
+use super::{operate, words};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str kebab-case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str kebab-case").rest(
+            SyntaxShape::ColumnPath,
+            "optionally convert text to kebab-case by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "converts text to kebab-case"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, to_kebab_case)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "convert a string to kebab-case",
+                example: "echo 'NuShell' | str kebab-case",
+                result: Some(vec![Value::from("nu-shell")]),
+            },
+            Example {
+                description: "convert a snake_case string to kebab-case",
+                example: "echo 'this_is_the_first_case' | str kebab-case",
+                result: Some(vec![Value::from("this-is-the-first-case")]),
+            },
+        ]
+    }
+}
+
+fn to_kebab_case(s: &str) -> String {
+    words(s)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_kebab_case, SubCommand};
+    use crate::commands::str_::case::action;
+    use nu_plugin::test_helpers::value::string;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn kebab_cases_words() {
+        let word = string("hello world");
+        let expected = string("hello-world");
+
+        let actual = action(&word, Tag::unknown(), to_kebab_case).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn kebab_cases_acronyms() {
+        assert_eq!(to_kebab_case("getHTTPResponse"), "get-http-response");
+        assert_eq!(to_kebab_case("HTTP2Server"), "http2-server");
+        assert_eq!(to_kebab_case("XMLHttpRequest"), "xml-http-request");
+    }
+}
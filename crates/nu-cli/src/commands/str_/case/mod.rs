@@ -0,0 +1,175 @@
+mod camel_case;
+mod kebab_case;
+mod snake_case;
+
+pub use camel_case::SubCommand as StrCamelCase;
+pub use kebab_case::SubCommand as StrKebabCase;
+pub use snake_case::SubCommand as StrSnakeCase;
+
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{ColumnPath, Primitive, ReturnSuccess, UntaggedValue, Value};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub fn operate(
+    args: CommandArgs,
+    registry: &CommandRegistry,
+    case_operation: fn(&str) -> String,
+) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+
+        let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
+
+        while let Some(v) = input.next().await {
+            if column_paths.is_empty() {
+                match action(&v, v.tag(), case_operation) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+
+                let mut ret = v.clone();
+
+                for path in &column_paths {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, old.tag(), case_operation)));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+pub fn action(
+    input: &Value,
+    tag: impl Into<Tag>,
+    case_operation: fn(&str) -> String,
+) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            Ok(UntaggedValue::string(case_operation(s)).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+/// Splits text into words at spaces, underscores, hyphens and changes of case.
+///
+/// A run of capitals stays together as an acronym, so "HTTPServer" becomes "HTTP" and "Server",
+/// and digits stay with the letters before them.
+pub fn words(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut words = vec![];
+    let mut word = String::new();
+
+    for (idx, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c.is_whitespace() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() {
+            if let Some(prev) = word.chars().last() {
+                let next_is_lowercase = chars.get(idx + 1).is_some_and(|n| n.is_lowercase());
+
+                if !prev.is_uppercase() || next_is_lowercase {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+        }
+
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+pub fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::words;
+
+    #[test]
+    fn splits_at_separators() {
+        assert_eq!(words("hello world"), vec!["hello", "world"]);
+        assert_eq!(words("hello_world"), vec!["hello", "world"]);
+        assert_eq!(words("hello-world"), vec!["hello", "world"]);
+        assert_eq!(words("  hello  __world-- "), vec!["hello", "world"]);
+    }
+
+    #[test]
+    fn splits_at_changes_of_case() {
+        assert_eq!(words("helloWorld"), vec!["hello", "World"]);
+        assert_eq!(words("HelloWorld"), vec!["Hello", "World"]);
+    }
+
+    #[test]
+    fn keeps_acronyms_together() {
+        assert_eq!(words("HTTPServer"), vec!["HTTP", "Server"]);
+        assert_eq!(words("getHTTPResponse"), vec!["get", "HTTP", "Response"]);
+        assert_eq!(words("useHTTP"), vec!["use", "HTTP"]);
+    }
+
+    #[test]
+    fn splits_an_acronym_before_its_last_capital() {
+        assert_eq!(words("XMLHttpRequest"), vec!["XML", "Http", "Request"]);
+        assert_eq!(words("parseJSONValue"), vec!["parse", "JSON", "Value"]);
+    }
+
+    #[test]
+    fn keeps_digit_groups() {
+        assert_eq!(words("version2Update"), vec!["version2", "Update"]);
+        assert_eq!(words("HTTP2Server"), vec!["HTTP2", "Server"]);
+        assert_eq!(words("ipv6_address_128"), vec!["ipv6", "address", "128"]);
+    }
+}
@@ -0,0 +1,87 @@
+use super::{operate, words};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Signature, SyntaxShape, Value};
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str snake-case"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str snake-case").rest(
+            SyntaxShape::ColumnPath,
+            "optionally convert text to snake_case by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "converts text to snake_case"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry, to_snake_case)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "convert a string to snake_case",
+                example: "echo 'NuShell' | str snake-case",
+                result: Some(vec![Value::from("nu_shell")]),
+            },
+            Example {
+                description: "convert a kebab-case string to snake_case",
+                example: "echo 'this-is-the-first-case' | str snake-case",
+                result: Some(vec![Value::from("this_is_the_first_case")]),
+            },
+        ]
+    }
+}
+
+fn to_snake_case(s: &str) -> String {
+    words(s)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_snake_case, SubCommand};
+    use crate::commands::str_::case::action;
+    use nu_plugin::test_helpers::value::string;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn snake_cases_words() {
+        let word = string("hello world");
+        let expected = string("hello_world");
+
+        let actual = action(&word, Tag::unknown(), to_snake_case).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn snake_cases_acronyms() {
+        assert_eq!(to_snake_case("getHTTPResponse"), "get_http_response");
+        assert_eq!(to_snake_case("HTTP2Server"), "http2_server");
+        assert_eq!(to_snake_case("XMLHttpRequest"), "xml_http_request");
+    }
+}
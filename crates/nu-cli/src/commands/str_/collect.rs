@@ -0,0 +1,137 @@
+use crate::commands::WholeStreamCommand;
+use crate::data::value::format_leaf;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct Arguments {
+    separator: Option<Tagged<String>>,
+    strict: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str collect"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str collect")
+            .optional(
+                "separator",
+                SyntaxShape::String,
+                "the separator to put between each value",
+            )
+            .switch(
+                "strict",
+                "error on values that are not strings instead of joining their display text",
+                Some('s'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "joins the input stream into a single string"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+
+        let (Arguments { separator, strict }, mut input) = args.process(&registry).await?;
+        let values: Vec<Value> = input.drain_vec().await;
+
+        let separator = separator.map(|s| s.item).unwrap_or_default();
+        let joined = collect(&values, &separator, strict)?;
+
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(joined).into_value(name),
+        )))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Join a list of strings",
+                example: "echo [a b c] | str collect",
+                result: Some(vec![Value::from("abc")]),
+            },
+            Example {
+                description: "Join a list of strings with a separator",
+                example: "echo [a b c] | str collect '-'",
+                result: Some(vec![Value::from("a-b-c")]),
+            },
+        ]
+    }
+}
+
+pub(super) fn collect(
+    values: &[Value],
+    separator: &str,
+    strict: bool,
+) -> Result<String, ShellError> {
+    let mut parts = Vec::with_capacity(values.len());
+
+    for value in values {
+        let part = match &value.value {
+            UntaggedValue::Primitive(Primitive::Line(s))
+            | UntaggedValue::Primitive(Primitive::String(s)) => s.clone(),
+            other if strict => {
+                return Err(ShellError::labeled_error(
+                    "value is not string",
+                    format!("got {}", other.type_name()),
+                    value.tag.span,
+                ))
+            }
+            other => format_leaf(other).plain_string(100_000),
+        };
+
+        parts.push(part);
+    }
+
+    Ok(parts.join(separator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect, SubCommand};
+    use nu_plugin::test_helpers::value::{int, string};
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn joins_strings_with_a_separator() {
+        let values = vec![string("a"), string("b"), string("c")];
+
+        let actual = collect(&values, "-", false).unwrap();
+        assert_eq!(actual, "a-b-c");
+    }
+
+    #[test]
+    fn joins_display_text_of_values_that_are_not_strings() {
+        let values = vec![string("a"), int(1)];
+
+        let actual = collect(&values, ",", false).unwrap();
+        assert_eq!(actual, "a,1");
+    }
+
+    #[test]
+    fn errors_on_values_that_are_not_strings_when_strict() {
+        let values = vec![string("a"), int(1)];
+
+        assert!(collect(&values, ",", true).is_err());
+    }
+}
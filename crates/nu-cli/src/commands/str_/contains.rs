@@ -0,0 +1,177 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    pattern: Tagged<String>,
+    rest: Vec<ColumnPath>,
+    insensitive: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str contains"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str contains")
+            .required("pattern", SyntaxShape::String, "the pattern to match")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally checks if text contains pattern by column paths",
+            )
+            .switch("insensitive", "search is case insensitive", Some('i'))
+    }
+
+    fn usage(&self) -> &str {
+        "checks if text contains pattern"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Checks if string contains 'she'",
+                example: "echo 'nushell' | str contains 'she'",
+                result: Some(vec![UntaggedValue::boolean(true).into()]),
+            },
+            Example {
+                description: "Checks if string contains 'NU' ignoring case",
+                example: "echo 'nushell' | str contains -i 'NU'",
+                result: Some(vec![UntaggedValue::boolean(true).into()]),
+            },
+        ]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { pattern, rest, insensitive }, mut input) = args.process(&registry).await?;
+
+        let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
+
+        while let Some(v) = input.next().await {
+            if column_paths.is_empty() {
+                match action(&v, &pattern, insensitive, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+
+                let mut ret = v.clone();
+
+                for path in &column_paths {
+                    let pattern = pattern.clone();
+
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, &pattern, insensitive, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn action(
+    input: &Value,
+    pattern: &str,
+    insensitive: bool,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            let contains = if insensitive {
+                s.to_lowercase().contains(&pattern.to_lowercase())
+            } else {
+                s.contains(pattern)
+            };
+
+            Ok(UntaggedValue::boolean(contains).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn str_contains_pattern() {
+        let word = string("nushell");
+        let expected = UntaggedValue::boolean(true).into_untagged_value();
+
+        let actual = action(&word, "she", false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn str_does_not_contain_pattern_with_different_case() {
+        let word = string("nushell");
+        let expected = UntaggedValue::boolean(false).into_untagged_value();
+
+        let actual = action(&word, "NU", false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn str_contains_pattern_case_insensitive() {
+        let word = string("nushell");
+        let expected = UntaggedValue::boolean(true).into_untagged_value();
+
+        let actual = action(&word, "NU", true, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
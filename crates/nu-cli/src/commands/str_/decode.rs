@@ -0,0 +1,180 @@
+use super::encode::Encoding;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    hex: bool,
+    base64: bool,
+    binary: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str decode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str decode")
+            .switch("hex", "decode hexadecimal digits", Some('x'))
+            .switch("base64", "decode base64", Some('b'))
+            .switch(
+                "binary",
+                "always return binary, even if the decoded data is valid text",
+                None,
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally decode text by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "decodes hex or base64 text into text, or into binary if it isn't valid UTF-8"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Decode hex into text",
+                example: "echo '4142' | str decode --hex",
+                result: Some(vec![Value::from("AB")]),
+            },
+            Example {
+                description: "Decode base64 into text",
+                example: "echo 'QUI=' | str decode --base64",
+                result: Some(vec![Value::from("AB")]),
+            },
+            Example {
+                description: "Decode hex into binary",
+                example: "echo 'c0ffee' | str decode --hex --binary",
+                result: Some(vec![UntaggedValue::Primitive(Primitive::Binary(vec![
+                    0xC0, 0xFF, 0xEE,
+                ]))
+                .into()]),
+            },
+        ]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest, hex, base64, binary }, mut input) = args.process(&registry).await?;
+
+        let encoding = match Encoding::from_flags(hex, base64, &name) {
+            Ok(encoding) => encoding,
+            Err(err) => {
+                yield Err(err);
+                return;
+            }
+        };
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match encoding.decode(&v, binary, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| encoding.decode(old, binary, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Encoding, SubCommand};
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::{Primitive, UntaggedValue};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn decodes_hex_into_text() {
+        let actual = Encoding::Hex
+            .decode(&string("4142"), false, Tag::unknown())
+            .unwrap();
+        assert_eq!(actual, string("AB"));
+    }
+
+    #[test]
+    fn decodes_invalid_text_into_binary() {
+        let actual = Encoding::Base64(base64::STANDARD)
+            .decode(&string("wP/u"), false, Tag::unknown())
+            .unwrap();
+        assert_eq!(
+            actual,
+            UntaggedValue::Primitive(Primitive::Binary(vec![0xC0, 0xFF, 0xEE]))
+                .into_untagged_value()
+        );
+    }
+
+    #[test]
+    fn errors_decoding_odd_length_hex() {
+        assert!(Encoding::Hex
+            .decode(&string("414"), false, Tag::unknown())
+            .is_err());
+    }
+
+    #[test]
+    fn errors_decoding_non_hex_digits() {
+        assert!(Encoding::Hex
+            .decode(&string("41zz"), false, Tag::unknown())
+            .is_err());
+    }
+
+    #[test]
+    fn errors_decoding_base64_with_invalid_padding() {
+        assert!(Encoding::Base64(base64::STANDARD)
+            .decode(&string("QUI=="), false, Tag::unknown())
+            .is_err());
+    }
+}
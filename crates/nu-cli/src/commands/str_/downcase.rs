@@ -96,7 +96,7 @@ fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
         | UntaggedValue::Primitive(Primitive::String(s)) => {
-            Ok(UntaggedValue::string(s.to_ascii_lowercase()).into_value(tag))
+            Ok(UntaggedValue::string(s.to_lowercase()).into_value(tag))
         }
         other => {
             let got = format!("got {}", other.type_name());
@@ -112,7 +112,7 @@ fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
 #[cfg(test)]
 mod tests {
     use super::{action, SubCommand};
-    use nu_plugin::test_helpers::value::string;
+    use nu_plugin::test_helpers::value::{int, string};
     use nu_source::Tag;
 
     #[test]
@@ -130,4 +130,18 @@ mod tests {
         let actual = action(&word, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn downcases_accented_characters() {
+        let word = string("HÉLLO");
+        let expected = string("héllo");
+
+        let actual = action(&word, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn errors_given_numbers() {
+        assert!(action(&int(5), Tag::unknown()).is_err());
+    }
 }
@@ -0,0 +1,249 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    hex: bool,
+    base64: bool,
+}
+
+/// An encoding of bytes as text, shared by `str encode`, `str decode` and `base64`
+#[derive(Clone, Copy)]
+pub(crate) enum Encoding {
+    Hex,
+    Base64(base64::Config),
+}
+
+impl Encoding {
+    pub(super) fn from_flags(hex: bool, base64: bool, name: &Tag) -> Result<Encoding, ShellError> {
+        match (hex, base64) {
+            (true, false) => Ok(Encoding::Hex),
+            (false, true) => Ok(Encoding::Base64(base64::STANDARD)),
+            (true, true) => Err(ShellError::labeled_error(
+                "Can only use one encoding",
+                "use either --hex or --base64",
+                name,
+            )),
+            (false, false) => Err(ShellError::labeled_error(
+                "Missing encoding",
+                "needs --hex or --base64",
+                name,
+            )),
+        }
+    }
+
+    /// Encodes text or binary data into text
+    pub(crate) fn encode(self, input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+        let bytes = match &input.value {
+            UntaggedValue::Primitive(Primitive::Line(s))
+            | UntaggedValue::Primitive(Primitive::String(s)) => s.as_bytes(),
+            UntaggedValue::Primitive(Primitive::Binary(b)) => b.as_slice(),
+            other => {
+                let got = format!("got {}", other.type_name());
+                return Err(ShellError::labeled_error(
+                    "value is not string or binary",
+                    got,
+                    tag.into().span,
+                ));
+            }
+        };
+
+        let encoded = match self {
+            Encoding::Hex => hex::encode(bytes),
+            Encoding::Base64(config) => base64::encode_config(bytes, config),
+        };
+
+        Ok(UntaggedValue::string(encoded).into_value(tag))
+    }
+
+    /// Decodes text into bytes, ignoring the whitespace around it
+    pub(crate) fn decode_bytes(self, input: &Value, tag: &Tag) -> Result<Vec<u8>, ShellError> {
+        let s = match &input.value {
+            UntaggedValue::Primitive(Primitive::Line(s))
+            | UntaggedValue::Primitive(Primitive::String(s)) => s.trim(),
+            other => {
+                let got = format!("got {}", other.type_name());
+                return Err(ShellError::labeled_error(
+                    "value is not string",
+                    got,
+                    tag.span,
+                ));
+            }
+        };
+
+        match self {
+            Encoding::Hex => hex::decode(s).map_err(|err| {
+                ShellError::labeled_error("Could not decode hex", err.to_string(), tag.span)
+            }),
+            Encoding::Base64(config) => base64::decode_config(s, config).map_err(|err| {
+                ShellError::labeled_error("Could not decode base64", err.to_string(), tag.span)
+            }),
+        }
+    }
+
+    /// Decodes text into text if the result is valid UTF-8 and into binary otherwise, or always
+    /// into binary if asked to.
+    pub(crate) fn decode(
+        self,
+        input: &Value,
+        binary: bool,
+        tag: impl Into<Tag>,
+    ) -> Result<Value, ShellError> {
+        let tag = tag.into();
+        let decoded = self.decode_bytes(input, &tag)?;
+
+        if binary {
+            return Ok(UntaggedValue::Primitive(Primitive::Binary(decoded)).into_value(tag));
+        }
+
+        match String::from_utf8(decoded) {
+            Ok(text) => Ok(UntaggedValue::string(text).into_value(tag)),
+            Err(err) => {
+                Ok(UntaggedValue::Primitive(Primitive::Binary(err.into_bytes())).into_value(tag))
+            }
+        }
+    }
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str encode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str encode")
+            .switch("hex", "encode as hexadecimal digits", Some('x'))
+            .switch("base64", "encode as base64", Some('b'))
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally encode text or binary by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "encodes text or binary data as hex or base64 text"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Encode text as hex",
+                example: "echo 'AB' | str encode --hex",
+                result: Some(vec![Value::from("4142")]),
+            },
+            Example {
+                description: "Encode text as base64",
+                example: "echo 'AB' | str encode --base64",
+                result: Some(vec![Value::from("QUI=")]),
+            },
+        ]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest, hex, base64 }, mut input) = args.process(&registry).await?;
+
+        let encoding = match Encoding::from_flags(hex, base64, &name) {
+            Ok(encoding) => encoding,
+            Err(err) => {
+                yield Err(err);
+                return;
+            }
+        };
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match encoding.encode(&v, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| encoding.encode(old, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Encoding, SubCommand};
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_protocol::{Primitive, UntaggedValue};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn encodes_binary_as_hex() {
+        let bytes = UntaggedValue::Primitive(Primitive::Binary(vec![0xC0, 0xFF, 0xEE]))
+            .into_untagged_value();
+
+        let actual = Encoding::Hex.encode(&bytes, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("c0ffee"));
+    }
+
+    #[test]
+    fn encodes_binary_as_base64() {
+        let bytes = UntaggedValue::Primitive(Primitive::Binary(vec![0xC0, 0xFF, 0xEE]))
+            .into_untagged_value();
+
+        let actual = Encoding::Base64(base64::STANDARD)
+            .encode(&bytes, Tag::unknown())
+            .unwrap();
+        assert_eq!(actual, string("wP/u"));
+    }
+
+    #[test]
+    fn errors_encoding_numbers() {
+        assert!(Encoding::Hex.encode(&int(5), Tag::unknown()).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    pattern: Tagged<String>,
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str ends-with"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str ends-with")
+            .required("pattern", SyntaxShape::String, "the pattern to match")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally matches suffix of text by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "checks if text ends with pattern"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Checks if string ends with 'ell'",
+            example: "echo 'nushell' | str ends-with 'ell'",
+            result: Some(vec![UntaggedValue::boolean(true).into()]),
+        }]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { pattern, rest }, mut input) = args.process(&registry).await?;
+
+        let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
+
+        while let Some(v) = input.next().await {
+            if column_paths.is_empty() {
+                match action(&v, &pattern, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+
+                let mut ret = v.clone();
+
+                for path in &column_paths {
+                    let pattern = pattern.clone();
+
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, &pattern, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn action(input: &Value, pattern: &str, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            Ok(UntaggedValue::boolean(s.ends_with(pattern)).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::UntaggedValue;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn str_ends_with_pattern() {
+        let word = string("nushell");
+        let expected = UntaggedValue::boolean(true).into_untagged_value();
+
+        let actual = action(&word, "ell", Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn str_does_not_end_with_pattern() {
+        let word = string("nushell");
+        let expected = UntaggedValue::boolean(false).into_untagged_value();
+
+        let actual = action(&word, "nu", Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn str_ends_with_empty_pattern() {
+        let word = string("nushell");
+        let expected = UntaggedValue::boolean(true).into_untagged_value();
+
+        let actual = action(&word, "", Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
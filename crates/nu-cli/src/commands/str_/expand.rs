@@ -0,0 +1,173 @@
+use crate::commands::format::{format, push_column, FormatCommand};
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, PathMember, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    template: Tagged<String>,
+    #[serde(rename(deserialize = "keep-missing"))]
+    keep_missing: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str expand"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str expand")
+            .required(
+                "template",
+                SyntaxShape::String,
+                "the template to fill in. Eg) \"{first} {last}\"",
+            )
+            .switch(
+                "keep-missing",
+                "leave placeholders of missing columns as they are",
+                Some('k'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "fills in a template with the columns of each row"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Fill in the first and last name of each row",
+                example: r#"echo '{"first": "Ada", "last": "Lovelace"}' | from json | str expand '{first} {last}'"#,
+                result: None,
+            },
+            Example {
+                description: "Leave the placeholder of a missing column as it is",
+                example: r#"echo '{"first": "Ada"}' | from json | str expand -k '{first} {last}'"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { template, keep_missing }, mut input) = args.process(&registry).await?;
+
+        let tokens = format(&template);
+
+        while let Some(v) = input.next().await {
+            match action(&v, &tokens, keep_missing, &template.tag) {
+                Ok(out) => yield ReturnSuccess::value(out),
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn action(
+    input: &Value,
+    tokens: &[FormatCommand],
+    keep_missing: bool,
+    template_tag: &Tag,
+) -> Result<Value, ShellError> {
+    let mut output = String::new();
+
+    for token in tokens {
+        match token {
+            FormatCommand::Text(s) => output.push_str(s),
+            FormatCommand::Column(column) => {
+                let path = ColumnPath::new(
+                    column
+                        .split('.')
+                        .map(|member| PathMember::string(member, template_tag.span))
+                        .collect(),
+                );
+
+                let found = input.get_data_by_column_path(&path, Box::new(|(_, _, err)| err));
+
+                push_column(
+                    &mut output,
+                    column,
+                    found,
+                    keep_missing,
+                    template_tag,
+                    &input.tag,
+                )?;
+            }
+        }
+    }
+
+    Ok(UntaggedValue::string(output).into_value(&input.tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use crate::commands::format::format;
+    use nu_plugin::test_helpers::value::string;
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn expands_columns_of_the_row() {
+        let mut row = TaggedDictBuilder::new(Tag::unknown());
+        row.insert_value("first", string("Ada"));
+        row.insert_value("last", string("Lovelace"));
+
+        let tokens = format("{first} {last}");
+
+        let actual = action(&row.into_value(), &tokens, false, &Tag::unknown()).unwrap();
+        assert_eq!(actual, string("Ada Lovelace"));
+    }
+
+    #[test]
+    fn keeps_placeholders_of_missing_columns() {
+        let mut row = TaggedDictBuilder::new(Tag::unknown());
+        row.insert_value("first", string("Ada"));
+
+        let tokens = format("{first} {last}");
+
+        let actual = action(&row.into_value(), &tokens, true, &Tag::unknown()).unwrap();
+        assert_eq!(actual, string("Ada {last}"));
+    }
+
+    #[test]
+    fn errors_on_missing_columns() {
+        let mut row = TaggedDictBuilder::new(Tag::unknown());
+        row.insert_value("first", string("Ada"));
+
+        let tokens = format("{first} {last}");
+
+        assert!(action(&row.into_value(), &tokens, false, &Tag::unknown()).is_err());
+    }
+}
@@ -8,13 +8,15 @@ use nu_protocol::{
 use nu_source::{Tag, Tagged};
 use nu_value_ext::ValueExt;
 
-use regex::Regex;
+use regex::{NoExpand, Regex};
 
 #[derive(Deserialize)]
 struct Arguments {
     find: Tagged<String>,
     replace: Tagged<String>,
     rest: Vec<ColumnPath>,
+    all: bool,
+    regex: bool,
 }
 
 pub struct SubCommand;
@@ -33,6 +35,12 @@ impl WholeStreamCommand for SubCommand {
                 SyntaxShape::ColumnPath,
                 "optionally find and replace text by column paths",
             )
+            .switch("all", "replace all occurrences of the pattern", Some('a'))
+            .switch(
+                "regex",
+                "treat the pattern as a regular expression, allowing capture groups like $1 in the replacement",
+                Some('r'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -48,23 +56,72 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Find and replace contents with capture group",
-            example: "echo 'my_library.rb' | str find-replace '(.+).rb' '$1.nu'",
-            result: Some(vec![Value::from("my_library.nu")]),
-        }]
+        vec![
+            Example {
+                description: "Find and replace contents",
+                example: "echo 'a.b.c' | str find-replace '.' '-'",
+                result: Some(vec![Value::from("a-b.c")]),
+            },
+            Example {
+                description: "Find and replace all occurrences of the contents",
+                example: "echo 'a.b.c' | str find-replace '.' '-' --all",
+                result: Some(vec![Value::from("a-b-c")]),
+            },
+            Example {
+                description: "Find and replace contents with capture group",
+                example: "echo 'my_library.rb' | str find-replace '(.+).rb' '$1.nu' --regex",
+                result: Some(vec![Value::from("my_library.nu")]),
+            },
+        ]
     }
 }
 
 #[derive(Clone)]
-struct FindReplace(String, String);
+struct FindReplace {
+    find: Regex,
+    replace: String,
+    all: bool,
+    regex: bool,
+}
+
+impl FindReplace {
+    fn new(
+        find: &Tagged<String>,
+        replace: String,
+        all: bool,
+        regex: bool,
+    ) -> Result<Self, ShellError> {
+        let pattern = if regex {
+            find.item.clone()
+        } else {
+            regex::escape(&find.item)
+        };
+
+        let find = Regex::new(&pattern).map_err(|err| {
+            ShellError::labeled_error("Invalid regular expression", err.to_string(), find.tag.span)
+        })?;
+
+        Ok(FindReplace {
+            find,
+            replace,
+            all,
+            regex,
+        })
+    }
+}
 
 fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
 
     let stream = async_stream! {
-        let (Arguments { find, replace, rest }, mut input) = args.process(&registry).await?;
-        let options = FindReplace(find.item, replace.item);
+        let (Arguments { find, replace, rest, all, regex }, mut input) = args.process(&registry).await?;
+        let options = match FindReplace::new(&find, replace.item, all, regex) {
+            Ok(options) => options,
+            Err(err) => {
+                yield Err(err);
+                return;
+            }
+        };
 
         let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
 
@@ -111,17 +168,17 @@ fn action(input: &Value, options: &FindReplace, tag: impl Into<Tag>) -> Result<V
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
         | UntaggedValue::Primitive(Primitive::String(s)) => {
-            let find = &options.0;
-            let replacement = &options.1;
-
-            let regex = Regex::new(find.as_str());
+            let re = &options.find;
+            let replacement = options.replace.as_str();
 
-            let out = match regex {
-                Ok(re) => UntaggedValue::string(re.replace(s, replacement.as_str()).to_owned()),
-                Err(_) => UntaggedValue::string(s),
+            let out = match (options.all, options.regex) {
+                (true, true) => re.replace_all(s, replacement),
+                (false, true) => re.replace(s, replacement),
+                (true, false) => re.replace_all(s, NoExpand(replacement)),
+                (false, false) => re.replace(s, NoExpand(replacement)),
             };
 
-            Ok(out.into_value(tag))
+            Ok(UntaggedValue::string(out).into_value(tag))
         }
         other => {
             let got = format!("got {}", other.type_name());
@@ -138,7 +195,7 @@ fn action(input: &Value, options: &FindReplace, tag: impl Into<Tag>) -> Result<V
 mod tests {
     use super::{action, FindReplace, SubCommand};
     use nu_plugin::test_helpers::value::string;
-    use nu_source::Tag;
+    use nu_source::{Tag, TaggedItem};
 
     #[test]
     fn examples_work_as_expected() {
@@ -152,9 +209,61 @@ mod tests {
         let word = string("Cargo.toml");
         let expected = string("Carga.toml");
 
-        let find_replace_options = FindReplace("Cargo.(.+)".to_string(), "Carga.$1".to_string());
+        let find_replace_options = FindReplace::new(
+            &"Cargo.(.+)".to_string().tagged_unknown(),
+            "Carga.$1".to_string(),
+            false,
+            true,
+        )
+        .unwrap();
 
         let actual = action(&word, &find_replace_options, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn replaces_all_regex_matches() {
+        let word = string("abc123def456");
+        let expected = string("abcNdefN");
+
+        let find_replace_options = FindReplace::new(
+            &"\\d+".to_string().tagged_unknown(),
+            "N".to_string(),
+            true,
+            true,
+        )
+        .unwrap();
+
+        let actual = action(&word, &find_replace_options, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn treats_the_pattern_literally_without_regex() {
+        let word = string("my_library.rb");
+        let expected = string("my_library.rb");
+
+        let find_replace_options = FindReplace::new(
+            &"(.+).rb".to_string().tagged_unknown(),
+            "$1.nu".to_string(),
+            false,
+            false,
+        )
+        .unwrap();
+
+        let actual = action(&word, &find_replace_options, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn errors_given_an_invalid_regex() {
+        let find_replace_options = FindReplace::new(
+            &"(unclosed".to_string().tagged_unknown(),
+            "".to_string(),
+            false,
+            true,
+        );
+
+        assert!(find_replace_options.is_err());
+    }
 }
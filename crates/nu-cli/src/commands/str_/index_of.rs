@@ -0,0 +1,306 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Deserialize)]
+struct Arguments {
+    pattern: Tagged<String>,
+    rest: Vec<ColumnPath>,
+    end: bool,
+    range: Option<Tagged<String>>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str index-of"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str index-of")
+            .required("pattern", SyntaxShape::String, "the pattern to find")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally find the index of the pattern by column paths",
+            )
+            .switch("end", "search from the end of the text", Some('e'))
+            .named(
+                "range",
+                SyntaxShape::String,
+                "only search within the character indexes \"start,end\"",
+                Some('r'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "returns the character index of the pattern in text, or -1 when not found"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find the index of a pattern",
+                example: "echo 'hello world' | str index-of 'world'",
+                result: Some(vec![UntaggedValue::int(6).into()]),
+            },
+            Example {
+                description: "Find the index of the last occurrence of a pattern",
+                example: "echo 'abcabc' | str index-of 'b' --end",
+                result: Some(vec![UntaggedValue::int(4).into()]),
+            },
+            Example {
+                description: "Find the index of a pattern within a range",
+                example: "echo 'abcabc' | str index-of 'a' --range '1,6'",
+                result: Some(vec![UntaggedValue::int(3).into()]),
+            },
+        ]
+    }
+}
+
+#[derive(Clone)]
+struct IndexOf {
+    pattern: String,
+    from_end: bool,
+    start: usize,
+    end: usize,
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { pattern, rest, end, range }, mut input) = args.process(&registry).await?;
+
+        let (start, stop) = match range {
+            Some(range) => match parse_range(&range) {
+                Ok(bounds) => bounds,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            },
+            None => (0, usize::max_value()),
+        };
+
+        let options = IndexOf {
+            pattern: pattern.item,
+            from_end: end,
+            start,
+            end: stop,
+        };
+
+        let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
+
+        while let Some(v) = input.next().await {
+            if column_paths.is_empty() {
+                match action(&v, &options, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+
+                let mut ret = v.clone();
+
+                for path in &column_paths {
+                    let options = options.clone();
+
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, &options, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn parse_range(range: &Tagged<String>) -> Result<(usize, usize), ShellError> {
+    let bounds: Vec<&str> = range.item.split(',').collect();
+
+    if bounds.len() != 2 {
+        return Err(ShellError::labeled_error(
+            "could not parse the range",
+            "expected \"start,end\"",
+            range.tag.span,
+        ));
+    }
+
+    let parse_bound = |bound: &str, default: usize| match bound.trim() {
+        "" => Ok(default),
+        bound => bound.parse().map_err(|_| {
+            ShellError::labeled_error(
+                "could not parse the range",
+                "expected positive integer indexes",
+                range.tag.span,
+            )
+        }),
+    };
+
+    let start = parse_bound(bounds[0], 0)?;
+    let end = parse_bound(bounds[1], usize::MAX)?;
+
+    if start > end {
+        return Err(ShellError::labeled_error(
+            "End must be greater than or equal to Start",
+            "End must be greater than or equal to Start",
+            range.tag.span,
+        ));
+    }
+
+    Ok((start, end))
+}
+
+fn action(input: &Value, options: &IndexOf, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            let index = match index_of(s, options) {
+                Some(index) => index as i64,
+                None => -1,
+            };
+
+            Ok(UntaggedValue::int(index).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+/// Finds the pattern and reports its position counted in graphemes rather than bytes
+fn index_of(s: &str, options: &IndexOf) -> Option<usize> {
+    let offsets: Vec<usize> = s.grapheme_indices(true).map(|(idx, _)| idx).collect();
+    let byte_at = |index: usize| offsets.get(index).copied().unwrap_or(s.len());
+
+    if options.start > 0 && options.start >= offsets.len() {
+        return None;
+    }
+
+    if options.pattern.is_empty() {
+        return Some(options.start);
+    }
+
+    let start = byte_at(options.start);
+    let end = byte_at(options.end);
+
+    let found = if options.from_end {
+        s[start..end].rfind(&options.pattern)
+    } else {
+        s[start..end].find(&options.pattern)
+    }?;
+
+    let found = start + found;
+
+    Some(
+        offsets
+            .iter()
+            .take_while(|&&offset| offset <= found)
+            .count()
+            - 1,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, IndexOf, SubCommand};
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_source::Tag;
+
+    fn options(pattern: &str) -> IndexOf {
+        IndexOf {
+            pattern: pattern.to_string(),
+            from_end: false,
+            start: 0,
+            end: usize::MAX,
+        }
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn finds_the_index_of_a_pattern() {
+        let word = string("hello world");
+
+        let actual = action(&word, &options("world"), Tag::unknown()).unwrap();
+        assert_eq!(actual, int(6));
+    }
+
+    #[test]
+    fn counts_graphemes_rather_than_bytes() {
+        let word = string("cafe\u{301} au lait");
+
+        let actual = action(&word, &options("au"), Tag::unknown()).unwrap();
+        assert_eq!(actual, int(5));
+    }
+
+    #[test]
+    fn returns_minus_one_when_not_found() {
+        let word = string("hello world");
+
+        let actual = action(&word, &options("nu"), Tag::unknown()).unwrap();
+        assert_eq!(actual, int(-1));
+    }
+
+    #[test]
+    fn returns_zero_given_an_empty_pattern() {
+        let word = string("hello world");
+
+        let actual = action(&word, &options(""), Tag::unknown()).unwrap();
+        assert_eq!(actual, int(0));
+    }
+
+    #[test]
+    fn returns_minus_one_when_searching_past_the_end() {
+        let word = string("hello");
+
+        let past_the_end = IndexOf {
+            start: 10,
+            ..options("o")
+        };
+
+        let actual = action(&word, &past_the_end, Tag::unknown()).unwrap();
+        assert_eq!(actual, int(-1));
+    }
+}
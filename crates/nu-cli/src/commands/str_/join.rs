@@ -0,0 +1,98 @@
+use super::collect::collect;
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    column: ColumnPath,
+    separator: Option<Tagged<String>>,
+    strict: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str join")
+            .required(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column to take the values from",
+            )
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "the separator to put between each value",
+                Some('s'),
+            )
+            .switch(
+                "strict",
+                "error on values that are not strings instead of joining their display text",
+                None,
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "joins the values of a column across the rows into a single string"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        let registry = registry.clone();
+        let name = args.call_info.name_tag.clone();
+
+        let (
+            Arguments {
+                column,
+                separator,
+                strict,
+            },
+            mut input,
+        ) = args.process(&registry).await?;
+        let rows: Vec<Value> = input.drain_vec().await;
+
+        let values = rows
+            .iter()
+            .map(|row| row.get_data_by_column_path(&column, Box::new(|(_, _, err)| err)))
+            .collect::<Result<Vec<Value>, ShellError>>()?;
+
+        let separator = separator.map(|s| s.item).unwrap_or_default();
+        let joined = collect(&values, &separator, strict)?;
+
+        Ok(OutputStream::one(ReturnSuccess::value(
+            UntaggedValue::string(joined).into_value(name),
+        )))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Join the names of the rows with a comma",
+            example: r#"echo '[{"name": "a"}, {"name": "b"}]' | from json | str join name --separator ', '"#,
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
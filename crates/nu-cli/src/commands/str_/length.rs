@@ -0,0 +1,167 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+    bytes: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str length"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str length")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally get the length of text by column paths",
+            )
+            .switch(
+                "bytes",
+                "count the bytes of the text instead of its characters",
+                Some('b'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "outputs the number of characters in text, counting grapheme clusters"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the length of text",
+                example: "echo 'nushell' | str length",
+                result: Some(vec![UntaggedValue::int(7).into()]),
+            },
+            Example {
+                description: "Get the length of text in bytes",
+                example: "echo 'café' | str length --bytes",
+                result: Some(vec![UntaggedValue::int(5).into()]),
+            },
+        ]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest, bytes }, mut input) = args.process(&registry).await?;
+
+        let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
+
+        while let Some(v) = input.next().await {
+            if column_paths.is_empty() {
+                match action(&v, bytes, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+
+                let mut ret = v.clone();
+
+                for path in &column_paths {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, bytes, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn action(input: &Value, bytes: bool, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            let length = if bytes {
+                s.len()
+            } else {
+                s.graphemes(true).count()
+            };
+
+            Ok(UntaggedValue::int(length).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn counts_grapheme_clusters() {
+        let word = string("cafe\u{301}");
+
+        let actual = action(&word, false, Tag::unknown()).unwrap();
+        assert_eq!(actual, int(4));
+    }
+
+    #[test]
+    fn counts_bytes() {
+        let word = string("cafe\u{301}");
+
+        let actual = action(&word, true, Tag::unknown()).unwrap();
+        assert_eq!(actual, int(6));
+    }
+
+    #[test]
+    fn errors_given_a_value_that_is_not_text() {
+        assert!(action(&int(42), false, Tag::unknown()).is_err());
+    }
+}
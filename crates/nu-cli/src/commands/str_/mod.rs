@@ -1,23 +1,56 @@
 mod capitalize;
+mod capture;
+mod case;
+mod collect;
 mod command;
+mod contains;
+mod decode;
 mod downcase;
+mod encode;
+mod ends_with;
+mod expand;
 mod find_replace;
+mod index_of;
+mod join;
+mod length;
+mod pad;
+mod reverse;
 mod set;
+mod starts_with;
 mod substring;
+mod title_case;
 mod to_datetime;
 mod to_decimal;
 mod to_integer;
+mod translate;
 mod trim;
 mod upcase;
 
 pub use capitalize::SubCommand as StrCapitalize;
+pub use capture::SubCommand as StrCapture;
+pub use case::{StrCamelCase, StrKebabCase, StrSnakeCase};
+pub use collect::SubCommand as StrCollect;
 pub use command::Command as Str;
+pub use contains::SubCommand as StrContains;
+pub use decode::SubCommand as StrDecode;
 pub use downcase::SubCommand as StrDowncase;
+pub(crate) use encode::Encoding;
+pub use encode::SubCommand as StrEncode;
+pub use ends_with::SubCommand as StrEndsWith;
+pub use expand::SubCommand as StrExpand;
 pub use find_replace::SubCommand as StrFindReplace;
+pub use index_of::SubCommand as StrIndexOf;
+pub use join::SubCommand as StrJoin;
+pub use length::SubCommand as StrLength;
+pub use pad::SubCommand as StrPad;
+pub use reverse::SubCommand as StrReverse;
 pub use set::SubCommand as StrSet;
+pub use starts_with::SubCommand as StrStartsWith;
 pub use substring::SubCommand as StrSubstring;
+pub use title_case::SubCommand as StrTitleCase;
 pub use to_datetime::SubCommand as StrToDatetime;
 pub use to_decimal::SubCommand as StrToDecimal;
 pub use to_integer::SubCommand as StrToInteger;
+pub use translate::SubCommand as StrTranslate;
 pub use trim::SubCommand as StrTrim;
 pub use upcase::SubCommand as StrUpcase;
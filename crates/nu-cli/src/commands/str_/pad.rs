@@ -0,0 +1,391 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::{Tag, Tagged};
+use nu_value_ext::ValueExt;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Deserialize)]
+struct Arguments {
+    length: Tagged<usize>,
+    rest: Vec<ColumnPath>,
+    character: Option<Tagged<String>>,
+    right: bool,
+    zero: bool,
+    truncate: bool,
+    ellipsis: bool,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str pad"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str pad")
+            .required("length", SyntaxShape::Int, "the width to pad the text to")
+            .named(
+                "character",
+                SyntaxShape::String,
+                "the character to pad with, defaults to a space",
+                Some('c'),
+            )
+            .switch("right", "pad on the right instead of the left", Some('r'))
+            .switch(
+                "zero",
+                "pad numbers with zeros, keeping the sign in front",
+                Some('z'),
+            )
+            .switch(
+                "truncate",
+                "cut text longer than the width down to it",
+                Some('t'),
+            )
+            .switch(
+                "ellipsis",
+                "end truncated text with an ellipsis, counted within the width",
+                Some('e'),
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally pad text by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "pads (or truncates) text or integers to a given width"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pad contents on the left with spaces",
+                example: "echo 'abc' | str pad 5",
+                result: Some(vec![Value::from("  abc")]),
+            },
+            Example {
+                description: "Pad contents on the right with a preferred character",
+                example: "echo 'abc' | str pad 5 --character '.' --right",
+                result: Some(vec![Value::from("abc..")]),
+            },
+            Example {
+                description: "Pad numbers with zeros",
+                example: "echo [7 -7] | str pad 3 --zero",
+                result: Some(vec![Value::from("007"), Value::from("-07")]),
+            },
+            Example {
+                description: "Truncate contents longer than the width with an ellipsis",
+                example: "echo 'hello world' | str pad 8 --truncate --ellipsis",
+                result: Some(vec![Value::from("hello w…")]),
+            },
+        ]
+    }
+}
+
+#[derive(Clone)]
+struct Pad {
+    length: usize,
+    character: String,
+    right: bool,
+    zero: bool,
+    truncate: bool,
+    ellipsis: bool,
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { length, rest, character, right, zero, truncate, ellipsis }, mut input) = args.process(&registry).await?;
+
+        let character = match character {
+            Some(c) if zero => {
+                yield Err(ShellError::labeled_error(
+                    "Can not pad with a character and with zeros",
+                    "remove --character or --zero",
+                    c.tag,
+                ));
+                return;
+            }
+            Some(c) => {
+                if c.item.graphemes(true).count() != 1 {
+                    yield Err(ShellError::labeled_error(
+                        "Expected a single character to pad with",
+                        "requires a single character",
+                        c.tag,
+                    ));
+                    return;
+                }
+                c.item
+            }
+            None if zero => String::from("0"),
+            None => String::from(" "),
+        };
+
+        if zero && right {
+            yield Err(ShellError::labeled_error(
+                "Can not pad with zeros on the right",
+                "remove --right or --zero",
+                length.tag,
+            ));
+            return;
+        }
+
+        if ellipsis && !truncate {
+            yield Err(ShellError::labeled_error(
+                "Can not add an ellipsis without truncating",
+                "add --truncate or remove --ellipsis",
+                length.tag,
+            ));
+            return;
+        }
+
+        let options = Pad { length: length.item, character, right, zero, truncate, ellipsis };
+
+        let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
+
+        while let Some(v) = input.next().await {
+            if column_paths.is_empty() {
+                match action(&v, &options, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+
+                let mut ret = v.clone();
+
+                for path in &column_paths {
+                    let options = options.clone();
+
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, &options, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn action(input: &Value, options: &Pad, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    let s = match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => s.clone(),
+        UntaggedValue::Primitive(Primitive::Int(i)) => i.to_string(),
+        other => {
+            let got = format!("got {}", other.type_name());
+            return Err(ShellError::labeled_error(
+                "value is not string or integer",
+                got,
+                tag.into().span,
+            ));
+        }
+    };
+
+    let width = s.graphemes(true).count();
+
+    if width > options.length && options.truncate {
+        let out = if options.ellipsis {
+            let kept = options.length.saturating_sub(1);
+            let mut out: String = s.graphemes(true).take(kept).collect();
+            if options.length > 0 {
+                out.push('…');
+            }
+            out
+        } else {
+            s.graphemes(true).take(options.length).collect()
+        };
+
+        return Ok(UntaggedValue::string(out).into_value(tag));
+    }
+
+    if width >= options.length {
+        return Ok(UntaggedValue::string(s).into_value(tag));
+    }
+
+    let padding = options.character.repeat(options.length - width);
+
+    let out = if options.right {
+        format!("{}{}", s, padding)
+    } else if options.zero && (s.starts_with('-') || s.starts_with('+')) {
+        // The sign stays in front of the zeros, eg) -7 becomes -07
+        format!("{}{}{}", &s[..1], padding, &s[1..])
+    } else {
+        format!("{}{}", padding, s)
+    };
+
+    Ok(UntaggedValue::string(out).into_value(tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, Pad, SubCommand};
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn pads() {
+        let word = string("abc");
+        let expected = string("  abc");
+
+        let pad_options = Pad {
+            length: 5,
+            character: String::from(" "),
+            right: false,
+            zero: false,
+            truncate: false,
+            ellipsis: false,
+        };
+
+        let actual = action(&word, &pad_options, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn pads_by_grapheme_width() {
+        let word = string("cafe\u{301}");
+        let expected = string("cafe\u{301}-");
+
+        let pad_options = Pad {
+            length: 5,
+            character: String::from("-"),
+            right: true,
+            zero: false,
+            truncate: false,
+            ellipsis: false,
+        };
+
+        let actual = action(&word, &pad_options, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn leaves_text_at_or_over_width_untouched() {
+        let word = string("nushell");
+        let expected = string("nushell");
+
+        let pad_options = Pad {
+            length: 3,
+            character: String::from(" "),
+            right: false,
+            zero: false,
+            truncate: false,
+            ellipsis: false,
+        };
+
+        let actual = action(&word, &pad_options, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn pads_numbers_with_zeros() {
+        let pad_options = Pad {
+            length: 3,
+            character: String::from("0"),
+            right: false,
+            zero: true,
+            truncate: false,
+            ellipsis: false,
+        };
+
+        let actual = action(&int(5), &pad_options, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("005"));
+    }
+
+    #[test]
+    fn pads_negative_numbers_with_zeros_after_the_sign() {
+        let pad_options = Pad {
+            length: 3,
+            character: String::from("0"),
+            right: false,
+            zero: true,
+            truncate: false,
+            ellipsis: false,
+        };
+
+        let actual = action(&int(-5), &pad_options, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("-05"));
+    }
+
+    #[test]
+    fn truncates_with_an_ellipsis_within_the_width() {
+        let pad_options = Pad {
+            length: 8,
+            character: String::from(" "),
+            right: false,
+            zero: false,
+            truncate: true,
+            ellipsis: true,
+        };
+
+        let actual = action(&string("hello world"), &pad_options, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("hello w…"));
+    }
+
+    #[test]
+    fn truncates_by_grapheme_width() {
+        let pad_options = Pad {
+            length: 4,
+            character: String::from(" "),
+            right: false,
+            zero: false,
+            truncate: true,
+            ellipsis: false,
+        };
+
+        let actual = action(&string("cafe\u{301}s"), &pad_options, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("cafe\u{301}"));
+    }
+
+    #[test]
+    fn pads_short_text_when_truncating() {
+        let pad_options = Pad {
+            length: 5,
+            character: String::from(" "),
+            right: true,
+            zero: false,
+            truncate: true,
+            ellipsis: true,
+        };
+
+        let actual = action(&string("abc"), &pad_options, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("abc  "));
+    }
+}
@@ -5,14 +5,16 @@ use nu_protocol::ShellTypeName;
 use nu_protocol::{
     ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
 };
-use nu_source::{Tag, Tagged};
+use nu_source::Tag;
 use nu_value_ext::ValueExt;
+use num_traits::cast::ToPrimitive;
 
 use std::cmp;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Deserialize)]
 struct Arguments {
-    range: Tagged<String>,
+    range: Value,
     rest: Vec<ColumnPath>,
 }
 
@@ -28,8 +30,8 @@ impl WholeStreamCommand for SubCommand {
         Signature::build("str substring")
             .required(
                 "range",
-                SyntaxShape::String,
-                "the indexes to substring \"start, end\"",
+                SyntaxShape::Any,
+                "the indexes to substring, eg. 2..5 or \"start,end\", negative indexes count from the end",
             )
             .rest(
                 SyntaxShape::ColumnPath,
@@ -38,7 +40,7 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn usage(&self) -> &str {
-        "substrings text"
+        "substrings text by character, the end index is exclusive"
     }
 
     async fn run(
@@ -66,55 +68,42 @@ impl WholeStreamCommand for SubCommand {
                 example: "echo 'good nushell' | str substring ',7'",
                 result: Some(vec![Value::from("good nu")]),
             },
+            Example {
+                description: "Get a substring using a range",
+                example: "echo 'good nushell' | str substring 5..7",
+                result: Some(vec![Value::from("nu")]),
+            },
+            Example {
+                description: "Get the last characters using a negative index",
+                example: "echo 'good nushell' | str substring '-5..'",
+                result: Some(vec![Value::from("shell")]),
+            },
         ]
     }
 }
 
 #[derive(Clone)]
-struct Substring(usize, usize);
+struct Substring(i64, i64);
 
 fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    let name = args.call_info.name_tag.clone();
     let registry = registry.clone();
 
     let stream = async_stream! {
         let (Arguments { range, rest }, mut input) = args.process(&registry).await?;
 
-        let v: Vec<&str> = range.item.split(',').collect();
-
-        let start = match v[0] {
-            "" => 0,
-            _ => v[0]
-                .trim()
-                .parse()
-                .map_err(|_| {
-                    ShellError::labeled_error(
-                        "could not perform substring",
-                        "could not perform substring",
-                        name.span,
-                    )
-                })?
-        };
-
-        let end = match v[1] {
-            "" => usize::max_value(),
-            _ => v[1]
-                .trim()
-                .parse()
-                .map_err(|_| {
-                    ShellError::labeled_error(
-                        "could not perform substring",
-                        "could not perform substring",
-                        name.span,
-                    )
-                })?
+        let (start, end) = match parse_range(&range) {
+            Ok(bounds) => bounds,
+            Err(err) => {
+                yield Err(err);
+                return;
+            }
         };
 
-        if start > end {
+        if start >= 0 && end >= 0 && start > end {
             yield Err(ShellError::labeled_error(
                 "End must be greater than or equal to Start",
                 "End must be greater than or equal to Start",
-                name.span,
+                range.tag.span,
             ));
             return;
         }
@@ -160,24 +149,73 @@ fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
     Ok(stream.to_output_stream())
 }
 
+/// Reads the bounds from a range like `2..5` or from text like "2..5" or "2,5", either side may be left out
+fn parse_range(range: &Value) -> Result<(i64, i64), ShellError> {
+    let invalid = || {
+        ShellError::labeled_error(
+            "could not perform substring",
+            "expected a range of integer indexes, eg. 2..5 or \"start,end\"",
+            range.tag.span,
+        )
+    };
+
+    match &range.value {
+        UntaggedValue::Primitive(Primitive::Range(bounds)) => {
+            let bound = |primitive: &Primitive| match primitive {
+                Primitive::Int(int) => int.to_i64().ok_or_else(invalid),
+                _ => Err(invalid()),
+            };
+
+            Ok((bound(&bounds.from.0.item)?, bound(&bounds.to.0.item)?))
+        }
+        UntaggedValue::Primitive(Primitive::String(s)) => {
+            let bounds: Vec<&str> = if s.contains("..") {
+                s.splitn(2, "..").collect()
+            } else {
+                s.split(',').collect()
+            };
+
+            if bounds.len() != 2 {
+                return Err(invalid());
+            }
+
+            let bound = |bound: &str, default: i64| match bound.trim() {
+                "" => Ok(default),
+                bound => bound.parse().map_err(|_| invalid()),
+            };
+
+            Ok((bound(bounds[0], 0)?, bound(bounds[1], i64::MAX)?))
+        }
+        _ => Err(invalid()),
+    }
+}
+
 fn action(input: &Value, options: &Substring, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
         | UntaggedValue::Primitive(Primitive::String(s)) => {
-            let start = options.0;
-            let end: usize = cmp::min(options.1, s.len());
+            let graphemes: Vec<&str> = s.graphemes(true).collect();
+            let len = graphemes.len() as i64;
 
-            let out = {
-                if start > s.len() - 1 {
-                    UntaggedValue::string("")
+            // Negative indexes count back from the end, and every index is clamped to the text
+            let clamp = |index: i64| {
+                if index < 0 {
+                    cmp::max(len + index, 0) as usize
                 } else {
-                    UntaggedValue::string(
-                        s.chars().skip(start).take(end - start).collect::<String>(),
-                    )
+                    cmp::min(index, len) as usize
                 }
             };
 
-            Ok(out.into_value(tag))
+            let start = clamp(options.0);
+            let end = clamp(options.1);
+
+            let out = if start >= end {
+                String::new()
+            } else {
+                graphemes[start..end].concat()
+            };
+
+            Ok(UntaggedValue::string(out).into_value(tag))
         }
         other => {
             let got = format!("got {}", other.type_name());
@@ -213,4 +251,43 @@ mod tests {
         let actual = action(&word, &substring_options, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn excludes_the_end_index() {
+        let word = string("hello");
+        let expected = string("ll");
+
+        let actual = action(&word, &Substring(2, 4), Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn counts_negative_indexes_from_the_end() {
+        let word = string("hello");
+
+        let actual = action(&word, &Substring(-3, -1), Tag::unknown()).unwrap();
+        assert_eq!(actual, string("ll"));
+
+        let actual = action(&word, &Substring(-10, 2), Tag::unknown()).unwrap();
+        assert_eq!(actual, string("he"));
+    }
+
+    #[test]
+    fn clamps_indexes_past_the_end() {
+        let word = string("hello");
+
+        let actual = action(&word, &Substring(3, 99), Tag::unknown()).unwrap();
+        assert_eq!(actual, string("lo"));
+
+        let actual = action(&word, &Substring(99, 100), Tag::unknown()).unwrap();
+        assert_eq!(actual, string(""));
+    }
+
+    #[test]
+    fn slices_multi_byte_text_by_character() {
+        let word = string("añoño");
+
+        let actual = action(&word, &Substring(1, 3), Tag::unknown()).unwrap();
+        assert_eq!(actual, string("ño"));
+    }
 }
@@ -8,12 +8,13 @@ use nu_protocol::{
 use nu_source::{Tag, Tagged};
 use nu_value_ext::ValueExt;
 
-use chrono::DateTime;
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
 
 #[derive(Deserialize)]
 struct Arguments {
-    format: Option<Tagged<String>>,
     rest: Vec<ColumnPath>,
+    format: Option<Tagged<String>>,
+    utc: bool,
 }
 
 pub struct SubCommand;
@@ -29,9 +30,14 @@ impl WholeStreamCommand for SubCommand {
             .named(
                 "format",
                 SyntaxShape::String,
-                "Specify date and time formatting",
+                "Specify date and time formatting (defaults to RFC 3339)",
                 Some('f'),
             )
+            .switch(
+                "utc",
+                "interpret text without a timezone as UTC instead of local time",
+                Some('u'),
+            )
             .rest(
                 SyntaxShape::ColumnPath,
                 "optionally convert text into datetime by column paths",
@@ -51,29 +57,55 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Convert to datetime",
-            example: "echo '16.11.1984 8:00 am +0000' | str to-datetime",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Convert to datetime",
+                example: "echo '1984-11-16T08:00:00+00:00' | str to-datetime",
+                result: Some(vec![UntaggedValue::date(
+                    Utc.ymd(1984, 11, 16).and_hms(8, 0, 0),
+                )
+                .into()]),
+            },
+            Example {
+                description: "Convert to datetime using a custom format",
+                example: "echo '16.11.1984 8:00 am +0000' | str to-datetime --format '%d.%m.%Y %H:%M %P %z'",
+                result: Some(vec![UntaggedValue::date(
+                    Utc.ymd(1984, 11, 16).and_hms(8, 0, 0),
+                )
+                .into()]),
+            },
+            Example {
+                description: "Convert a date without a timezone to a UTC datetime",
+                example: "echo '2020-01-01' | str to-datetime --format '%Y-%m-%d' --utc",
+                result: Some(vec![UntaggedValue::date(
+                    Utc.ymd(2020, 1, 1).and_hms(0, 0, 0),
+                )
+                .into()]),
+            },
+        ]
     }
 }
 
 #[derive(Clone)]
 struct DatetimeFormat(String);
 
+#[derive(Clone)]
+struct DatetimeOptions {
+    format: Option<DatetimeFormat>,
+    utc: bool,
+}
+
 fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
 
     let stream = async_stream! {
-        let (Arguments { format, rest }, mut input) = args.process(&registry).await?;
+        let (Arguments { rest, format, utc }, mut input) = args.process(&registry).await?;
 
         let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
 
-        let options = if let Some(Tagged { item: fmt, tag }) = format {
-            DatetimeFormat(fmt)
-        } else {
-            DatetimeFormat(String::from("%d.%m.%Y %H:%M %P %z"))
+        let options = DatetimeOptions {
+            format: format.map(|fmt| DatetimeFormat(fmt.item)),
+            utc,
         };
 
         while let Some(v) = input.next().await {
@@ -114,39 +146,83 @@ fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
 
 fn action(
     input: &Value,
-    options: &DatetimeFormat,
+    options: &DatetimeOptions,
     tag: impl Into<Tag>,
 ) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
         | UntaggedValue::Primitive(Primitive::String(s)) => {
-            let dt = &options.0;
-
-            let out = match DateTime::parse_from_str(s, dt) {
-                Ok(d) => UntaggedValue::date(d),
-                Err(_) => UntaggedValue::string(s),
+            let parsed = match &options.format {
+                Some(DatetimeFormat(dt)) => parse_with_format(s, dt, options.utc),
+                None => DateTime::parse_from_rfc3339(s)
+                    .ok()
+                    .map(|d| d.with_timezone(&Utc)),
             };
 
-            Ok(out.into_value(tag))
+            match parsed {
+                Some(d) => Ok(UntaggedValue::date(d).into_value(tag)),
+                None => {
+                    let expected = match &options.format {
+                        Some(DatetimeFormat(dt)) => format!("expected format '{}'", dt),
+                        None => String::from("expected an RFC 3339 datetime"),
+                    };
+
+                    Err(ShellError::labeled_error(
+                        format!("Could not parse '{}' as datetime", s),
+                        expected,
+                        tag.span,
+                    ))
+                }
+            }
         }
         other => {
             let got = format!("got {}", other.type_name());
             Err(ShellError::labeled_error(
                 "value is not string",
                 got,
-                tag.into().span,
+                tag.span,
             ))
         }
     }
 }
 
+/// Tries the format with a timezone first, then as a date and time or a date with no timezone
+fn parse_with_format(s: &str, dt: &str, utc: bool) -> Option<DateTime<Utc>> {
+    if let Ok(d) = DateTime::<FixedOffset>::parse_from_str(s, dt) {
+        return Some(d.with_timezone(&Utc));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(s, dt)
+        .or_else(|_| NaiveDate::parse_from_str(s, dt).map(|d| d.and_hms(0, 0, 0)))
+        .ok()?;
+
+    if utc {
+        Some(Utc.from_utc_datetime(&naive))
+    } else {
+        Local
+            .from_local_datetime(&naive)
+            .single()
+            .map(|d| d.with_timezone(&Utc))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{action, DatetimeFormat, SubCommand};
+    use super::{action, DatetimeFormat, DatetimeOptions, SubCommand};
+    use chrono::{TimeZone, Utc};
     use nu_plugin::test_helpers::value::string;
     use nu_protocol::{Primitive, UntaggedValue};
     use nu_source::Tag;
 
+    fn with_format(format: &str, utc: bool) -> DatetimeOptions {
+        DatetimeOptions {
+            format: Some(DatetimeFormat(format.to_string())),
+            utc,
+        }
+    }
+
     #[test]
     fn examples_work_as_expected() {
         use crate::examples::test as test_examples;
@@ -158,7 +234,35 @@ mod tests {
     fn takes_a_date_format() {
         let date_str = string("16.11.1984 8:00 am +0000");
 
-        let fmt_options = DatetimeFormat("%d.%m.%Y %H:%M %P %z".to_string());
+        let fmt_options = with_format("%d.%m.%Y %H:%M %P %z", false);
+
+        let actual = action(&date_str, &fmt_options, Tag::unknown()).unwrap();
+
+        match actual.value {
+            UntaggedValue::Primitive(Primitive::Date(_)) => {}
+            _ => panic!("Didn't convert to date"),
+        }
+    }
+
+    #[test]
+    fn takes_a_date_format_without_timezone() {
+        let date_str = string("2020-01-01");
+
+        let fmt_options = with_format("%Y-%m-%d", true);
+
+        let actual = action(&date_str, &fmt_options, Tag::unknown()).unwrap();
+
+        assert_eq!(
+            actual,
+            UntaggedValue::date(Utc.ymd(2020, 1, 1).and_hms(0, 0, 0)).into_untagged_value()
+        );
+    }
+
+    #[test]
+    fn interprets_text_without_timezone_as_local_time() {
+        let date_str = string("2020-01-01 12:00");
+
+        let fmt_options = with_format("%Y-%m-%d %H:%M", false);
 
         let actual = action(&date_str, &fmt_options, Tag::unknown()).unwrap();
 
@@ -167,4 +271,30 @@ mod tests {
             _ => panic!("Didn't convert to date"),
         }
     }
+
+    #[test]
+    fn defaults_to_rfc3339() {
+        let date_str = string("2020-01-01T10:00:00+02:00");
+
+        let options = DatetimeOptions {
+            format: None,
+            utc: false,
+        };
+
+        let actual = action(&date_str, &options, Tag::unknown()).unwrap();
+
+        assert_eq!(
+            actual,
+            UntaggedValue::date(Utc.ymd(2020, 1, 1).and_hms(8, 0, 0)).into_untagged_value()
+        );
+    }
+
+    #[test]
+    fn errors_on_text_that_does_not_match_the_format() {
+        let date_str = string("not a date");
+
+        let fmt_options = with_format("%Y-%m-%d", false);
+
+        assert!(action(&date_str, &fmt_options, Tag::unknown()).is_err());
+    }
 }
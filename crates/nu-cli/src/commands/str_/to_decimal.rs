@@ -14,6 +14,10 @@ use std::str::FromStr;
 #[derive(Deserialize)]
 struct Arguments {
     rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "comma-decimal"))]
+    comma_decimal: bool,
+    #[serde(rename(deserialize = "skip-errors"))]
+    skip_errors: bool,
 }
 
 pub struct SubCommand;
@@ -25,14 +29,25 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("str to-decimal").rest(
-            SyntaxShape::ColumnPath,
-            "optionally convert text into decimal by column paths",
-        )
+        Signature::build("str to-decimal")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert text into decimal by column paths",
+            )
+            .switch(
+                "comma-decimal",
+                "use ',' instead of '.' as the decimal separator",
+                Some('c'),
+            )
+            .switch(
+                "skip-errors",
+                "leave text that is not a decimal unchanged",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "converts text into decimal"
+        "converts text or integers into decimal"
     }
 
     async fn run(
@@ -44,11 +59,23 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Convert to decimal",
-            example: "echo '3.1415' | str to-decimal",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Convert to decimal",
+                example: "echo '3.1415' | str to-decimal",
+                result: None,
+            },
+            Example {
+                description: "Convert scientific notation to decimal",
+                example: "echo '1.5e3' | str to-decimal",
+                result: Some(vec![UntaggedValue::decimal(1500).into()]),
+            },
+            Example {
+                description: "Convert to decimal using ',' as the decimal separator",
+                example: "echo '2,5' | str to-decimal --comma-decimal",
+                result: Some(vec![UntaggedValue::decimal(2.5).into()]),
+            },
+        ]
     }
 }
 
@@ -56,13 +83,13 @@ fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
     let registry = registry.clone();
 
     let stream = async_stream! {
-        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+        let (Arguments { rest, comma_decimal, skip_errors }, mut input) = args.process(&registry).await?;
 
         let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
 
         while let Some(v) = input.next().await {
             if column_paths.is_empty() {
-                match action(&v, v.tag()) {
+                match action(&v, comma_decimal, skip_errors, v.tag()) {
                     Ok(out) => yield ReturnSuccess::value(out),
                     Err(err) => {
                         yield Err(err);
@@ -74,7 +101,7 @@ fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
                 let mut ret = v.clone();
 
                 for path in &column_paths {
-                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, old.tag())));
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, comma_decimal, skip_errors, old.tag())));
 
                     match swapping {
                         Ok(new_value) => {
@@ -95,16 +122,39 @@ fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
     Ok(stream.to_output_stream())
 }
 
-fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+fn action(
+    input: &Value,
+    comma_decimal: bool,
+    skip_errors: bool,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
         | UntaggedValue::Primitive(Primitive::String(s)) => {
             let other = s.trim();
-            let out = match BigDecimal::from_str(other) {
-                Ok(v) => UntaggedValue::decimal(v),
-                Err(_) => UntaggedValue::string(s),
+
+            let parsed = if comma_decimal {
+                if other.contains('.') {
+                    None
+                } else {
+                    BigDecimal::from_str(&other.replace(',', ".")).ok()
+                }
+            } else {
+                BigDecimal::from_str(other).ok()
             };
-            Ok(out.into_value(tag))
+
+            match parsed {
+                Some(v) => Ok(UntaggedValue::decimal(v).into_value(tag)),
+                None if skip_errors => Ok(input.clone()),
+                None => Err(ShellError::labeled_error(
+                    format!("Could not convert '{}' into a decimal", s),
+                    "text is not a decimal",
+                    tag.into().span,
+                )),
+            }
+        }
+        UntaggedValue::Primitive(Primitive::Int(i)) => {
+            Ok(UntaggedValue::decimal(BigDecimal::new(i.clone(), 0)).into_value(tag))
         }
         other => {
             let got = format!("got {}", other.type_name());
@@ -120,7 +170,7 @@ fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
 #[cfg(test)]
 mod tests {
     use super::{action, SubCommand};
-    use nu_plugin::test_helpers::value::{decimal, string};
+    use nu_plugin::test_helpers::value::{decimal, int, string};
     use nu_source::Tag;
 
     #[test]
@@ -136,7 +186,50 @@ mod tests {
         let word = string("3.1415");
         let expected = decimal(3.1415);
 
-        let actual = action(&word, Tag::unknown()).unwrap();
+        let actual = action(&word, false, false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn turns_scientific_notation_to_decimal() {
+        let word = string("1e2");
+        let expected = decimal(100);
+
+        let actual = action(&word, false, false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn turns_comma_separated_decimal_to_decimal() {
+        let word = string("2,5");
+        let expected = decimal(2.5);
+
+        let actual = action(&word, true, false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn widens_integers_to_decimals() {
+        let number = int(7);
+        let expected = decimal(7);
+
+        let actual = action(&number, false, false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn errors_given_text_that_is_not_a_decimal() {
+        let word = string("abc");
+
+        assert!(action(&word, false, false, Tag::unknown()).is_err());
+    }
+
+    #[test]
+    fn leaves_text_that_is_not_a_decimal_given_skip_errors() {
+        let word = string("abc");
+        let expected = string("abc");
+
+        let actual = action(&word, false, true, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
 }
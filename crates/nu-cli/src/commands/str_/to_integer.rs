@@ -5,15 +5,18 @@ use nu_protocol::ShellTypeName;
 use nu_protocol::{
     ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
 };
-use nu_source::Tag;
+use nu_source::{Tag, Tagged};
 use nu_value_ext::ValueExt;
 
 use num_bigint::BigInt;
-use std::str::FromStr;
+use num_traits::Num;
 
 #[derive(Deserialize)]
 struct Arguments {
     rest: Vec<ColumnPath>,
+    radix: Option<Tagged<u64>>,
+    #[serde(rename(deserialize = "skip-errors"))]
+    skip_errors: bool,
 }
 
 pub struct SubCommand;
@@ -25,10 +28,22 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("str to-int").rest(
-            SyntaxShape::ColumnPath,
-            "optionally convert text into integer by column paths",
-        )
+        Signature::build("str to-int")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally convert text into integer by column paths",
+            )
+            .named(
+                "radix",
+                SyntaxShape::Int,
+                "the radix to parse the text with, from 2 to 36 (defaults to 10)",
+                Some('r'),
+            )
+            .switch(
+                "skip-errors",
+                "leave text that is not an integer unchanged",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -44,11 +59,18 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Convert to an integer",
-            example: "echo '255' | str to-int",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Convert to an integer",
+                example: "echo '255' | str to-int",
+                result: Some(vec![UntaggedValue::int(255).into()]),
+            },
+            Example {
+                description: "Convert hexadecimal text to an integer",
+                example: "echo '0xff' | str to-int --radix 16",
+                result: Some(vec![UntaggedValue::int(255).into()]),
+            },
+        ]
     }
 }
 
@@ -56,13 +78,26 @@ fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
     let registry = registry.clone();
 
     let stream = async_stream! {
-        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+        let (Arguments { rest, radix, skip_errors }, mut input) = args.process(&registry).await?;
+
+        let radix = match radix {
+            Some(Tagged { item: radix, tag }) if !(2..=36).contains(&radix) => {
+                yield Err(ShellError::labeled_error(
+                    "Radix must be between 2 and 36",
+                    "invalid radix",
+                    tag,
+                ));
+                return;
+            }
+            Some(radix) => radix.item as u32,
+            None => 10,
+        };
 
         let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
 
         while let Some(v) = input.next().await {
             if column_paths.is_empty() {
-                match action(&v, v.tag()) {
+                match action(&v, radix, skip_errors, v.tag()) {
                     Ok(out) => yield ReturnSuccess::value(out),
                     Err(err) => {
                         yield Err(err);
@@ -74,7 +109,7 @@ fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
                 let mut ret = v.clone();
 
                 for path in &column_paths {
-                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, old.tag())));
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, radix, skip_errors, old.tag())));
 
                     match swapping {
                         Ok(new_value) => {
@@ -95,17 +130,23 @@ fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
     Ok(stream.to_output_stream())
 }
 
-fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+fn action(
+    input: &Value,
+    radix: u32,
+    skip_errors: bool,
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
-        | UntaggedValue::Primitive(Primitive::String(s)) => {
-            let other = s.trim();
-            let out = match BigInt::from_str(other) {
-                Ok(v) => UntaggedValue::int(v),
-                Err(_) => UntaggedValue::string(s),
-            };
-            Ok(out.into_value(tag))
-        }
+        | UntaggedValue::Primitive(Primitive::String(s)) => match parse_int(s, radix) {
+            Some(v) => Ok(UntaggedValue::int(v).into_value(tag)),
+            None if skip_errors => Ok(input.clone()),
+            None => Err(ShellError::labeled_error(
+                format!("Could not convert '{}' into an integer", s),
+                "text is not an integer",
+                tag.into().span,
+            )),
+        },
         other => {
             let got = format!("got {}", other.type_name());
             Err(ShellError::labeled_error(
@@ -117,6 +158,44 @@ fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     }
 }
 
+fn parse_int(text: &str, radix: u32) -> Option<BigInt> {
+    let text = text.trim();
+
+    let (negative, digits) = if let Some(digits) = text.strip_prefix('-') {
+        (true, digits)
+    } else if let Some(digits) = text.strip_prefix('+') {
+        (false, digits)
+    } else {
+        (false, text)
+    };
+
+    let prefix = match radix {
+        16 => "0x",
+        8 => "0o",
+        2 => "0b",
+        _ => "",
+    };
+
+    let digits = if !prefix.is_empty() && digits.to_lowercase().starts_with(prefix) {
+        &digits[prefix.len()..]
+    } else {
+        digits
+    };
+
+    // Signs were already taken care of above, so don't accept a second one here
+    if digits.starts_with('-') || digits.starts_with('+') {
+        return None;
+    }
+
+    let value = BigInt::from_str_radix(digits, radix).ok()?;
+
+    if negative {
+        Some(-value)
+    } else {
+        Some(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{action, SubCommand};
@@ -135,7 +214,50 @@ mod tests {
         let word = string("10");
         let expected = int(10);
 
-        let actual = action(&word, Tag::unknown()).unwrap();
+        let actual = action(&word, 10, false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn trims_whitespace_around_the_integer() {
+        let word = string("  42 ");
+        let expected = int(42);
+
+        let actual = action(&word, 10, false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn turns_hexadecimal_to_integer() {
+        let word = string("0xff");
+        let expected = int(255);
+
+        let actual = action(&word, 16, false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn turns_negative_binary_to_integer() {
+        let word = string("-0b101");
+        let expected = int(-5);
+
+        let actual = action(&word, 2, false, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn errors_given_text_that_is_not_an_integer() {
+        let word = string("abc");
+
+        assert!(action(&word, 10, false, Tag::unknown()).is_err());
+    }
+
+    #[test]
+    fn leaves_text_that_is_not_an_integer_given_skip_errors() {
+        let word = string("abc");
+        let expected = string("abc");
+
+        let actual = action(&word, 10, true, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
 }
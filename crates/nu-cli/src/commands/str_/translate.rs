@@ -0,0 +1,230 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    pairs: Value,
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "str translate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str translate")
+            .required(
+                "pairs",
+                SyntaxShape::Any,
+                "the patterns and their replacements, as a row or as a list like [find replace find replace]",
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally translate text by column paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "replaces several patterns one after another, in the order given (so earlier replacements can affect later ones)"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Replace several patterns given as a list",
+                example: "echo 'abc' | str translate [a x b y]",
+                result: Some(vec![Value::from("xyc")]),
+            },
+            Example {
+                description: "Replace several patterns given as a row",
+                example: r#"echo 'abc' | str translate $(echo '{"a": "x", "b": "y"}' | from json)"#,
+                result: None,
+            },
+            Example {
+                description: "Earlier replacements are seen by later ones",
+                example: "echo 'abc' | str translate [a b b c]",
+                result: Some(vec![Value::from("ccc")]),
+            },
+        ]
+    }
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { pairs, rest }, mut input) = args.process(&registry).await?;
+
+        let pairs = match pairs_of(&pairs) {
+            Ok(pairs) => pairs,
+            Err(err) => {
+                yield Err(err);
+                return;
+            }
+        };
+
+        let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
+
+        while let Some(v) = input.next().await {
+            if column_paths.is_empty() {
+                match action(&v, &pairs, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+
+                let mut ret = v.clone();
+
+                for path in &column_paths {
+                    let pairs = pairs.clone();
+
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| {
+                        action(old, &pairs, old.tag())
+                    }));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+fn pairs_of(value: &Value) -> Result<Vec<(String, String)>, ShellError> {
+    match &value.value {
+        UntaggedValue::Row(dict) => Ok(dict
+            .entries
+            .iter()
+            .map(|(find, replace)| (find.clone(), replace.convert_to_string()))
+            .collect()),
+        UntaggedValue::Table(values) if values.len() == 1 => pairs_of(&values[0]),
+        UntaggedValue::Table(values)
+            if values.len() % 2 == 0 && values.iter().all(|value| value.as_primitive().is_ok()) =>
+        {
+            Ok(values
+                .chunks(2)
+                .map(|pair| (pair[0].convert_to_string(), pair[1].convert_to_string()))
+                .collect())
+        }
+        _ => Err(ShellError::labeled_error(
+            "Expected patterns and their replacements",
+            "requires a row, or a list like [find replace find replace]",
+            &value.tag,
+        )),
+    }
+}
+
+fn action(
+    input: &Value,
+    pairs: &[(String, String)],
+    tag: impl Into<Tag>,
+) -> Result<Value, ShellError> {
+    match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => {
+            let out = pairs
+                .iter()
+                .filter(|(find, _)| !find.is_empty())
+                .fold(s.clone(), |out, (find, replace)| out.replace(find, replace));
+
+            Ok(UntaggedValue::string(out).into_value(tag))
+        }
+        other => {
+            let got = format!("got {}", other.type_name());
+            Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.into().span,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, pairs_of, SubCommand};
+    use nu_plugin::test_helpers::value::{string, table};
+    use nu_protocol::TaggedDictBuilder;
+    use nu_source::Tag;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn replaces_the_pairs_of_a_row_in_order() {
+        let mut row = TaggedDictBuilder::new(Tag::unknown());
+        row.insert_value("a", string("x"));
+        row.insert_value("b", string("y"));
+
+        let pairs = pairs_of(&row.into_value()).unwrap();
+
+        let actual = action(&string("abc"), &pairs, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("xyc"));
+    }
+
+    #[test]
+    fn replaces_the_pairs_of_a_list_in_order() {
+        let pairs = pairs_of(&table(&[
+            string("a"),
+            string("b"),
+            string("b"),
+            string("c"),
+        ]))
+        .unwrap();
+
+        let actual = action(&string("abc"), &pairs, Tag::unknown()).unwrap();
+        assert_eq!(actual, string("ccc"));
+    }
+
+    #[test]
+    fn errors_given_a_pattern_without_a_replacement() {
+        assert!(pairs_of(&table(&[string("a"), string("b"), string("c")])).is_err());
+    }
+
+    #[test]
+    fn errors_given_a_table_of_rows() {
+        let mut first = TaggedDictBuilder::new(Tag::unknown());
+        first.insert_value("a", string("x"));
+        let mut second = TaggedDictBuilder::new(Tag::unknown());
+        second.insert_value("b", string("y"));
+
+        assert!(pairs_of(&table(&[first.into_value(), second.into_value()])).is_err());
+    }
+}
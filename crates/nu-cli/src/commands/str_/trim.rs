@@ -5,12 +5,16 @@ use nu_protocol::ShellTypeName;
 use nu_protocol::{
     ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value,
 };
-use nu_source::Tag;
+use nu_source::{Tag, Tagged};
 use nu_value_ext::ValueExt;
 
 #[derive(Deserialize)]
 struct Arguments {
     rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "char"))]
+    character: Option<Tagged<String>>,
+    left: bool,
+    right: bool,
 }
 
 pub struct SubCommand;
@@ -22,14 +26,23 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("str trim").rest(
-            SyntaxShape::ColumnPath,
-            "optionally trim text by column paths",
-        )
+        Signature::build("str trim")
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally trim text by column paths",
+            )
+            .named(
+                "char",
+                SyntaxShape::String,
+                "the characters to trim instead of whitespace",
+                Some('c'),
+            )
+            .switch("left", "trim only the start of the text", Some('l'))
+            .switch("right", "trim only the end of the text", Some('r'))
     }
 
     fn usage(&self) -> &str {
-        "trims text"
+        "trims whitespace or the given characters from both ends of text"
     }
 
     async fn run(
@@ -41,25 +54,50 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Trim contents",
-            example: "echo 'Nu shell ' | str trim",
-            result: Some(vec![Value::from("Nu shell")]),
-        }]
+        vec![
+            Example {
+                description: "Trim contents",
+                example: "echo 'Nu shell ' | str trim",
+                result: Some(vec![Value::from("Nu shell")]),
+            },
+            Example {
+                description: "Trim a specific character",
+                example: "echo 'xxNu shellxx' | str trim --char x",
+                result: Some(vec![Value::from("Nu shell")]),
+            },
+            Example {
+                description: "Trim only the start of the text",
+                example: "echo '--Nu shell--' | str trim --char - --left",
+                result: Some(vec![Value::from("Nu shell--")]),
+            },
+        ]
     }
 }
 
+#[derive(Clone)]
+struct Trim {
+    chars: Option<Vec<char>>,
+    left: bool,
+    right: bool,
+}
+
 fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let registry = registry.clone();
 
     let stream = async_stream! {
-        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+        let (Arguments { rest, character, left, right }, mut input) = args.process(&registry).await?;
 
         let column_paths: Vec<_> = rest.iter().map(|x| x.clone()).collect();
 
+        let options = Trim {
+            chars: character.map(|chars| chars.item.chars().collect()),
+            left: left || !right,
+            right: right || !left,
+        };
+
         while let Some(v) = input.next().await {
             if column_paths.is_empty() {
-                match action(&v, v.tag()) {
+                match action(&v, &options, v.tag()) {
                     Ok(out) => yield ReturnSuccess::value(out),
                     Err(err) => {
                         yield Err(err);
@@ -71,7 +109,8 @@ fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
                 let mut ret = v.clone();
 
                 for path in &column_paths {
-                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, old.tag())));
+                    let options = options.clone();
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, &options, old.tag())));
 
                     match swapping {
                         Ok(new_value) => {
@@ -92,11 +131,11 @@ fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
     Ok(stream.to_output_stream())
 }
 
-fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+fn action(input: &Value, options: &Trim, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
         | UntaggedValue::Primitive(Primitive::String(s)) => {
-            Ok(UntaggedValue::string(s.trim()).into_value(tag))
+            Ok(UntaggedValue::string(trim(s, options)).into_value(tag))
         }
         other => {
             let got = format!("got {}", other.type_name());
@@ -109,12 +148,40 @@ fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     }
 }
 
+/// Trims by character rather than by byte so multi-byte characters can be trimmed too
+fn trim<'a>(s: &'a str, options: &Trim) -> &'a str {
+    let should_trim = |c: char| match &options.chars {
+        Some(chars) => chars.contains(&c),
+        None => c.is_whitespace(),
+    };
+
+    let s = if options.left {
+        s.trim_start_matches(should_trim)
+    } else {
+        s
+    };
+
+    if options.right {
+        s.trim_end_matches(should_trim)
+    } else {
+        s
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{action, SubCommand};
+    use super::{action, SubCommand, Trim};
     use nu_plugin::test_helpers::value::string;
     use nu_source::Tag;
 
+    fn both_ends(chars: Option<&str>) -> Trim {
+        Trim {
+            chars: chars.map(|chars| chars.chars().collect()),
+            left: true,
+            right: true,
+        }
+    }
+
     #[test]
     fn examples_work_as_expected() {
         use crate::examples::test as test_examples;
@@ -127,7 +194,62 @@ mod tests {
         let word = string("andres ");
         let expected = string("andres");
 
-        let actual = action(&word, Tag::unknown()).unwrap();
+        let actual = action(&word, &both_ends(None), Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn trims_a_custom_character() {
+        let word = string("xxhelloxx");
+        let expected = string("hello");
+
+        let actual = action(&word, &both_ends(Some("x")), Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn trims_a_set_of_characters() {
+        let word = string("-=hello=-");
+        let expected = string("hello");
+
+        let actual = action(&word, &both_ends(Some("=-")), Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn trims_multi_byte_characters() {
+        let word = string("ééhelloéé");
+        let expected = string("hello");
+
+        let actual = action(&word, &both_ends(Some("é")), Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn trims_only_the_left() {
+        let word = string("xxhelloxx");
+        let expected = string("helloxx");
+
+        let left = Trim {
+            right: false,
+            ..both_ends(Some("x"))
+        };
+
+        let actual = action(&word, &left, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn trims_only_the_right() {
+        let word = string("  hello  ");
+        let expected = string("  hello");
+
+        let right = Trim {
+            left: false,
+            ..both_ends(None)
+        };
+
+        let actual = action(&word, &right, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
 }
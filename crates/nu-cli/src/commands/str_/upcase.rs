@@ -41,11 +41,18 @@ impl WholeStreamCommand for SubCommand {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Upcase contents",
-            example: "echo 'nu' | str upcase",
-            result: Some(vec![Value::from("NU")]),
-        }]
+        vec![
+            Example {
+                description: "Upcase contents",
+                example: "echo 'nu' | str upcase",
+                result: Some(vec![Value::from("NU")]),
+            },
+            Example {
+                description: "Upcase accented contents",
+                example: "echo 'héllo' | str upcase",
+                result: Some(vec![Value::from("HÉLLO")]),
+            },
+        ]
     }
 }
 
@@ -96,7 +103,7 @@ fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
     match &input.value {
         UntaggedValue::Primitive(Primitive::Line(s))
         | UntaggedValue::Primitive(Primitive::String(s)) => {
-            Ok(UntaggedValue::string(s.to_ascii_uppercase()).into_value(tag))
+            Ok(UntaggedValue::string(s.to_uppercase()).into_value(tag))
         }
         other => {
             let got = format!("got {}", other.type_name());
@@ -112,7 +119,7 @@ fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
 #[cfg(test)]
 mod tests {
     use super::{action, SubCommand};
-    use nu_plugin::test_helpers::value::string;
+    use nu_plugin::test_helpers::value::{int, string};
     use nu_source::Tag;
 
     #[test]
@@ -130,4 +137,27 @@ mod tests {
         let actual = action(&word, Tag::unknown()).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn upcases_accented_characters() {
+        let word = string("héllo");
+        let expected = string("HÉLLO");
+
+        let actual = action(&word, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn upcases_characters_that_grow() {
+        let word = string("straße");
+        let expected = string("STRASSE");
+
+        let actual = action(&word, Tag::unknown()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn errors_given_numbers() {
+        assert!(action(&int(5), Tag::unknown()).is_err());
+    }
 }
@@ -0,0 +1,172 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_value_ext::as_string;
+use serde_ini::{Item, LineEnding, Writer};
+
+pub struct ToINI;
+
+#[async_trait]
+impl WholeStreamCommand for ToINI {
+    fn name(&self) -> &str {
+        "to ini"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to ini")
+    }
+
+    fn usage(&self) -> &str {
+        "Convert table into .ini text"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        to_ini(args, registry)
+    }
+}
+
+/// Converts a row into INI text, writing its scalar columns as top-level keys
+/// and its row columns as sections
+pub fn value_to_ini_string(v: &Value) -> Result<String, ShellError> {
+    let dict = match &v.value {
+        UntaggedValue::Row(dict) => dict,
+        _ => {
+            return Err(ShellError::labeled_error(
+                "Expected a table with INI-compatible structure from pipeline",
+                "requires a row",
+                v.tag(),
+            ))
+        }
+    };
+
+    let mut items = vec![];
+    let mut sections = vec![];
+
+    // Keys written after a section would belong to it, so top-level keys go first
+    for (key, value) in dict.entries.iter() {
+        match &value.value {
+            UntaggedValue::Row(section) => sections.push((key, section)),
+            _ => items.push(Item::Value {
+                key: key.clone(),
+                value: scalar_to_ini_string(key, value)?,
+            }),
+        }
+    }
+
+    for (name, section) in sections {
+        if !items.is_empty() {
+            items.push(Item::Empty);
+        }
+
+        items.push(Item::Section { name: name.clone() });
+
+        for (key, value) in section.entries.iter() {
+            items.push(Item::Value {
+                key: key.clone(),
+                value: scalar_to_ini_string(key, value)?,
+            });
+        }
+    }
+
+    let mut writer = Writer::new(vec![], LineEnding::Linefeed);
+
+    for item in &items {
+        writer.write(item).map_err(|_| {
+            ShellError::labeled_error(
+                "Could not convert to INI",
+                "unable to write INI text",
+                v.tag(),
+            )
+        })?;
+    }
+
+    String::from_utf8(writer.into_inner()).map_err(|_| {
+        ShellError::labeled_error(
+            "Could not convert to INI",
+            "INI text is not valid utf-8",
+            v.tag(),
+        )
+    })
+}
+
+fn scalar_to_ini_string(key: &str, value: &Value) -> Result<String, ShellError> {
+    match &value.value {
+        UntaggedValue::Row(_) | UntaggedValue::Table(_) => Err(ShellError::labeled_error(
+            "INI can only hold one level of sections",
+            format!("'{}' is nested too deeply to be written as INI", key),
+            value.tag(),
+        )),
+        _ => as_string(value),
+    }
+}
+
+fn to_ini(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let stream = async_stream! {
+        let args = args.evaluate_once(&registry).await?;
+        let name_tag = args.name_tag();
+        let input: Vec<Value> = args.input.collect().await;
+
+        for value in input {
+            match value_to_ini_string(&value) {
+                Ok(ini) => yield ReturnSuccess::value(
+                    UntaggedValue::Primitive(Primitive::String(ini)).into_value(&name_tag),
+                ),
+                Err(err) => yield Err(err),
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{value_to_ini_string, ToINI};
+    use indexmap::{indexmap, IndexMap};
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_protocol::{UntaggedValue, Value};
+
+    fn row(entries: IndexMap<String, Value>) -> Value {
+        UntaggedValue::row(entries).into_untagged_value()
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(ToINI {})
+    }
+
+    #[test]
+    fn writes_top_level_keys_before_sections() {
+        let value = row(indexmap! {
+            "server".into() => row(indexmap! {
+                "host".into() => string("localhost"),
+                "port".into() => int(8080),
+            }),
+            "name".into() => string("nu"),
+        });
+
+        assert_eq!(
+            value_to_ini_string(&value).unwrap(),
+            "name=nu\n\n[server]\nhost=localhost\nport=8080\n"
+        );
+    }
+
+    #[test]
+    fn errors_on_structures_nested_more_than_one_level() {
+        let value = row(indexmap! {
+            "server".into() => row(indexmap! {
+                "tls".into() => row(indexmap! {"enabled".into() => string("yes")}),
+            }),
+        });
+
+        assert!(value_to_ini_string(&value).is_err());
+    }
+}
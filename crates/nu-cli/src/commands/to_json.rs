@@ -59,6 +59,39 @@ impl WholeStreamCommand for ToJSON {
     }
 }
 
+pub struct ToJSONL;
+
+#[async_trait]
+impl WholeStreamCommand for ToJSONL {
+    fn name(&self) -> &str {
+        "to jsonl"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to jsonl")
+    }
+
+    fn usage(&self) -> &str {
+        "Converts table data into JSON text with one row per line."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        to_jsonl(args)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Outputs each value of this table as its own line of JSON text",
+            example: "echo [1 2 3] | to jsonl",
+            result: Some(vec![Value::from("1\n2\n3")]),
+        }]
+    }
+}
+
 pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
     Ok(match &v.value {
         UntaggedValue::Primitive(Primitive::Boolean(b)) => serde_json::Value::Bool(*b),
@@ -237,9 +270,45 @@ fn to_json(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
     Ok(stream.to_output_stream())
 }
 
+fn to_jsonl(args: CommandArgs) -> Result<OutputStream, ShellError> {
+    let name_tag = args.call_info.name_tag.clone();
+    let mut input = args.input;
+
+    let stream = async_stream! {
+        let mut lines = vec![];
+
+        while let Some(value) = input.next().await {
+            let line = value_to_json_value(&value)
+                .and_then(|json_value| serde_json::to_string(&json_value).map_err(|_| {
+                    ShellError::labeled_error_with_secondary(
+                        "Expected a table with JSON-compatible structure from pipeline",
+                        "requires JSON-compatible input",
+                        &name_tag,
+                        "originates from here",
+                        &value.tag,
+                    )
+                }));
+
+            match line {
+                Ok(line) => lines.push(line),
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            }
+        }
+
+        yield ReturnSuccess::value(
+            UntaggedValue::Primitive(Primitive::String(lines.join("\n"))).into_value(&name_tag),
+        )
+    };
+
+    Ok(stream.to_output_stream())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::ToJSON;
+    use super::{ToJSON, ToJSONL};
 
     #[test]
     fn examples_work_as_expected() {
@@ -247,4 +316,11 @@ mod tests {
 
         test_examples(ToJSON {})
     }
+
+    #[test]
+    fn jsonl_examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(ToJSONL {})
+    }
 }
@@ -46,7 +46,10 @@ fn helper(v: &Value) -> Result<toml::Value, ShellError> {
         UntaggedValue::Primitive(Primitive::Boolean(b)) => toml::Value::Boolean(*b),
         UntaggedValue::Primitive(Primitive::Bytes(b)) => toml::Value::Integer(*b as i64),
         UntaggedValue::Primitive(Primitive::Duration(d)) => toml::Value::Integer(*d as i64),
-        UntaggedValue::Primitive(Primitive::Date(d)) => toml::Value::String(d.to_string()),
+        UntaggedValue::Primitive(Primitive::Date(d)) => match d.to_rfc3339().parse() {
+            Ok(datetime) => toml::Value::Datetime(datetime),
+            Err(_) => toml::Value::String(d.to_string()),
+        },
         UntaggedValue::Primitive(Primitive::EndOfStream) => {
             toml::Value::String("<End of Stream>".to_string())
         }
@@ -117,6 +120,11 @@ pub fn value_to_toml_value(v: &Value) -> Result<toml::Value, ShellError> {
                 )
             })
         }
+        UntaggedValue::Table(_) => Err(ShellError::labeled_error(
+            "Can not convert a list to TOML",
+            "a TOML document has to be a row, not a list",
+            v.tag(),
+        )),
         _ => Err(ShellError::labeled_error(
             format!("{:?} is not a valid top-level TOML", v.value),
             "invalid TOML",
@@ -169,10 +177,7 @@ fn to_toml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream
                         )),
                     }
                 }
-                _ => yield Err(ShellError::labeled_error(
-                    "Expected a table with TOML-compatible structure from pipeline",
-                    "requires TOML-compatible input",
-                    &name_tag))
+                Err(err) => yield Err(err),
             }
         }
     };
@@ -247,4 +252,40 @@ mod tests {
         value_to_toml_value(&UntaggedValue::Table(vec![Value::from("1")]).into_untagged_value())
             .expect_err("Expected non-valid toml (Table) to cause error!");
     }
+
+    #[test]
+    fn writes_dates_as_toml_datetimes() {
+        use chrono::{TimeZone, Utc};
+
+        let mut m = indexmap::IndexMap::new();
+        m.insert(
+            "released".to_owned(),
+            UntaggedValue::date(Utc.ymd(2019, 8, 23).and_hms(18, 0, 0)).into_untagged_value(),
+        );
+
+        let tv = value_to_toml_value(&UntaggedValue::Row(Dictionary::new(m)).into_untagged_value())
+            .expect("Expected Ok from a row with a date");
+
+        match tv.get("released") {
+            Some(toml::Value::Datetime(datetime)) => {
+                assert_eq!(datetime.to_string(), "2019-08-23T18:00:00+00:00")
+            }
+            other => panic!("expected a TOML datetime, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn keeps_the_order_of_the_columns() {
+        let mut m = indexmap::IndexMap::new();
+        m.insert("zeta".to_owned(), Value::from("last"));
+        m.insert("alpha".to_owned(), Value::from("first"));
+
+        let tv = value_to_toml_value(&UntaggedValue::Row(Dictionary::new(m)).into_untagged_value())
+            .expect("Expected Ok from valid TOML dictionary");
+
+        assert_eq!(
+            toml::to_string(&tv).expect("Expected TOML text"),
+            "zeta = \"last\"\nalpha = \"first\"\n"
+        );
+    }
 }
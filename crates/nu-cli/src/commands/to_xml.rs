@@ -0,0 +1,190 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_value_ext::as_string;
+
+pub struct ToXML;
+
+#[async_trait]
+impl WholeStreamCommand for ToXML {
+    fn name(&self) -> &str {
+        "to xml"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to xml")
+    }
+
+    fn usage(&self) -> &str {
+        "Convert table into .xml text"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        to_xml(args, registry)
+    }
+}
+
+/// Converts the row structure created by `from xml` back into XML text
+pub fn value_to_xml_string(v: &Value) -> Result<String, ShellError> {
+    let mut out = String::new();
+    write_element(v, &mut out)?;
+    Ok(out)
+}
+
+fn write_element(v: &Value, out: &mut String) -> Result<(), ShellError> {
+    let (name, element) = match &v.value {
+        UntaggedValue::Row(dict) if dict.entries.len() == 1 => match dict.entries.iter().next() {
+            Some((name, element)) => (name, element),
+            None => return Err(not_an_element(v)),
+        },
+        _ => return Err(not_an_element(v)),
+    };
+
+    let element = match &element.value {
+        UntaggedValue::Row(element) => element,
+        _ => return Err(not_an_element(v)),
+    };
+
+    out.push('<');
+    out.push_str(name);
+
+    if let Some(attributes) = element.entries.get("attributes") {
+        match &attributes.value {
+            UntaggedValue::Row(attributes) => {
+                for (key, value) in attributes.entries.iter() {
+                    out.push_str(&format!(" {}=\"{}\"", key, escape(&as_string(value)?)));
+                }
+            }
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Expected the attributes to be a row",
+                    "requires a row of attributes",
+                    attributes.tag(),
+                ))
+            }
+        }
+    }
+
+    out.push('>');
+
+    let children = match element.entries.get("children") {
+        Some(Value {
+            value: UntaggedValue::Table(children),
+            ..
+        }) => children.as_slice(),
+        Some(children) => {
+            return Err(ShellError::labeled_error(
+                "Expected the children to be a table",
+                "requires a table of children",
+                children.tag(),
+            ))
+        }
+        None => &[],
+    };
+
+    if children.is_empty() {
+        // Text is kept in the children too, so the content is only needed without them
+        if let Some(content) = element.entries.get("content") {
+            out.push_str(&escape(&as_string(content)?));
+        }
+    } else {
+        for child in children {
+            match &child.value {
+                UntaggedValue::Row(_) => write_element(child, out)?,
+                _ => out.push_str(&escape(&as_string(child)?)),
+            }
+        }
+    }
+
+    out.push_str(&format!("</{}>", name));
+
+    Ok(())
+}
+
+fn not_an_element(v: &Value) -> ShellError {
+    ShellError::labeled_error(
+        "Expected a table with XML-compatible structure from pipeline",
+        "requires a row with a single element name",
+        v.tag(),
+    )
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn to_xml(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let stream = async_stream! {
+        let args = args.evaluate_once(&registry).await?;
+        let name_tag = args.name_tag();
+        let input: Vec<Value> = args.input.collect().await;
+
+        for value in input {
+            match value_to_xml_string(&value) {
+                Ok(xml) => yield ReturnSuccess::value(
+                    UntaggedValue::Primitive(Primitive::String(xml)).into_value(&name_tag),
+                ),
+                Err(err) => yield Err(err),
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{value_to_xml_string, ToXML};
+    use crate::commands::from_xml::from_xml_string_to_value;
+    use nu_source::Tag;
+
+    fn round_trip(xml: &str) -> String {
+        let value = from_xml_string_to_value(xml.to_string(), Tag::unknown()).unwrap();
+        value_to_xml_string(&value).unwrap()
+    }
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(ToXML {})
+    }
+
+    #[test]
+    fn round_trips_elements_with_text() {
+        assert_eq!(round_trip("<a>b</a>"), "<a>b</a>");
+    }
+
+    #[test]
+    fn round_trips_nested_elements_with_attributes() {
+        let xml = r#"<nu version="2.0"><dev>Andrés</dev><dev>Jonathan</dev></nu>"#;
+
+        assert_eq!(round_trip(xml), xml);
+    }
+
+    #[test]
+    fn escapes_text_and_attribute_values() {
+        let xml = r#"<nu quote="&quot;hi&quot;">a &lt; b &amp; c</nu>"#;
+
+        assert_eq!(round_trip(xml), xml);
+    }
+}
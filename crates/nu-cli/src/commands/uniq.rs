@@ -1,12 +1,23 @@
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
-use indexmap::set::IndexSet;
+use indexmap::indexmap;
+use indexmap::map::IndexMap;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature};
+use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_value_ext::ValueExt;
 
 pub struct Uniq;
 
+#[derive(Deserialize)]
+pub struct UniqArgs {
+    count: bool,
+    repeated: bool,
+    duplicated: bool,
+    adjacent: bool,
+    by: Option<ColumnPath>,
+}
+
 #[async_trait]
 impl WholeStreamCommand for Uniq {
     fn name(&self) -> &str {
@@ -15,10 +26,36 @@ impl WholeStreamCommand for Uniq {
 
     fn signature(&self) -> Signature {
         Signature::build("uniq")
+            .switch(
+                "count",
+                "return a table of each value and the number of times it occurs",
+                Some('c'),
+            )
+            .switch(
+                "repeated",
+                "only return the values that occur more than once",
+                Some('d'),
+            )
+            .switch(
+                "duplicated",
+                "return every value that occurs more than once, keeping their order",
+                Some('D'),
+            )
+            .switch(
+                "adjacent",
+                "only compare each value with the one before it, streaming instead of buffering",
+                Some('a'),
+            )
+            .named(
+                "by",
+                SyntaxShape::ColumnPath,
+                "compare rows by the value at this column instead of the whole row",
+                Some('b'),
+            )
     }
 
     fn usage(&self) -> &str {
-        "Return the unique rows"
+        "Return the unique rows (buffers the whole input before returning anything, unless --adjacent)"
     }
 
     async fn run(
@@ -28,27 +65,282 @@ impl WholeStreamCommand for Uniq {
     ) -> Result<OutputStream, ShellError> {
         uniq(args, registry).await
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Remove duplicate values",
+                example: "echo [1 1 2 3 3 3] | uniq",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
+            Example {
+                description: "Count how many times each value occurs",
+                example: "echo [1 1 2 3 3 3] | uniq --count",
+                result: Some(vec![
+                    UntaggedValue::row(indexmap! {
+                        "value".to_string() => UntaggedValue::int(1).into(),
+                        "count".to_string() => UntaggedValue::int(2).into(),
+                    })
+                    .into(),
+                    UntaggedValue::row(indexmap! {
+                        "value".to_string() => UntaggedValue::int(2).into(),
+                        "count".to_string() => UntaggedValue::int(1).into(),
+                    })
+                    .into(),
+                    UntaggedValue::row(indexmap! {
+                        "value".to_string() => UntaggedValue::int(3).into(),
+                        "count".to_string() => UntaggedValue::int(3).into(),
+                    })
+                    .into(),
+                ]),
+            },
+            Example {
+                description: "Only return the values that are duplicated",
+                example: "echo [1 1 2 3 3 3] | uniq --repeated",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
+            Example {
+                description: "Return every value that is duplicated",
+                example: "echo [1 2 2 3 3 3] | uniq --duplicated",
+                result: Some(vec![
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(3).into(),
+                    UntaggedValue::int(3).into(),
+                ]),
+            },
+            Example {
+                description: "Remove rows with a duplicate value in a column",
+                example: r#"echo '[{"name": "a", "type": "A"}, {"name": "b", "type": "A"}]' | from json | uniq --by type"#,
+                result: None,
+            },
+            Example {
+                description: "Only collapse runs of the same value, like uniq after sort",
+                example: "echo [1 1 2 2 2 1] | uniq --adjacent",
+                result: Some(vec![
+                    UntaggedValue::int(1).into(),
+                    UntaggedValue::int(2).into(),
+                    UntaggedValue::int(1).into(),
+                ]),
+            },
+        ]
+    }
 }
 
-async fn uniq(args: CommandArgs, _registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    let input = args.input;
-    let uniq_values: IndexSet<_> = input.collect().await;
+async fn uniq(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+    let (
+        UniqArgs {
+            count,
+            repeated,
+            duplicated,
+            adjacent,
+            by,
+        },
+        input,
+    ) = args.process(&registry).await?;
+
+    if duplicated && (count || repeated) {
+        return Err(ShellError::labeled_error(
+            "Can not use --duplicated with --count or --repeated",
+            "remove --count and --repeated",
+            name,
+        ));
+    }
+
+    if adjacent {
+        return Ok(adjacent_runs(input, by, count, repeated, duplicated));
+    }
+
+    let values: Vec<Value> = input.collect().await;
+    let keyed = keyed_by(values, by.as_ref())?;
 
     let mut values_vec_deque = VecDeque::new();
 
-    for item in uniq_values
-        .iter()
-        .map(|row| ReturnSuccess::value(row.clone()))
-    {
-        values_vec_deque.push_back(item);
+    if duplicated {
+        let counts: IndexMap<UntaggedValue, usize> = tally(keyed.clone())
+            .into_iter()
+            .map(|(key, _, occurrences)| (key, occurrences))
+            .collect();
+
+        for (key, value) in keyed {
+            if counts.get(&key).is_some_and(|occurrences| *occurrences > 1) {
+                values_vec_deque.push_back(ReturnSuccess::value(value));
+            }
+        }
+
+        return Ok(futures::stream::iter(values_vec_deque).to_output_stream());
+    }
+
+    for (key, value, occurrences) in tally(keyed) {
+        if repeated && occurrences < 2 {
+            continue;
+        }
+
+        let item = if count {
+            counted(key, value, occurrences, by.is_some())
+        } else {
+            value
+        };
+
+        values_vec_deque.push_back(ReturnSuccess::value(item));
     }
 
     Ok(futures::stream::iter(values_vec_deque).to_output_stream())
 }
 
+fn counted(key: UntaggedValue, value: Value, occurrences: usize, by_column: bool) -> Value {
+    let tag = value.tag.clone();
+
+    // With --by the value counted is the one in the column, not the whole row
+    let value = if by_column {
+        key.into_value(&tag)
+    } else {
+        value
+    };
+
+    UntaggedValue::row(indexmap! {
+        "value".to_string() => value,
+        "count".to_string() => UntaggedValue::int(occurrences).into_value(&tag),
+    })
+    .into_value(tag)
+}
+
+/// A stretch of consecutive values with the same key. The values after the first are only kept
+/// for --duplicated, so counting a long run doesn't hold on to it.
+struct Run {
+    key: UntaggedValue,
+    first: Value,
+    rest: Vec<Value>,
+    occurrences: usize,
+}
+
+impl Run {
+    fn finish(self, by_column: bool, count: bool, repeated: bool, duplicated: bool) -> Vec<Value> {
+        if duplicated {
+            if self.occurrences > 1 {
+                let mut values = vec![self.first];
+                values.extend(self.rest);
+                return values;
+            }
+
+            return vec![];
+        }
+
+        if repeated && self.occurrences < 2 {
+            return vec![];
+        }
+
+        if count {
+            vec![counted(self.key, self.first, self.occurrences, by_column)]
+        } else {
+            vec![self.first]
+        }
+    }
+}
+
+fn adjacent_runs(
+    input: InputStream,
+    by: Option<ColumnPath>,
+    count: bool,
+    repeated: bool,
+    duplicated: bool,
+) -> OutputStream {
+    let stream = async_stream! {
+        let mut input = input;
+        let mut run: Option<Run> = None;
+
+        while let Some(value) = input.next().await {
+            let key = match key_of(&value, by.as_ref()) {
+                Ok(key) => key,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            if let Some(current) = run.as_mut().filter(|current| current.key == key) {
+                current.occurrences += 1;
+
+                if duplicated {
+                    current.rest.push(value);
+                }
+            } else {
+                let next = Run {
+                    key,
+                    first: value,
+                    rest: vec![],
+                    occurrences: 1,
+                };
+
+                if let Some(finished) = run.replace(next) {
+                    for item in finished.finish(by.is_some(), count, repeated, duplicated) {
+                        yield ReturnSuccess::value(item);
+                    }
+                }
+            }
+        }
+
+        if let Some(finished) = run {
+            for item in finished.finish(by.is_some(), count, repeated, duplicated) {
+                yield ReturnSuccess::value(item);
+            }
+        }
+    };
+
+    stream.to_output_stream()
+}
+
+fn key_of(value: &Value, by: Option<&ColumnPath>) -> Result<UntaggedValue, ShellError> {
+    // Keyed by the untagged value so the same value coming from different places is one
+    match by {
+        Some(path) => Ok(value
+            .get_data_by_column_path(path, Box::new(|(_, _, err)| err))?
+            .value),
+        None => Ok(value.value.clone()),
+    }
+}
+
+fn keyed_by(
+    values: Vec<Value>,
+    by: Option<&ColumnPath>,
+) -> Result<Vec<(UntaggedValue, Value)>, ShellError> {
+    values
+        .into_iter()
+        .map(|value| Ok((key_of(&value, by)?, value)))
+        .collect()
+}
+
+fn tally(keyed: Vec<(UntaggedValue, Value)>) -> Vec<(UntaggedValue, Value, usize)> {
+    let mut counts: IndexMap<UntaggedValue, (Value, usize)> = IndexMap::new();
+
+    for (key, value) in keyed {
+        counts.entry(key).or_insert_with(|| (value, 0)).1 += 1;
+    }
+
+    counts
+        .into_iter()
+        .map(|(key, (value, occurrences))| (key, value, occurrences))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Uniq;
+    use super::{keyed_by, tally, Run, Uniq};
+    use indexmap::indexmap;
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_protocol::{ColumnPath, PathMember, UntaggedValue};
+    use nu_source::{Span, SpannedItem};
+    use nu_value_ext::ValueExt;
 
     #[test]
     fn examples_work_as_expected() {
@@ -56,4 +348,68 @@ mod tests {
 
         test_examples(Uniq {})
     }
+
+    #[test]
+    fn tallies_values_in_first_seen_order() {
+        let values = vec![int(3), int(1), int(3), int(2), int(3), int(1)];
+        let keyed = keyed_by(values, None).expect("Could not key the values");
+
+        let actual: Vec<_> = tally(keyed)
+            .into_iter()
+            .map(|(_, value, occurrences)| (value, occurrences))
+            .collect();
+
+        assert_eq!(actual, vec![(int(3), 3), (int(1), 2), (int(2), 1)]);
+    }
+
+    #[test]
+    fn keys_rows_by_a_column() {
+        let values = vec![
+            UntaggedValue::row(
+                indexmap! {"name".into() => string("a"), "type".into() => string("A")},
+            )
+            .into_untagged_value(),
+            UntaggedValue::row(
+                indexmap! {"name".into() => string("b"), "type".into() => string("A")},
+            )
+            .into_untagged_value(),
+        ];
+        let by = ColumnPath::new(vec![PathMember::string("type", Span::unknown())]);
+
+        let keyed = keyed_by(values, Some(&by)).expect("Could not key the rows");
+        let tallied = tally(keyed);
+
+        assert_eq!(tallied.len(), 1);
+        assert_eq!(tallied[0].2, 2);
+    }
+
+    #[test]
+    fn errors_keying_by_a_missing_column() {
+        let values = vec![
+            UntaggedValue::row(indexmap! {"name".into() => string("a")}).into_untagged_value()
+        ];
+        let by = ColumnPath::new(vec![PathMember::string("type", Span::unknown())]);
+
+        assert!(keyed_by(values, Some(&by)).is_err());
+    }
+
+    #[test]
+    fn counts_a_run_without_keeping_its_values() {
+        let run = Run {
+            key: int(7).value,
+            first: int(7),
+            rest: vec![],
+            occurrences: 3,
+        };
+
+        let counted = run.finish(false, true, false, false);
+
+        assert_eq!(counted.len(), 1);
+        assert_eq!(
+            counted[0]
+                .get_data_by_key("count".spanned_unknown())
+                .map(|count| count.value),
+            Some(UntaggedValue::int(3))
+        );
+    }
 }
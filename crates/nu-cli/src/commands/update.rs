@@ -4,6 +4,7 @@ use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
 use nu_protocol::{ColumnPath, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::span_for_spanned_list;
 use nu_value_ext::ValueExt;
 
 use futures::stream::once;
@@ -31,12 +32,12 @@ impl WholeStreamCommand for Update {
             .required(
                 "replacement value",
                 SyntaxShape::Any,
-                "the new value to give the cell(s)",
+                "the new value to give the cell(s), or a block run with each row as $it",
             )
     }
 
     fn usage(&self) -> &str {
-        "Update an existing column to have a new value."
+        "Update an existing column to have a new value. Columns are never added, use insert for that."
     }
 
     async fn run(
@@ -46,6 +47,31 @@ impl WholeStreamCommand for Update {
     ) -> Result<OutputStream, ShellError> {
         update(args, registry)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Update a column with a new value",
+                example: "ls | update modified never",
+                result: None,
+            },
+            Example {
+                description: "Update a column with the output of a block run for each row",
+                example: r#"echo '[{"price": 10}, {"price": 25}]' | from json | update price {= $it.price * 2}"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn column_not_found(field: &ColumnPath, tag: impl Into<Tag>) -> ShellError {
+    ShellError::labeled_error_with_secondary(
+        "Unknown column",
+        "there isn't a column here to update",
+        span_for_spanned_list(field.members().iter().map(|p| p.span)),
+        "in this row (use insert to add columns)",
+        tag.into().span,
+    )
 }
 
 fn update(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
@@ -89,13 +115,7 @@ fn update(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
                                     if let Some(result) = stream.next().await {
                                         match obj.replace_data_at_column_path(&field, result.clone()) {
                                             Some(v) => yield Ok(ReturnSuccess::Value(v)),
-                                            None => {
-                                                yield Err(ShellError::labeled_error(
-                                                    "update could not find place to insert column",
-                                                    "column name",
-                                                    obj.tag,
-                                                ))
-                                            }
+                                            None => yield Err(column_not_found(&field, obj.tag)),
                                         }
                                     }
                                 }
@@ -120,13 +140,7 @@ fn update(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
                             ..
                         } => match obj.replace_data_at_column_path(&field, replacement.clone()) {
                             Some(v) => yield Ok(ReturnSuccess::Value(v)),
-                            None => {
-                                yield Err(ShellError::labeled_error(
-                                    "update could not find place to insert column",
-                                    "column name",
-                                    obj.tag,
-                                ))
-                            }
+                            None => yield Err(column_not_found(&field, obj.tag)),
                         },
                         Value { tag, ..} => {
                             yield Err(ShellError::labeled_error(
@@ -135,7 +149,6 @@ fn update(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStr
                                 tag,
                             ))
                         }
-                        _ => {}
                     }
                 }
             }
@@ -0,0 +1,218 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Primitive, ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_source::SpannedItem;
+use nu_value_ext::get_data_by_key;
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "url join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("url join")
+    }
+
+    fn usage(&self) -> &str {
+        "joins a row of url parts, like the ones from url parse, back into a url"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        _registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(args
+            .input
+            .map(|row| match action(&row) {
+                Ok(url) => ReturnSuccess::value(url),
+                Err(err) => Err(err),
+            })
+            .to_output_stream())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Join the parts of a url back together",
+                example: "echo 'https://x.com/a?b=1#c' | url parse | url join",
+                result: None,
+            },
+            Example {
+                description: "Build a url from a row",
+                example: r#"echo '{"scheme": "https", "host": "x.com", "path": "/a", "query": {"b": 1}}' | from json | url join"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn action(row: &Value) -> Result<Value, ShellError> {
+    let tag = row.tag.clone();
+
+    if let UntaggedValue::Row(_) = &row.value {
+    } else {
+        return Err(ShellError::labeled_error(
+            "Expected a row of url parts",
+            "value is not a row",
+            tag.span,
+        ));
+    }
+
+    let scheme = text(row, "scheme")?.ok_or_else(|| {
+        ShellError::labeled_error("Could not join the url", "the row needs a scheme", tag.span)
+    })?;
+
+    let mut out = format!("{}:", scheme);
+
+    if let Some(host) = text(row, "host")? {
+        out.push_str("//");
+
+        if let Some(username) = text(row, "username")? {
+            out.push_str(&username);
+
+            if let Some(password) = text(row, "password")? {
+                out.push(':');
+                out.push_str(&password);
+            }
+
+            out.push('@');
+        }
+
+        out.push_str(&host);
+
+        if let Some(port) = part(row, "port") {
+            out.push(':');
+            out.push_str(&port.convert_to_string());
+        }
+    }
+
+    if let Some(path) = text(row, "path")? {
+        out.push_str(&path);
+    }
+
+    if let Some(query) = part(row, "query") {
+        out.push('?');
+
+        match &query.value {
+            UntaggedValue::Row(pairs) => {
+                let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+
+                for (key, value) in pairs.entries.iter() {
+                    match &value.value {
+                        UntaggedValue::Table(values) => {
+                            for value in values {
+                                serializer.append_pair(key, &value.convert_to_string());
+                            }
+                        }
+                        _ => {
+                            serializer.append_pair(key, &value.convert_to_string());
+                        }
+                    }
+                }
+
+                out.push_str(&serializer.finish());
+            }
+            _ => out.push_str(&query.as_string()?),
+        }
+    }
+
+    if let Some(fragment) = text(row, "fragment")? {
+        out.push('#');
+        out.push_str(&fragment);
+    }
+
+    let url = url::Url::parse(&out).map_err(|err| {
+        ShellError::labeled_error(
+            "Could not join the url",
+            format!("{} in {}", err, out),
+            tag.span,
+        )
+    })?;
+
+    Ok(UntaggedValue::string(url.into_string()).into_value(tag))
+}
+
+/// The value of a url part, where a missing column and nothing both mean the part is absent
+fn part(row: &Value, name: &str) -> Option<Value> {
+    match get_data_by_key(row, name.spanned_unknown()) {
+        Some(Value {
+            value: UntaggedValue::Primitive(Primitive::Nothing),
+            ..
+        }) => None,
+        other => other,
+    }
+}
+
+fn text(row: &Value, name: &str) -> Result<Option<String>, ShellError> {
+    match part(row, name) {
+        Some(value) => Ok(Some(value.as_string()?)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use crate::commands::url_::parse;
+    use indexmap::indexmap;
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_protocol::UntaggedValue;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn joins_the_parts_of_a_url() {
+        let row = UntaggedValue::row(indexmap! {
+            "scheme".into() => string("https"),
+            "username".into() => string("u"),
+            "password".into() => string("p"),
+            "host".into() => string("x.com"),
+            "port".into() => int(8080),
+            "path".into() => string("/a"),
+            "query".into() => UntaggedValue::row(indexmap! {
+                "q".into() => string("a b"),
+                "r".into() => string("&"),
+            }).into_untagged_value(),
+            "fragment".into() => string("c"),
+        })
+        .into_untagged_value();
+
+        let actual = action(&row).unwrap();
+        assert_eq!(actual, string("https://u:p@x.com:8080/a?q=a+b&r=%26#c"));
+    }
+
+    #[test]
+    fn round_trips_parsed_urls() {
+        for url in &[
+            "https://x.com/a?b=1#c",
+            "https://u:p@x.com:8080/a%20b?q=a+b&r=%26",
+            "https://x.com/?a=1&a=2&b=3",
+            "mailto:me@x.com",
+            "file:///tmp/nu.txt",
+        ] {
+            let parsed = parse::action(&string(*url), nu_source::Tag::unknown()).unwrap();
+
+            assert_eq!(action(&parsed).unwrap(), string(*url));
+        }
+    }
+
+    #[test]
+    fn errors_without_a_scheme() {
+        let row = UntaggedValue::row(indexmap! {
+            "host".into() => string("x.com"),
+        })
+        .into_untagged_value();
+
+        assert!(action(&row).is_err());
+    }
+}
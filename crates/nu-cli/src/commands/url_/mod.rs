@@ -0,0 +1,7 @@
+mod command;
+mod join;
+mod parse;
+
+pub use command::Command as Url;
+pub use join::SubCommand as UrlJoin;
+pub use parse::SubCommand as UrlParse;
@@ -0,0 +1,277 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::ShellTypeName;
+use nu_protocol::{
+    ColumnPath, Primitive, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue,
+    Value,
+};
+use nu_source::Tag;
+use nu_value_ext::ValueExt;
+
+#[derive(Deserialize)]
+struct Arguments {
+    rest: Vec<ColumnPath>,
+}
+
+pub struct SubCommand;
+
+#[async_trait]
+impl WholeStreamCommand for SubCommand {
+    fn name(&self) -> &str {
+        "url parse"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("url parse").rest(
+            SyntaxShape::ColumnPath,
+            "optionally parse urls by column paths",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "parses a url into a row of its parts"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        operate(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Parse a url into its parts",
+                example: "echo 'https://x.com/a?b=1#c' | url parse",
+                result: Some(vec![parsed(
+                    "https",
+                    "x.com",
+                    "/a",
+                    Some(("b", "1")),
+                    Some("c"),
+                )]),
+            },
+            Example {
+                description: "Parse the urls in a column",
+                example: r#"echo '{"link": "https://www.nushell.sh/book"}' | from json | url parse link"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn parsed(
+    scheme: &str,
+    host: &str,
+    path: &str,
+    query: Option<(&str, &str)>,
+    fragment: Option<&str>,
+) -> Value {
+    let mut row = TaggedDictBuilder::new(Tag::unknown());
+
+    row.insert_untagged("scheme", UntaggedValue::string(scheme));
+    row.insert_untagged("username", UntaggedValue::nothing());
+    row.insert_untagged("password", UntaggedValue::nothing());
+    row.insert_untagged("host", UntaggedValue::string(host));
+    row.insert_untagged("port", UntaggedValue::nothing());
+    row.insert_untagged("path", UntaggedValue::string(path));
+
+    match query {
+        Some((key, value)) => {
+            let mut pairs = IndexMap::new();
+            pairs.insert(key.to_string(), UntaggedValue::string(value).into());
+            row.insert_untagged("query", UntaggedValue::row(pairs));
+        }
+        None => row.insert_untagged("query", UntaggedValue::nothing()),
+    }
+
+    match fragment {
+        Some(fragment) => row.insert_untagged("fragment", UntaggedValue::string(fragment)),
+        None => row.insert_untagged("fragment", UntaggedValue::nothing()),
+    }
+
+    row.into_value()
+}
+
+fn operate(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+
+    let stream = async_stream! {
+        let (Arguments { rest }, mut input) = args.process(&registry).await?;
+
+        while let Some(v) = input.next().await {
+            if rest.is_empty() {
+                match action(&v, v.tag()) {
+                    Ok(out) => yield ReturnSuccess::value(out),
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            } else {
+                let mut ret = v.clone();
+
+                for path in &rest {
+                    let swapping = ret.swap_data_by_column_path(path, Box::new(move |old| action(old, old.tag())));
+
+                    match swapping {
+                        Ok(new_value) => {
+                            ret = new_value;
+                        }
+                        Err(err) => {
+                            yield Err(err);
+                            return;
+                        }
+                    }
+                }
+
+                yield ReturnSuccess::value(ret);
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+pub(super) fn action(input: &Value, tag: impl Into<Tag>) -> Result<Value, ShellError> {
+    let tag = tag.into();
+
+    let s = match &input.value {
+        UntaggedValue::Primitive(Primitive::Line(s))
+        | UntaggedValue::Primitive(Primitive::String(s)) => s.trim(),
+        other => {
+            let got = format!("got {}", other.type_name());
+            return Err(ShellError::labeled_error(
+                "value is not string",
+                got,
+                tag.span,
+            ));
+        }
+    };
+
+    let url = url::Url::parse(s).map_err(|err| {
+        ShellError::labeled_error("Could not parse the url", err.to_string(), tag.span)
+    })?;
+
+    let mut row = TaggedDictBuilder::new(&tag);
+
+    row.insert_untagged("scheme", UntaggedValue::string(url.scheme()));
+    row.insert_untagged(
+        "username",
+        optional(Some(url.username()).filter(|u| !u.is_empty())),
+    );
+    row.insert_untagged("password", optional(url.password()));
+    row.insert_untagged("host", optional(url.host_str()));
+    row.insert_untagged(
+        "port",
+        match url.port() {
+            Some(port) => UntaggedValue::int(port),
+            None => UntaggedValue::nothing(),
+        },
+    );
+    row.insert_untagged("path", UntaggedValue::string(url.path()));
+
+    match url.query() {
+        Some(_) => row.insert_value("query", query(&url, &tag)),
+        None => row.insert_untagged("query", UntaggedValue::nothing()),
+    }
+
+    row.insert_untagged("fragment", optional(url.fragment()));
+
+    Ok(row.into_value())
+}
+
+/// The query pairs as a row, where a key given more than once gets the list of its values
+fn query(url: &url::Url, tag: &Tag) -> Value {
+    let mut values: IndexMap<String, Vec<String>> = IndexMap::new();
+
+    for (key, value) in url.query_pairs() {
+        values
+            .entry(key.to_string())
+            .or_default()
+            .push(value.to_string());
+    }
+
+    let mut pairs = TaggedDictBuilder::new(tag);
+
+    for (key, mut values) in values {
+        if values.len() == 1 {
+            pairs.insert_untagged(key, UntaggedValue::string(values.remove(0)));
+        } else {
+            let values = values
+                .into_iter()
+                .map(|value| UntaggedValue::string(value).into_value(tag))
+                .collect();
+            pairs.insert_untagged(key, UntaggedValue::Table(values));
+        }
+    }
+
+    pairs.into_value()
+}
+
+fn optional(part: Option<&str>) -> UntaggedValue {
+    match part {
+        Some(part) => UntaggedValue::string(part),
+        None => UntaggedValue::nothing(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{action, SubCommand};
+    use nu_plugin::test_helpers::value::{int, string};
+    use nu_source::Tag;
+    use nu_value_ext::ValueExt;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn parses_the_user_and_port() {
+        let actual = action(&string("https://u:p@x.com:8080/"), Tag::unknown()).unwrap();
+
+        assert_eq!(*actual.get_data("username").borrow(), string("u"));
+        assert_eq!(*actual.get_data("password").borrow(), string("p"));
+        assert_eq!(*actual.get_data("port").borrow(), int(8080));
+    }
+
+    #[test]
+    fn decodes_query_pairs() {
+        let actual = action(&string("https://x.com/?q=a+b&r=%26"), Tag::unknown()).unwrap();
+        let query = actual.get_data("query");
+
+        assert_eq!(*query.borrow().get_data("q").borrow(), string("a b"));
+        assert_eq!(*query.borrow().get_data("r").borrow(), string("&"));
+    }
+
+    #[test]
+    fn keeps_every_value_of_repeated_keys() {
+        let actual = action(&string("https://x.com/?a=1&b=2&a=3"), Tag::unknown()).unwrap();
+        let query = actual.get_data("query");
+
+        assert_eq!(
+            query
+                .borrow()
+                .get_data("a")
+                .borrow()
+                .table_entries()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![string("1"), string("3")]
+        );
+        assert_eq!(*query.borrow().get_data("b").borrow(), string("2"));
+    }
+
+    #[test]
+    fn errors_given_relative_urls() {
+        assert!(action(&string("x.com/a"), Tag::unknown()).is_err());
+    }
+}
@@ -3,7 +3,11 @@ use crate::context::CommandRegistry;
 use crate::evaluate::evaluate_baseline_expr;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{hir::Block, hir::ClassifiedCommand, ReturnSuccess, Signature, SyntaxShape};
+use nu_protocol::{
+    hir::Block, hir::ClassifiedCommand, hir::Expression, hir::SpannedExpression, hir::Variable,
+    PathMember, ReturnSuccess, Signature, SyntaxShape, Value,
+};
+use nu_value_ext::get_data_by_member;
 
 pub struct Where;
 
@@ -27,7 +31,7 @@ impl WholeStreamCommand for Where {
     }
 
     fn usage(&self) -> &str {
-        "Filter table to match the condition."
+        "Filter table to match the condition. Rows missing a column used in the condition don't match."
     }
 
     async fn run(
@@ -60,9 +64,53 @@ impl WholeStreamCommand for Where {
                 example: "ls | where modified <= 2M",
                 result: None,
             },
+            Example {
+                description: "Keep only the people older than 18",
+                example: r#"echo '[{"name": "Ann", "age": 20}, {"name": "Bob", "age": 10}]' | from json | where age > 18"#,
+                result: None,
+            },
         ]
     }
 }
+
+/// Collects the column paths the condition reads from `$it`, eg. `size` in `where size > 10mb`.
+fn it_column_paths(expr: &SpannedExpression, paths: &mut Vec<Vec<PathMember>>) {
+    match &expr.expr {
+        Expression::Path(path) => {
+            if let Expression::Variable(Variable::It(_)) = path.head.expr {
+                if !path.tail.is_empty() {
+                    paths.push(path.tail.clone());
+                }
+            } else {
+                it_column_paths(&path.head, paths);
+            }
+        }
+        Expression::Binary(binary) => {
+            it_column_paths(&binary.left, paths);
+            it_column_paths(&binary.right, paths);
+        }
+        Expression::List(list) => {
+            for item in list {
+                it_column_paths(item, paths);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn has_column_path(value: &Value, path: &[PathMember]) -> bool {
+    let mut current = value.clone();
+
+    for member in path {
+        match get_data_by_member(&current, member) {
+            Ok(next) => current = next,
+            Err(_) => return false,
+        }
+    }
+
+    true
+}
+
 fn where_command(
     raw_args: CommandArgs,
     registry: &CommandRegistry,
@@ -104,8 +152,14 @@ fn where_command(
             }
         };
 
+        let mut paths = vec![];
+        it_column_paths(&condition, &mut paths);
+
         let mut input = input;
         while let Some(input) = input.next().await {
+            if !paths.iter().all(|path| has_column_path(&input, path)) {
+                continue;
+            }
 
             //FIXME: should we use the scope that's brought in as well?
             let condition = evaluate_baseline_expr(&condition, &registry, &input, &scope.vars, &scope.env).await?;
@@ -0,0 +1,178 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_source::Tagged;
+
+#[derive(Deserialize)]
+struct WindowArgs {
+    size: Tagged<u64>,
+    stride: Option<Tagged<u64>>,
+    remainder: bool,
+}
+
+pub struct Window;
+
+#[async_trait]
+impl WholeStreamCommand for Window {
+    fn name(&self) -> &str {
+        "window"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("window")
+            .required(
+                "size",
+                SyntaxShape::Int,
+                "the number of rows in each window",
+            )
+            .named(
+                "stride",
+                SyntaxShape::Int,
+                "the number of rows to slide the window by (defaults to 1)",
+                Some('s'),
+            )
+            .switch(
+                "remainder",
+                "return the last window even when it has fewer rows than the size",
+                Some('r'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Creates tables of consecutive rows sliding over the input"
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        window(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Slide a window of two rows over the input",
+                example: "echo [1 2 3 4] | window 2",
+                result: Some(vec![
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(1).into(),
+                        UntaggedValue::int(2).into(),
+                    ])
+                    .into(),
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(2).into(),
+                        UntaggedValue::int(3).into(),
+                    ])
+                    .into(),
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(3).into(),
+                        UntaggedValue::int(4).into(),
+                    ])
+                    .into(),
+                ]),
+            },
+            Example {
+                description: "Slide the window by two rows, keeping the shorter last window",
+                example: "echo [1 2 3 4 5] | window 2 --stride 2 --remainder",
+                result: Some(vec![
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(1).into(),
+                        UntaggedValue::int(2).into(),
+                    ])
+                    .into(),
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(3).into(),
+                        UntaggedValue::int(4).into(),
+                    ])
+                    .into(),
+                    UntaggedValue::table(&[UntaggedValue::int(5).into()]).into(),
+                ]),
+            },
+        ]
+    }
+}
+
+fn window(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let name = args.call_info.name_tag.clone();
+
+    let stream = async_stream! {
+        let (WindowArgs { size, stride, remainder }, mut input) = args.process(&registry).await?;
+
+        if size.item == 0 {
+            yield Err(ShellError::labeled_error(
+                "Window size must be positive",
+                "expected a size greater than zero",
+                size.tag,
+            ));
+            return;
+        }
+
+        let stride = match stride {
+            Some(stride) if stride.item == 0 => {
+                yield Err(ShellError::labeled_error(
+                    "Window stride must be positive",
+                    "expected a stride greater than zero",
+                    stride.tag,
+                ));
+                return;
+            }
+            Some(stride) => stride.item as usize,
+            None => 1,
+        };
+
+        let size = size.item as usize;
+
+        let mut buffer: VecDeque<Value> = VecDeque::with_capacity(size);
+        // Rows that have not been part of any window yet
+        let mut fresh = 0;
+        // Rows still to be skipped when the stride is larger than the window
+        let mut skip = 0;
+
+        while let Some(item) = input.next().await {
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+
+            buffer.push_back(item);
+            fresh += 1;
+
+            if buffer.len() == size {
+                let rows: Vec<Value> = buffer.iter().cloned().collect();
+                yield ReturnSuccess::value(UntaggedValue::table(&rows).into_value(&name));
+
+                fresh = 0;
+
+                for _ in 0..stride.min(size) {
+                    buffer.pop_front();
+                }
+
+                skip = stride.saturating_sub(size);
+            }
+        }
+
+        if remainder && fresh > 0 {
+            let rows: Vec<Value> = buffer.into_iter().collect();
+            yield ReturnSuccess::value(UntaggedValue::table(&rows).into_value(&name));
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Window;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Window {})
+    }
+}
@@ -3,10 +3,10 @@ use crate::context::CommandRegistry;
 use crate::prelude::*;
 use indexmap::{indexmap, IndexMap};
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue};
 use nu_source::Tagged;
 
-const DEFAULT_COLUMN_NAME: &str = "Column";
+const DEFAULT_COLUMN_NAME: &str = "Column1";
 
 pub struct Wrap;
 
@@ -30,7 +30,7 @@ impl WholeStreamCommand for Wrap {
     }
 
     fn usage(&self) -> &str {
-        "Wraps the given data in a table."
+        "Wraps each value (or row) in a row under the given column."
     }
 
     async fn run(
@@ -38,7 +38,6 @@ impl WholeStreamCommand for Wrap {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        // args.process(registry, wrap)?.run()
         wrap(args, registry)
     }
 
@@ -89,55 +88,19 @@ fn wrap(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, S
 
     let stream = async_stream! {
         let (WrapArgs { column }, mut input) = args.process(&registry).await?;
-        let mut result_table = vec![];
-        let mut are_all_rows = true;
+
+        let column = match column {
+            Some(key) => key.item,
+            None => DEFAULT_COLUMN_NAME.to_string(),
+        };
 
         while let Some(value) = input.next().await {
-            match value {
-                Value {
-                    value: UntaggedValue::Row(_),
-                    ..
-                } => {
-                    result_table.push(value);
-                }
-                _ => {
-                    are_all_rows = false;
-
-                    let mut index_map = IndexMap::new();
-                    index_map.insert(
-                        match &column {
-                            Some(key) => key.item.clone(),
-                            None => DEFAULT_COLUMN_NAME.to_string(),
-                        },
-                        value,
-                    );
-
-                    result_table.push(UntaggedValue::row(index_map).into_value(Tag::unknown()));
-                }
-
-            }
-        }
+            let tag = value.tag.clone();
 
-        if are_all_rows {
             let mut index_map = IndexMap::new();
-            index_map.insert(
-                match &column {
-                    Some(key) => key.item.clone(),
-                    None => DEFAULT_COLUMN_NAME.to_string(),
-                },
-                UntaggedValue::table(&result_table).into_value(Tag::unknown()),
-            );
-
-            let row = UntaggedValue::row(index_map).into_untagged_value();
-
-            yield ReturnSuccess::value(row);
-        } else {
-            for item in result_table
-                .iter()
-                .map(|row| ReturnSuccess::value(row.clone())) {
-
-                yield item;
-            }
+            index_map.insert(column.clone(), value);
+
+            yield ReturnSuccess::value(UntaggedValue::row(index_map).into_value(tag));
         }
     };
 
@@ -0,0 +1,162 @@
+use crate::commands::classified::block::run_block;
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct Zip;
+
+#[derive(Deserialize)]
+pub struct ZipArgs {
+    other: Value,
+    longest: bool,
+}
+
+#[async_trait]
+impl WholeStreamCommand for Zip {
+    fn name(&self) -> &str {
+        "zip"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("zip")
+            .required(
+                "other",
+                SyntaxShape::Any,
+                "the list, or a block producing the list, to pair with the input",
+            )
+            .switch(
+                "longest",
+                "keep going until both sides run out, filling the shorter one with nothing",
+                Some('l'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Pair each row of the input with the row at the same position in another list."
+    }
+
+    async fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        zip(args, registry)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pair the numbers with the letters",
+                example: "echo [1 2 3] | zip [a b c]",
+                result: Some(vec![
+                    UntaggedValue::table(&[UntaggedValue::int(1).into(), Value::from("a")]).into(),
+                    UntaggedValue::table(&[UntaggedValue::int(2).into(), Value::from("b")]).into(),
+                    UntaggedValue::table(&[UntaggedValue::int(3).into(), Value::from("c")]).into(),
+                ]),
+            },
+            Example {
+                description: "Pair the numbers with the letters, filling in the missing letters",
+                example: "echo [1 2 3] | zip [a b] --longest",
+                result: Some(vec![
+                    UntaggedValue::table(&[UntaggedValue::int(1).into(), Value::from("a")]).into(),
+                    UntaggedValue::table(&[UntaggedValue::int(2).into(), Value::from("b")]).into(),
+                    UntaggedValue::table(&[
+                        UntaggedValue::int(3).into(),
+                        UntaggedValue::nothing().into(),
+                    ])
+                    .into(),
+                ]),
+            },
+        ]
+    }
+}
+
+fn zip(raw_args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
+    let registry = registry.clone();
+    let scope = raw_args.call_info.scope.clone();
+
+    let stream = async_stream! {
+        let mut context = Context::from_raw(&raw_args, &registry);
+        let (ZipArgs { other, longest }, mut input) = raw_args.process(&registry).await?;
+
+        // The other side is gathered up front so the input can keep streaming
+        let others = match other {
+            Value {
+                value: UntaggedValue::Block(block),
+                tag,
+            } => {
+                let result = run_block(
+                    &block,
+                    &mut context,
+                    InputStream::empty(),
+                    &UntaggedValue::nothing().into_value(&tag),
+                    &scope.vars,
+                    &scope.env,
+                ).await;
+
+                match result {
+                    Ok(mut stream) => {
+                        let values = stream.drain_vec().await;
+
+                        if let Some(error) = context.get_errors().first() {
+                            yield Err(error.clone());
+                            return;
+                        }
+
+                        values
+                    }
+                    Err(err) => {
+                        yield Err(err);
+                        return;
+                    }
+                }
+            }
+            Value {
+                value: UntaggedValue::Table(values),
+                ..
+            } => values,
+            value => vec![value],
+        };
+
+        let mut others = others.into_iter();
+
+        while let Some(item) = input.next().await {
+            let tag = item.tag.clone();
+
+            let paired = match others.next() {
+                Some(paired) => paired,
+                None if longest => UntaggedValue::nothing().into_value(&tag),
+                None => break,
+            };
+
+            yield ReturnSuccess::value(UntaggedValue::table(&[item, paired]).into_value(tag));
+        }
+
+        if longest {
+            for paired in others {
+                let tag = paired.tag.clone();
+
+                yield ReturnSuccess::value(
+                    UntaggedValue::table(&[UntaggedValue::nothing().into_value(&tag), paired])
+                        .into_value(tag),
+                );
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Zip;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::examples::test as test_examples;
+
+        test_examples(Zip {})
+    }
+}
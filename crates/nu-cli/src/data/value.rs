@@ -5,8 +5,8 @@ use chrono::DateTime;
 use nu_errors::ShellError;
 use nu_protocol::hir::Operator;
 use nu_protocol::ShellTypeName;
-use nu_protocol::{Primitive, Type, UntaggedValue};
-use nu_source::{DebugDocBuilder, PrettyDebug, Tagged};
+use nu_protocol::{Primitive, Type, UntaggedValue, Value};
+use nu_source::{DebugDocBuilder, PrettyDebug, Tag, Tagged};
 
 pub fn date_from_str(s: Tagged<&str>) -> Result<UntaggedValue, ShellError> {
     let date = DateTime::parse_from_rfc3339(s.item).map_err(|err| {
@@ -22,6 +22,53 @@ pub fn date_from_str(s: Tagged<&str>) -> Result<UntaggedValue, ShellError> {
     Ok(UntaggedValue::Primitive(Primitive::Date(date)))
 }
 
+/// Reads the bounds given either as a `MIN..MAX` range or as a minimum followed by a maximum
+pub fn range_bounds(first: &Value, second: Option<&Value>) -> Result<(Value, Value), ShellError> {
+    if let Some(max) = second {
+        return Ok((first.clone(), max.clone()));
+    }
+
+    match &first.value {
+        UntaggedValue::Primitive(Primitive::Range(range)) => {
+            let (from, _) = &range.from;
+            let (to, _) = &range.to;
+
+            Ok((
+                UntaggedValue::Primitive(from.item.clone()).into_value(Tag::from(from.span)),
+                UntaggedValue::Primitive(to.item.clone()).into_value(Tag::from(to.span)),
+            ))
+        }
+        UntaggedValue::Primitive(Primitive::String(s)) if s.contains("..") => {
+            let mut parts = s.splitn(2, "..");
+            let min = parse_number(parts.next().unwrap_or(""), &first.tag)?;
+            let max = parse_number(parts.next().unwrap_or(""), &first.tag)?;
+
+            Ok((min, max))
+        }
+        _ => Err(ShellError::labeled_error(
+            "expected a range",
+            "expected MIN..MAX, or a minimum and a maximum",
+            first.tag.span,
+        )),
+    }
+}
+
+fn parse_number(s: &str, tag: &Tag) -> Result<Value, ShellError> {
+    let s = s.trim();
+
+    if let Ok(int) = s.parse::<i64>() {
+        Ok(UntaggedValue::int(int).into_value(tag))
+    } else if let Ok(float) = s.parse::<f64>() {
+        Ok(UntaggedValue::Primitive(float.into()).into_value(tag))
+    } else {
+        Err(ShellError::labeled_error(
+            format!("could not parse '{}' as a number", s),
+            "expected MIN..MAX with numeric bounds",
+            tag.span,
+        ))
+    }
+}
+
 pub fn merge_values(
     left: &UntaggedValue,
     right: &UntaggedValue,
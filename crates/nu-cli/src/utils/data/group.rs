@@ -15,6 +15,15 @@ pub fn group(
 
     let mut groups: IndexMap<String, Vec<Value>> = IndexMap::new();
 
+    // Rows missing the column are only grouped together when some other row has it,
+    // otherwise the column name is most likely mistyped
+    let column_found = match column_name {
+        Some(ref column_name) => values
+            .iter()
+            .any(|value| get_data_by_key(value, column_name.borrow_spanned()).is_some()),
+        None => true,
+    };
+
     for value in values {
         let group_key = if let Some(ref column_name) = column_name {
             get_data_by_key(&value, column_name.borrow_spanned())
@@ -30,6 +39,9 @@ pub fn group(
             };
             let group = groups.entry(group_key?).or_insert(vec![]);
             group.push((*value).clone());
+        } else if column_found {
+            let group = groups.entry(String::from("")).or_insert(vec![]);
+            group.push((*value).clone());
         } else {
             let column_name = column_name.unwrap_or_else(|| String::from("").tagged(&tag));
 
@@ -214,6 +214,24 @@ pub fn sum(data: Vec<Value>) -> Result<Value, ShellError> {
     Ok(acc)
 }
 
+pub fn product(data: Vec<Value>) -> Result<Value, ShellError> {
+    let mut acc = UntaggedValue::int(1).into_untagged_value();
+    for value in data {
+        match value.value {
+            UntaggedValue::Primitive(Primitive::Int(_))
+            | UntaggedValue::Primitive(Primitive::Decimal(_)) => acc = acc * value,
+            _ => {
+                return Err(ShellError::labeled_error(
+                    "Attempted to compute the product of a value that cannot be multiplied.",
+                    "value appears here",
+                    value.tag.span,
+                ))
+            }
+        }
+    }
+    Ok(acc)
+}
+
 fn formula(
     acc_begin: Value,
     calculator: Box<dyn Fn(Vec<Value>) -> Result<Value, ShellError> + Send + Sync + 'static>,
@@ -233,11 +251,13 @@ pub fn reducer_for(
 ) -> Box<dyn Fn(Value, Vec<Value>) -> Result<Value, ShellError> + Send + Sync + 'static> {
     match command {
         Reduce::Sum | Reduce::Default => Box::new(formula(Value::zero(), Box::new(sum))),
+        Reduce::Product => Box::new(formula(Value::zero(), Box::new(product))),
     }
 }
 
 pub enum Reduce {
     Sum,
+    Product,
     Default,
 }
 
@@ -586,6 +606,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn reducer_computes_given_a_product_command() -> Result<(), ShellError> {
+        let subject = vec![int(2), int(3), int(4)];
+
+        let action = reducer_for(Reduce::Product);
+
+        assert_eq!(action(Value::zero(), subject)?, int(24));
+
+        Ok(())
+    }
+
+    #[test]
+    fn reducer_computes_the_multiplicative_identity_given_no_values() -> Result<(), ShellError> {
+        let action = reducer_for(Reduce::Product);
+
+        assert_eq!(action(Value::zero(), vec![])?, int(1));
+
+        Ok(())
+    }
+
     #[test]
     fn reducer_computes() -> Result<(), ShellError> {
         assert_eq!(
@@ -0,0 +1,52 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn emits_the_escape_code_of_a_color() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ansi red | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""\u001b[31m""#);
+}
+
+#[test]
+fn emits_the_escape_code_to_reset() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ansi reset | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""\u001b[0m""#);
+}
+
+#[test]
+fn lists_the_known_codes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ansi --list
+            | where name == underline
+            | get code
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"\e[4m"#);
+}
+
+#[test]
+fn errors_given_an_unknown_code() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ansi ultraviolet
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown ansi code"));
+}
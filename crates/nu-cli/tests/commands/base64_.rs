@@ -0,0 +1,71 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn encodes_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'hello'
+            | base64 --encode
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "aGVsbG8=");
+}
+
+#[test]
+fn decodes_into_binary() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'aGVsbG8='
+            | base64 --decode
+            | describe
+            | echo $it
+        "#
+    ));
+
+    assert!(actual.out.contains("binary"));
+}
+
+#[test]
+fn decodes_into_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'hello'
+            | base64 --encode --url-safe
+            | base64 --decode --url-safe --to-string
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "hello");
+}
+
+#[test]
+fn errors_decoding_invalid_base64() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'aGVsbG8=='
+            | base64 --decode
+        "#
+    ));
+
+    assert!(actual.err.contains("Could not decode base64"));
+}
+
+#[test]
+fn errors_decoding_into_text_given_invalid_utf8() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '+/8='
+            | base64 --decode --to-string
+        "#
+    ));
+
+    assert!(actual.err.contains("Could not decode base64 into text"));
+}
@@ -0,0 +1,88 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn outputs_a_tab() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            char tab | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""\t""#);
+}
+
+#[test]
+fn outputs_a_newline() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            char newline | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""\n""#);
+}
+
+#[test]
+fn lists_the_known_characters() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            char --list
+            | where name == right_arrow
+            | get character
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "→");
+}
+
+#[test]
+fn errors_given_an_unknown_name() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            char snowman
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown character name"));
+}
+
+#[test]
+fn generates_a_range_of_characters() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            char range a e | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b","c","d","e"]"#);
+}
+
+#[test]
+fn generates_a_descending_range_with_a_step() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            char range z t --step 3 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["z","w","t"]"#);
+}
+
+#[test]
+fn range_errors_on_multi_character_bounds() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            char range ab e
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a single character"));
+}
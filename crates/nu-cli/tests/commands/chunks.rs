@@ -0,0 +1,57 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn chunks_the_input_keeping_a_shorter_last_chunk() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | chunks 2
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[[1,2],[3,4],[5]]");
+}
+
+#[test]
+fn chunks_the_input_evenly() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4]
+            | chunks 2
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn errors_given_a_size_of_zero() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | chunks 0
+        "#
+    ));
+
+    assert!(actual.err.contains("Chunk size must be positive"));
+}
+
+#[test]
+fn chunks_the_input_into_one_chunk_given_a_huge_size() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | chunks 9999999999999999
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
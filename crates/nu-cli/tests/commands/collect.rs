@@ -0,0 +1,30 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn collects_the_rows_into_a_single_list() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | collect
+            | each { echo $it | count }
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn collects_into_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | collect --into numbers
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"numbers":[1,2,3]}"#);
+}
@@ -50,3 +50,67 @@ fn discards_empty_rows_by_default() {
         assert_eq!(actual.out, "4");
     });
 }
+
+#[test]
+fn discards_rows_where_given_column_is_null() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Yehuda", "luck": 1}, {"name": "Jonathan", "luck": null}, {"name": "Andres", "luck": ""}]'
+            | from json
+            | compact luck
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["Yehuda","Andres"]"#);
+}
+
+#[test]
+fn discards_rows_where_given_column_is_an_empty_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Yehuda", "pets": []}, {"name": "Jonathan", "pets": ["Cosmo"]}]'
+            | from json
+            | compact pets
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""Jonathan""#);
+}
+
+#[test]
+fn discards_rows_where_given_column_is_an_empty_string_given_empty() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Yehuda", "luck": 1}, {"name": "Jonathan", "luck": null}, {"name": "Andres", "luck": ""}]'
+            | from json
+            | compact luck --empty
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""Yehuda""#);
+}
+
+#[test]
+fn keeps_empty_strings_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '["a", "", null]'
+            | from json
+            | compact
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
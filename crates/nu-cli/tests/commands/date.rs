@@ -0,0 +1,76 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn formats_a_date() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '2020-01-02T03:04:05+00:00'
+            | str to-datetime
+            | date format '%Y/%m/%d'
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2020/01/02");
+}
+
+#[test]
+fn formats_dates_by_column_path() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"released": "2020-01-02T03:04:05+00:00"}'
+            | from json
+            | str to-datetime released
+            | date format '%H:%M' released
+            | get released
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "03:04");
+}
+
+#[test]
+fn errors_given_a_value_that_is_not_a_date() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'nushell'
+            | date format '%Y/%m/%d'
+        "#
+    ));
+
+    assert!(actual.err.contains("value is not a date"));
+}
+
+#[test]
+fn humanizes_a_date_relative_to_a_reference() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '2020-01-01T12:00:00+00:00'
+            | str to-datetime
+            | date humanize --reference '2020-01-01T13:00:00+00:00'
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "1 hour ago");
+}
+
+#[test]
+fn humanizes_a_date_in_the_future() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '2020-01-03T00:00:00+00:00'
+            | str to-datetime
+            | date humanize --reference '2020-01-01T00:00:00+00:00'
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "in 2 days");
+}
@@ -34,3 +34,37 @@ fn adds_row_data_if_column_missing() {
         assert_eq!(actual.out, "2");
     });
 }
+
+#[test]
+fn adds_a_missing_column_to_every_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Yehuda"}, {"name": "Jonathan", "status": "online"}, {"name": "Andres", "status": null}]'
+            | from json
+            | default status "unknown"
+            | get status
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["unknown","online","unknown"]"#);
+}
+
+#[test]
+fn fills_only_null_columns_given_only_null() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Yehuda"}, {"name": "Andres", "status": null}]'
+            | from json
+            | default status "unknown" --only-null
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"name":"Yehuda"},{"name":"Andres","status":"unknown"}]"#
+    );
+}
@@ -9,3 +9,43 @@ fn drop_rows() {
 
     assert_eq!(actual.out, "3");
 }
+
+#[test]
+fn drop_rows_from_the_tail() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [1 2 3 4] | drop 2 | to json"#
+    );
+
+    assert_eq!(actual.out, "[1,2]");
+}
+
+#[test]
+fn drop_more_rows_than_there_are() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [1 2 3 4] | drop 10 | count"#
+    );
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn drop_a_huge_number_of_rows() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo [1 2 3] | drop 9999999999999999 | count"#
+    );
+
+    assert_eq!(actual.out, "0");
+}
+
+#[test]
+fn drop_columns() {
+    let actual = nu!(
+        cwd: ".",
+        r#"echo '[{"a": 1, "b": 2, "c": 3}]' | from json | drop column 2 | to json"#
+    );
+
+    assert_eq!(actual.out, r#"{"a":1}"#);
+}
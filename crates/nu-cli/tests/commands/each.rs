@@ -11,3 +11,18 @@ fn each_works_separately() {
 
     assert_eq!(actual.out, "[11,12,13]");
 }
+
+#[test]
+fn each_numbered_passes_the_index_and_item() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+        echo [a b c] | each --numbered { echo $it } | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"index":0,"item":"a"},{"index":1,"item":"b"},{"index":2,"item":"c"}]"#
+    );
+}
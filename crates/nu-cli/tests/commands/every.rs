@@ -0,0 +1,42 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn keeps_every_nth_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [0 1 2 3 4 5]
+            | every 2
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[0,2,4]");
+}
+
+#[test]
+fn skips_every_nth_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [0 1 2 3 4 5]
+            | every 2 --skip
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,3,5]");
+}
+
+#[test]
+fn errors_given_a_stride_of_zero() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [0 1 2 3 4 5]
+            | every 0
+        "#
+    ));
+
+    assert!(actual.err.contains("Stride must be a positive number"));
+}
@@ -68,3 +68,15 @@ fn gets_first_row_when_no_amount_given() {
         assert_eq!(actual.out, "1");
     })
 }
+
+#[test]
+fn gets_the_first_rows_of_a_list() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4] | first 2 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2]");
+}
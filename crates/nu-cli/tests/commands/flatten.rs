@@ -0,0 +1,64 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn flattens_nested_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": {"c": 2}}'
+            | from json
+            | flatten
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"a":1,"b.c":2}"#);
+}
+
+#[test]
+fn flattens_table_columns_into_one_row_each() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "tags": [{"id": 1}, {"id": 2}]}'
+            | from json
+            | flatten tags
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"name":"nu","tags.id":1},{"name":"nu","tags.id":2}]"#
+    );
+}
+
+#[test]
+fn flattens_every_level_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": {"b": {"c": 1}}}'
+            | from json
+            | flatten
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"a.b.c":1}"#);
+}
+
+#[test]
+fn flattens_only_up_to_the_depth() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": {"b": {"c": 1}}}'
+            | from json
+            | flatten --depth 1
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"a.b":{"c":1}}"#);
+}
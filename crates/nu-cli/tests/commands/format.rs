@@ -42,3 +42,78 @@ fn can_use_variables() {
 
     assert_eq!(actual.out, "nu is a new type of shell");
 }
+
+#[test]
+fn renders_nested_column_paths() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"user": {"name": "Ada", "address": {"city": "London"}}}'
+            | from json
+            | format "{user.name} lives in {user.address.city}"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "Ada lives in London");
+}
+
+#[test]
+fn errors_on_columns_the_row_does_not_have() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Ada"}'
+            | from json
+            | format "{name} {surname}"
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown column surname"));
+}
+
+#[test]
+fn keeps_placeholders_of_missing_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Ada"}'
+            | from json
+            | format "{name} {surname}" --keep
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "Ada {surname}");
+}
+
+#[test]
+fn puts_the_output_into_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Ada"}'
+            | from json
+            | format "hello {name}" --into greeting
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"Ada","greeting":"hello Ada"}"#);
+}
+
+#[test]
+fn formats_byte_counts_of_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "nu", "size": 1536}'
+            | from json
+            | format filesize size --decimal
+            | get size
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "1.5 KB");
+}
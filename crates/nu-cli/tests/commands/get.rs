@@ -126,15 +126,64 @@ fn fetches_more_than_one_column_path() {
             r#"
                 open sample.toml
                 | get fortune_tellers.2.name fortune_tellers.0.name fortune_tellers.1.name
-                | nth 2
-                | echo $it
+                | to json
             "#
         ));
 
-        assert_eq!(actual.out, "Jonathan Turner");
+        assert_eq!(
+            actual.out,
+            r#"{"fortune_tellers.2.name":"Yehuda Katz","fortune_tellers.0.name":"Andrés N. Robalino","fortune_tellers.1.name":"Jonathan Turner"}"#
+        );
     })
 }
 
+#[test]
+fn narrows_each_row_down_to_the_given_column_paths() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Ada", "age": 36, "city": "London"}, {"name": "Alan", "age": 41, "city": "Wilmslow"}]'
+            | from json
+            | get name age
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"name":"Ada","age":36},{"name":"Alan","age":41}]"#
+    );
+}
+
+#[test]
+fn errors_narrowing_down_to_a_column_not_present() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Ada", "age": 36}]'
+            | from json
+            | get name height
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown column"));
+}
+
+#[test]
+fn leaves_out_the_columns_not_present_ignoring_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Ada", "age": 36}]'
+            | from json
+            | get name height --ignore-errors
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"Ada"}"#);
+}
+
 #[test]
 fn errors_fetching_by_column_not_present() {
     Playground::setup("get_test_6", |dirs, sandbox| {
@@ -54,3 +54,20 @@ fn errors_if_given_unknown_column_name_is_missing() {
         assert!(actual.err.contains("Unknown column"));
     })
 }
+
+#[test]
+fn groups_by_type_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "a", "type": "file"}, {"name": "b", "type": "dir"}, {"name": "c", "type": "file"}]'
+            | from json
+            | group-by type
+            | get file
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
@@ -0,0 +1,46 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn sha256_hashes_a_string() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'abc'
+            | hash sha256
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn md5_hashes_by_column_path() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.ini
+            | hash md5 SectionOne.key
+            | get SectionOne.key
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2063c1608d6e0baf80249c42e2be5804");
+}
+
+#[test]
+fn errors_given_a_value_that_cannot_be_hashed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1
+            | hash md5
+        "#
+    ));
+
+    assert!(actual.err.contains("value is not string or binary"));
+}
@@ -29,3 +29,29 @@ fn headers_adds_missing_column_name() {
 
     assert_eq!(actual.out, "r1c1r2c1")
 }
+
+#[test]
+fn headers_promotes_the_first_list_to_column_names() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+        r#"
+            echo [[a b] [1 2] [3 4]]
+            | headers
+            | to json"#
+    ));
+
+    assert_eq!(actual.out, r#"[{"a":1,"b":2},{"a":3,"b":4}]"#)
+}
+
+#[test]
+fn headers_suffixes_duplicate_names() {
+    let actual = nu!(
+    cwd: ".", pipeline(
+        r#"
+            echo [[a a] [1 2]]
+            | headers
+            | to json"#
+    ));
+
+    assert_eq!(actual.out, r#"{"a":1,"a_1":2}"#)
+}
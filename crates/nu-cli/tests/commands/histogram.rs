@@ -19,9 +19,9 @@ fn summarizes() {
             cwd: dirs.test(), pipeline(
             r#"
                 open los_tres_caballeros.csv
-                | histogram rusty_at countries
+                | histogram rusty_at countries --bars
                 | where rusty_at == "Ecuador"
-                | get countries
+                | get bars
                 | echo $it
             "#
         ));
@@ -34,6 +34,53 @@ fn summarizes() {
     })
 }
 
+#[test]
+fn tallies_frequencies_and_percentages_most_frequent_first() {
+    Playground::setup("histogram_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at
+                Andrés,Robalino,Ecuador
+                Jonathan,Turner,Estados Unidos
+                Yehuda,Katz,Estados Unidos
+                Jason,Gedge,Estados Unidos
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | histogram rusty_at
+                | to json
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            r#"[{"rusty_at":"Estados Unidos","frequency":3,"percentage":75.0},{"rusty_at":"Ecuador","frequency":1,"percentage":25.0}]"#
+        );
+    })
+}
+
+#[test]
+fn sorts_percentages_as_numbers() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b b b c c c c c c c c]
+            | wrap letter
+            | histogram letter
+            | sort-by percentage
+            | get letter
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b","c"]"#);
+}
+
 #[test]
 fn help() {
     Playground::setup("histogram_test_help", |dirs, _sandbox| {
@@ -71,13 +118,13 @@ fn count() {
             echo "[{"bit":1},{"bit":0},{"bit":0},{"bit":0},{"bit":0},{"bit":0},{"bit":0},{"bit":1}]"
             | from json
             | histogram bit
-            | sort-by count
-            | reject frequency
+            | sort-by frequency
+            | reject percentage
             | to json
         "#
     ));
 
-    let bit_json = r#"[{"bit":"1","count":2},{"bit":"0","count":6}]"#;
+    let bit_json = r#"[{"bit":"1","frequency":2},{"bit":"0","frequency":6}]"#;
 
     assert_eq!(actual.out, bit_json);
 }
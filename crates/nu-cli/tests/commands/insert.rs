@@ -14,3 +14,53 @@ fn insert_plugin() {
 
     assert_eq!(actual.out, "1");
 }
+
+#[test]
+fn inserts_a_column_computed_from_each_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1, "b": 2}, {"a": 3, "b": 4}]'
+            | from json
+            | insert total {= $it.a + $it.b}
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"a":1,"b":2,"total":3},{"a":3,"b":4,"total":7}]"#
+    );
+}
+
+#[test]
+fn inserts_a_nested_column_creating_the_rows_leading_to_it() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés"}'
+            | from json
+            | insert address.verified $true
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"{"name":"Andrés","address":{"verified":true}}"#
+    );
+}
+
+#[test]
+fn errors_if_the_column_already_exists() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}]'
+            | from json
+            | insert a {= 2}
+        "#
+    ));
+
+    assert!(actual.err.contains("Column already exists"));
+}
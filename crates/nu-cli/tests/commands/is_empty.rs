@@ -53,12 +53,14 @@ fn adds_value_provided_for_columns_that_are_empty() {
                 open checks.json
                 | empty? boost check 1
                 | get boost check
-                | sum
-                | echo $it
+                | to json
             "#
         ));
 
-        assert_eq!(actual.out, "8");
+        assert_eq!(
+            actual.out,
+            r#"[{"boost":1,"check":1},{"boost":1,"check":1},{"boost":1,"check":1},{"boost":1,"check":1}]"#
+        );
     })
 }
 
@@ -94,3 +96,34 @@ fn value_emptiness_check() {
         assert_eq!(actual.out, "4");
     })
 }
+
+#[test]
+fn checks_emptiness_across_types() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '["", [], null, {}, "x", 0, [1]]'
+            | from json
+            | empty?
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[true,true,true,true,false,false,false]");
+}
+
+#[test]
+fn sets_the_emptiness_of_a_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"likes": ""}, {"likes": 2}]'
+            | from json
+            | empty? likes
+            | get likes
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[true,false]");
+}
@@ -49,3 +49,17 @@ fn condition_is_met() {
         assert_eq!(actual.out, "4");
     })
 }
+
+#[test]
+fn take_while_stops_at_the_first_row_not_matching() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 0 4]
+            | take while {$it > 0}
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
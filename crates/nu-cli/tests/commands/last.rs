@@ -54,3 +54,27 @@ fn gets_last_row_when_no_amount_given() {
         assert_eq!(actual.out, "1");
     })
 }
+
+#[test]
+fn gets_the_last_rows_of_a_list() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4] | last 2 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[3,4]");
+}
+
+#[test]
+fn gets_all_rows_if_amount_higher_than_all_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4] | last 99 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2,3,4]");
+}
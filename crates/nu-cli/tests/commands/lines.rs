@@ -1,3 +1,5 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
 #[test]
@@ -19,3 +21,38 @@ fn lines() {
 
     assert_eq!(actual.out, "rustyline");
 }
+
+#[test]
+fn drops_the_trailing_empty_line() {
+    Playground::setup("lines_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("abc.txt", "a\nb\nc\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open abc.txt --raw | lines | to json"
+        );
+
+        assert_eq!(actual.out, r#"["a","b","c"]"#);
+    })
+}
+
+#[test]
+fn splits_windows_line_endings_and_skips_empty_lines() {
+    Playground::setup("lines_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("crlf.txt", "a\r\nb\r\n\r\nc\r\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open crlf.txt --raw | lines | to json"
+        );
+
+        assert_eq!(actual.out, r#"["a","b","","c"]"#);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open crlf.txt --raw | lines --skip-empty | to json"
+        );
+
+        assert_eq!(actual.out, r#"["a","b","c"]"#);
+    })
+}
@@ -41,3 +41,52 @@ fn row() {
         assert_eq!(actual.out, "2");
     })
 }
+
+#[test]
+fn merges_the_columns_of_each_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}]'
+            | from json
+            | merge { echo '{"b": 2}' | from json }
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"a":1,"b":2}"#);
+}
+
+#[test]
+fn keeps_the_rest_of_the_longer_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}]'
+            | from json
+            | merge { echo '[{"a": 3, "b": 2}, {"b": 4}]' | from json }
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[{"a":3,"b":2},{"b":4}]"#);
+}
+
+#[test]
+fn errors_on_different_lengths_given_strict() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}, {"a": 2}]'
+            | from json
+            | merge --strict { echo '{"b": 2}' | from json }
+            | to json
+        "#
+    ));
+
+    assert!(
+        actual.err.contains("different lengths"),
+        "actual: {:?}",
+        actual.err
+    );
+}
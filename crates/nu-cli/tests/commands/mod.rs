@@ -1,19 +1,28 @@
 mod alias;
+mod ansi;
 mod append;
 mod average;
+mod base64_;
 mod cal;
 mod calc;
 mod cd;
+mod char_;
+mod chunks;
+mod collect;
 mod compact;
 mod cp;
+mod date;
 mod default;
 mod drop;
 mod each;
 mod enter;
+mod every;
 mod first;
+mod flatten;
 mod format;
 mod get;
 mod group_by;
+mod hash;
 mod headers;
 mod histogram;
 mod insert;
@@ -27,18 +36,30 @@ mod ls;
 mod math;
 mod merge;
 mod mkdir;
+mod move_;
 mod mv;
+mod nth;
 mod open;
 mod parse;
+mod path;
+mod pivot;
 mod prepend;
+mod random;
 mod range;
+mod reduce;
+mod reject;
 mod rename;
 mod reverse;
 mod rm;
+mod roll;
+mod rotate;
 mod save;
 mod select;
 mod semicolon;
+mod seq;
+mod shuffle;
 mod skip_until;
+mod skip_while;
 mod sort_by;
 mod split_by;
 mod split_column;
@@ -49,6 +70,9 @@ mod touch;
 mod trim;
 mod uniq;
 mod update;
+mod url_;
 mod where_;
+mod window;
 mod with_env;
 mod wrap;
+mod zip;
@@ -0,0 +1,52 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn moves_a_trailing_column_to_the_front() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Andrés", "lang": "rust", "rank": 1}, {"name": "Yehuda", "lang": "ruby", "rank": 2}]'
+            | from json
+            | move rank --before name
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"rank":1,"name":"Andrés","lang":"rust"},{"rank":2,"name":"Yehuda","lang":"ruby"}]"#
+    );
+}
+
+#[test]
+fn moves_many_columns_keeping_their_order() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2, "c": 3, "d": 4}'
+            | from json
+            | move c a --after d
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"b":2,"d":4,"a":1,"c":3}"#);
+}
+
+#[test]
+fn errors_if_the_anchor_column_is_missing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés", "lang": "rust"}'
+            | from json
+            | move lang --before rank
+        "#
+    ));
+
+    assert!(
+        actual.err.contains("there is no column named 'rank'"),
+        "actual: {:?}",
+        actual.err
+    );
+}
@@ -1,3 +1,7 @@
+use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
 #[test]
 fn selects_a_row() {
     Playground::setup("nth_test_1", |dirs, sandbox| {
@@ -36,4 +40,66 @@ fn selects_many_rows() {
 
         assert_eq!(actual.out, "2");
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn selects_rows_in_the_order_asked_for() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d] | nth 2 0 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["c","a"]"#);
+}
+
+#[test]
+fn selects_a_range_of_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d] | nth 0 2..3 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","c","d"]"#);
+}
+
+#[test]
+fn skips_rows_past_the_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d] | nth 0 9 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""a""#);
+}
+
+#[test]
+fn errors_on_rows_past_the_end_when_strict() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d] | nth 0 9 --strict
+        "#
+    ));
+
+    assert!(actual.err.contains("Row number too large"));
+}
+
+#[test]
+fn stops_huge_ranges_at_the_end_of_the_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b]
+            | nth 0..999999999999999
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b"]"#);
+}
@@ -198,6 +198,19 @@ fn parses_xml() {
     )
 }
 
+#[test]
+fn parses_xml_text_content() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "open jonathan.xml | get rss.children.channel.children | get item.children | get link.content | echo $it"
+    );
+
+    assert_eq!(
+        actual.out,
+        "http://www.jonathanturner.org/2015/10/off-to-new-adventures.html"
+    )
+}
+
 #[test]
 fn parses_ini() {
     let actual = nu!(
@@ -228,3 +241,32 @@ fn errors_if_file_not_found() {
     assert!(actual.err.contains("File could not be opened"));
     assert!(actual.err.contains("file not found"));
 }
+
+#[test]
+fn opens_json_as_text_with_raw() {
+    Playground::setup("open_test_raw", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "nu.json",
+            r#"
+                {"name": "nu"}
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open nu.json --raw | echo $it"
+        );
+
+        assert_eq!(actual.out, r#"{"name": "nu"}"#);
+    })
+}
+
+#[test]
+fn opens_non_utf8_files_as_binary_with_raw() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "open sample.db --raw | describe | echo $it"
+    );
+
+    assert!(actual.out.contains("binary"));
+}
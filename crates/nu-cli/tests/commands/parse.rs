@@ -96,6 +96,48 @@ mod simple {
             assert!(actual.err.contains("invalid parse pattern"));
         })
     }
+
+    #[test]
+    fn extracts_key_and_value() {
+        let actual = nu!(
+            cwd: ".", pipeline(
+            r#"
+                echo "key: 42"
+                | parse "{k}: {v}"
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"{"k":"key","v":"42"}"#);
+    }
+
+    #[test]
+    fn skips_input_not_matching_the_pattern() {
+        let actual = nu!(
+            cwd: ".", pipeline(
+            r#"
+                echo ["key: 42" "no separator" "other: 7"]
+                | parse "{k}: {v}"
+                | count
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    }
+
+    #[test]
+    fn errors_on_input_not_matching_the_pattern_given_strict() {
+        let actual = nu!(
+            cwd: ".", pipeline(
+            r#"
+                echo ["key: 42" "no separator"]
+                | parse --strict "{k}: {v}"
+            "#
+        ));
+
+        assert!(actual.err.contains("does not match the pattern"));
+    }
 }
 
 mod regex {
@@ -0,0 +1,176 @@
+use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn gets_the_basename() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '/a/b/c.txt'
+            | path basename
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "c.txt");
+}
+
+#[test]
+fn gets_the_dirname() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '/a/b/c.txt'
+            | path dirname --posix
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "/a/b");
+}
+
+#[test]
+fn gets_the_extension() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '/a/b/c.txt'
+            | path extension
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "txt");
+}
+
+#[test]
+fn gets_an_empty_extension_without_one() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [/a/b/c /a/b/c.tar.gz]
+            | path extension
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["","gz"]"#);
+}
+
+#[test]
+fn gets_the_parts_of_paths_by_column_path() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"file": "/a/b/c.txt"}'
+            | from json
+            | path basename file
+            | get file
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "c.txt");
+}
+
+#[test]
+fn joins_a_list_of_parts() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo ['/a' 'b']
+            | path join --posix
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "/a/b");
+}
+
+#[test]
+fn joins_parts_after_the_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '/a'
+            | path join b c.txt --posix
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "/a/b/c.txt");
+}
+
+#[test]
+fn checks_whether_paths_exist() {
+    Playground::setup("path_exists_test", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("spam.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo [spam.txt eggs.txt]
+                | path exists
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, "[true,false]");
+    })
+}
+
+#[test]
+fn gets_the_type_of_paths() {
+    Playground::setup("path_type_test_1", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("spam.txt")])
+            .mkdir("eggs");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                echo [spam.txt eggs]
+                | path type
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["file","dir"]"#);
+    })
+}
+
+#[test]
+fn gets_the_type_of_symlinks_or_what_they_point_to() {
+    Playground::setup("path_type_test_2", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("spam.txt")])
+            .symlink("spam.txt", "spam_link");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "echo spam_link | path type"
+        );
+
+        assert_eq!(actual.out, "symlink");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "echo spam_link | path type --follow-symlinks"
+        );
+
+        assert_eq!(actual.out, "file");
+    })
+}
+
+#[test]
+fn errors_getting_the_type_of_missing_paths() {
+    Playground::setup("path_type_test_3", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "echo eggs.txt | path type"
+        );
+
+        assert!(actual.err.contains("Could not get the type of the path"));
+    })
+}
@@ -0,0 +1,55 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn pivots_a_row_into_a_key_value_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2}'
+            | from json
+            | pivot
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"Column0":"a","Column1":1},{"Column0":"b","Column1":2}]"#
+    );
+}
+
+#[test]
+fn pivots_with_the_given_column_names() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2}'
+            | from json
+            | pivot key value
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"key":"a","value":1},{"key":"b","value":2}]"#
+    );
+}
+
+#[test]
+fn pivots_ragged_rows_filling_gaps_with_nothing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}, {"b": 2}]'
+            | from json
+            | pivot
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"Column0":"a","Column1":1,"Column2":null},{"Column0":"b","Column1":null,"Column2":2}]"#
+    );
+}
@@ -0,0 +1,99 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn generates_the_same_integer_given_a_seed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random integer 1..10 --seed 42
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn accepts_a_minimum_and_a_maximum() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random integer 1 10 --seed 42
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn generates_the_same_decimal_given_a_seed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random decimal '0.5..1.5' --seed 42
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "1.014049295765024");
+}
+
+#[test]
+fn errors_given_a_minimum_greater_than_the_maximum() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random integer 10..1
+        "#
+    ));
+
+    assert!(actual.err.contains("invalid range"));
+}
+
+#[test]
+fn generates_a_hyphenated_uuid() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random uuid
+            | echo $it
+        "#
+    ));
+
+    let format =
+        regex::Regex::new("^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$")
+            .unwrap();
+
+    assert!(format.is_match(&actual.out));
+}
+
+#[test]
+fn generates_unique_uuids_given_a_count() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random uuid --count 20 --uppercase
+            | uniq
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "20");
+}
+
+#[test]
+fn generates_uuids_lazily_given_a_huge_count() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            random uuid --count 100000000000
+            | first
+            | str length
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "36");
+}
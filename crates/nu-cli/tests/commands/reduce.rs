@@ -0,0 +1,72 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn reduces_using_the_first_row_as_the_starting_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | reduce {$acc + $it}
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn reduces_from_a_fold_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | reduce --fold 10 {$acc + $it}
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "16");
+}
+
+#[test]
+fn reduces_rows_by_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "a", "size": 2}, {"name": "b", "size": 3}]'
+            | from json
+            | get size
+            | reduce --fold 1 {$acc * $it}
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn returns_the_fold_value_given_no_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | reduce --fold 10 {$acc + $it}
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "10");
+}
+
+#[test]
+fn errors_given_no_input_and_no_fold_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | reduce {$acc + $it}
+        "#
+    ));
+
+    assert!(actual.err.contains("needs input to reduce"));
+}
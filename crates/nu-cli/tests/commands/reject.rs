@@ -0,0 +1,63 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn removes_the_given_columns_keeping_the_rest() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Ada", "password": "secret", "age": 36}, {"name": "Alan", "password": "hunter2", "age": 41}]'
+            | from json
+            | reject password
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"name":"Ada","age":36},{"name":"Alan","age":41}]"#
+    );
+}
+
+#[test]
+fn removes_only_the_leaf_of_a_nested_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Ada", "address": {"city": "London", "zip": "N1"}}'
+            | from json
+            | reject address.zip
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"Ada","address":{"city":"London"}}"#);
+}
+
+#[test]
+fn ignores_columns_not_present() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Ada"}'
+            | from json
+            | reject password
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"Ada"}"#);
+}
+
+#[test]
+fn errors_on_columns_not_present_when_strict() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Ada"}'
+            | from json
+            | reject password --strict
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown column"));
+}
@@ -95,3 +95,74 @@ fn errors_if_no_columns_present() {
         );
     })
 }
+
+#[test]
+fn renames_columns_by_their_old_names() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Andrés", "lang": "rust"}, {"name": "Yehuda", "lang": "ruby"}]'
+            | from json
+            | rename --column name:title
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"title":"Andrés","lang":"rust"},{"title":"Yehuda","lang":"ruby"}]"#
+    );
+}
+
+#[test]
+fn errors_if_the_old_column_is_missing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés"}'
+            | from json
+            | rename --column age:years
+        "#
+    ));
+
+    assert!(
+        actual.err.contains("there is no column named 'age'"),
+        "actual: {:?}",
+        actual.err
+    );
+}
+
+#[test]
+fn skips_missing_old_columns_given_ignore_missing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés", "lang": "rust"}'
+            | from json
+            | rename --column [age:years lang:language] --ignore-missing
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"name":"Andrés","language":"rust"}"#);
+}
+
+#[test]
+fn errors_if_renaming_to_an_existing_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "Andrés", "lang": "rust"}'
+            | from json
+            | rename --column name:lang
+        "#
+    ));
+
+    assert!(
+        actual
+            .err
+            .contains("renaming would overwrite the 'lang' column"),
+        "actual: {:?}",
+        actual.err
+    );
+}
@@ -0,0 +1,60 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn rolls_rows_down() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | roll down
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["c","a","b"]"#);
+}
+
+#[test]
+fn rolls_rows_up_wrapping_around_the_length() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | roll up --by 4
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["b","c","a"]"#);
+}
+
+#[test]
+fn rolls_columns_of_every_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1, "b": 2, "c": 3}, {"a": 4, "b": 5, "c": 6}]'
+            | from json
+            | roll right
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[{"c":3,"a":1,"b":2},{"c":6,"a":4,"b":5}]"#);
+}
+
+#[test]
+fn rolls_columns_left() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2, "c": 3}'
+            | from json
+            | roll left --by 2
+            | get
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["c","a","b"]"#);
+}
@@ -0,0 +1,83 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn rotates_a_table_clockwise() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1, "b": 2}, {"a": 3, "b": 4}]'
+            | from json
+            | rotate
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"Column0":3,"Column1":1,"Column2":"a"},{"Column0":4,"Column1":2,"Column2":"b"}]"#
+    );
+}
+
+#[test]
+fn rotates_a_table_counter_clockwise() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1, "b": 2}, {"a": 3, "b": 4}]'
+            | from json
+            | rotate --ccw
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"Column0":"b","Column1":2,"Column2":4},{"Column0":"a","Column1":1,"Column2":3}]"#
+    );
+}
+
+#[test]
+fn rotates_giving_names_to_the_new_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"a": 1, "b": 2}'
+            | from json
+            | rotate value key
+            | where key == b
+            | get value
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn errors_given_values_that_are_not_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | rotate
+            | to json
+        "#
+    ));
+
+    assert!(actual.err.contains("Expected a table of rows to rotate"));
+}
+
+#[test]
+fn errors_given_the_same_column_name_twice() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1, "b": 2}]'
+            | from json
+            | rotate x x
+            | to json
+        "#
+    ));
+
+    assert!(actual.err.contains("Column already exists"));
+}
@@ -45,3 +45,37 @@ fn writes_out_csv() {
         assert!(actual.contains("nu,0.1.1,[Table],a new type of shell,ISC,2018"));
     })
 }
+
+#[test]
+fn appends_raw_strings_to_the_file() {
+    Playground::setup("save_test_3", |dirs, _| {
+        let expected_file = dirs.test().join("log.txt");
+
+        nu!(
+            cwd: dirs.root(),
+            "echo 'hello' | save --raw save_test_3/log.txt"
+        );
+        nu!(
+            cwd: dirs.root(),
+            "echo ' world' | save --raw --append save_test_3/log.txt"
+        );
+
+        let actual = file_contents(expected_file);
+        assert_eq!(actual, "hello world");
+    })
+}
+
+#[test]
+fn append_creates_the_file_if_missing() {
+    Playground::setup("save_test_4", |dirs, _| {
+        let expected_file = dirs.test().join("new.txt");
+
+        nu!(
+            cwd: dirs.root(),
+            "echo 'first line' | save --append save_test_4/new.txt"
+        );
+
+        let actual = file_contents(expected_file);
+        assert_eq!(actual, "first line");
+    })
+}
@@ -0,0 +1,61 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn generates_the_numbers_from_start_to_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 1 5 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2,3,4,5]");
+}
+
+#[test]
+fn generates_numbers_with_a_step() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 1 5 --step 2 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,3,5]");
+}
+
+#[test]
+fn counts_down_when_start_is_greater_than_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 5 1 --step 2 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[5,3,1]");
+}
+
+#[test]
+fn only_generates_what_is_consumed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 1 1000000000 | first 3 | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2,3]");
+}
+
+#[test]
+fn errors_if_the_step_is_zero() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            seq 1 5 --step 0
+        "#
+    ));
+
+    assert!(actual.err.contains("Step can not be zero"));
+}
@@ -0,0 +1,30 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn shuffles_the_same_way_given_a_seed() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | shuffle --seed 42
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[4,1,2,3,5]");
+}
+
+#[test]
+fn shuffles_every_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | shuffle
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "15");
+}
@@ -38,7 +38,7 @@ fn condition_is_met() {
                 | split column ','
                 | headers
                 | skip-until "Chicken Collection" == "Red Chickens"
-                | str to-int "31/04/2020"
+                | str to-int --skip-errors "31/04/2020"
                 | get "31/04/2020"
                 | sum
                 | echo $it
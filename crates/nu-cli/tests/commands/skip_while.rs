@@ -0,0 +1,29 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn skips_rows_until_the_condition_no_longer_matches() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 0 4]
+            | skip while {$it > 0}
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[0,4]");
+}
+
+#[test]
+fn keeps_rows_matching_again_after_skipping_stops() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 0 2 3]
+            | skip while $it > 0
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[0,2,3]");
+}
@@ -62,3 +62,63 @@ fn sort_primitive_values() {
 
     assert_eq!(actual.out, "authors = [\"The Nu Project Contributors\"]");
 }
+
+#[test]
+fn by_natural_order() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [file2 file10 file1]
+            | sort-by --natural
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["file1","file2","file10"]"#);
+}
+
+#[test]
+fn by_natural_order_reversed_and_insensitive() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "File2"}, {"name": "file10"}, {"name": "FILE1"}]'
+            | from json
+            | sort-by name --natural --insensitive --reverse
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["file10","File2","FILE1"]"#);
+}
+
+#[test]
+fn by_insensitive_order_keeping_the_original_case() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [Banana apple Cherry]
+            | sort-by --insensitive
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["apple","Banana","Cherry"]"#);
+}
+
+#[test]
+fn by_insensitive_order_across_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"kind": "fruit", "name": "banana"}, {"kind": "Fruit", "name": "Apple"}, {"kind": "berry", "name": "cherry"}]'
+            | from json
+            | sort-by kind name --insensitive
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["cherry","Apple","banana"]"#);
+}
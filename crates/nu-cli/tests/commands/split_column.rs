@@ -28,3 +28,18 @@ fn to_column() {
         assert!(actual.out.contains("shipper"));
     })
 }
+
+#[test]
+fn to_named_columns_filling_missing_fields() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "importer::shipper"
+            | split column "::" importer shipper tariff_item
+            | format "{shipper}|{tariff_item}|"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "shipper||");
+}
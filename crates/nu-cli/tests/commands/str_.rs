@@ -22,6 +22,34 @@ fn trims() {
     })
 }
 
+#[test]
+fn trims_a_custom_character() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'xxhelloxx'
+            | str trim --char x
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "hello");
+}
+
+#[test]
+fn trims_only_the_left_side() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'xxhelloxx'
+            | str trim --char x --left
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "helloxx");
+}
+
 #[test]
 fn capitalizes() {
     Playground::setup("str_test_2", |dirs, sandbox| {
@@ -82,6 +110,32 @@ fn upcases() {
     })
 }
 
+#[test]
+fn upcases_and_downcases_accented_characters() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'héllo'
+            | str upcase
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "HÉLLO");
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'héllo'
+            | str upcase
+            | str downcase
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "héllo");
+}
+
 #[test]
 fn converts_to_int() {
     let actual = nu!(
@@ -340,3 +394,492 @@ fn substrings_the_input_and_treats_end_index_as_length_if_blank_end_index_given(
         assert_eq!(actual.out, "arepas");
     })
 }
+
+#[test]
+fn pads_the_input_by_column_path() {
+    Playground::setup("str_test_14", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                     [package]
+                     version = "7"
+                 "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                 open sample.toml
+                 | str pad 3 --character 0 package.version
+                 | get package.version
+                 | echo $it
+             "#
+        ));
+
+        assert_eq!(actual.out, "007");
+    })
+}
+
+#[test]
+fn zero_pads_numbers_keeping_the_sign_in_front() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [5 -5]
+            | str pad 3 --zero
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["005","-05"]"#);
+}
+
+#[test]
+fn zero_pads_numbers_by_column_path() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"id": 42}'
+            | from json
+            | str pad 5 --zero id
+            | get id
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "00042");
+}
+
+#[test]
+fn pads_and_truncates_to_a_fixed_width() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [abc 'hello world']
+            | str pad 8 --right --truncate --ellipsis
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["abc     ","hello w…"]"#);
+}
+
+#[test]
+fn errors_adding_an_ellipsis_without_truncating() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'hello world'
+            | str pad 8 --ellipsis
+        "#
+    ));
+
+    assert!(actual.err.contains("without truncating"));
+}
+
+#[test]
+fn find_and_replaces_all_regex_matches() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo 'abc123def456'
+            | str find-replace '\d+' N --all --regex
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "abcNdefN");
+}
+
+#[test]
+fn find_and_replace_errors_given_an_invalid_regex() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo 'abc'
+            | str find-replace '(a' b --regex
+        "#
+    ));
+
+    assert!(actual.err.contains("Invalid regular expression"));
+}
+
+#[test]
+fn converts_text_to_datetime_with_a_format() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '2020-01-01'
+            | str to-datetime --format '%Y-%m-%d' --utc
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#""2020-01-01 00:00:00 UTC""#);
+}
+
+#[test]
+fn to_datetime_errors_naming_the_input_that_does_not_parse() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo 'yesterday'
+            | str to-datetime --format '%Y-%m-%d'
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("Could not parse 'yesterday' as datetime"));
+}
+
+#[test]
+fn converts_text_between_cases() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"camel": "hello world", "snake": "hello world", "kebab": "hello world"}'
+            | from json
+            | str camel-case camel
+            | str snake-case snake
+            | str kebab-case kebab
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"{"camel":"helloWorld","snake":"hello_world","kebab":"hello-world"}"#
+    );
+}
+
+#[test]
+fn converting_between_cases_keeps_acronym_boundaries() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'XMLHttpRequest'
+            | str snake-case
+            | str camel-case
+            | str kebab-case
+        "#
+    ));
+
+    assert_eq!(actual.out, "xml-http-request");
+}
+
+#[test]
+fn substrings_the_input_by_range() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'hello'
+            | str substring 2..4
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "ll");
+}
+
+#[test]
+fn substrings_the_input_counting_negative_indexes_from_the_end() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'hello'
+            | str substring '-4..-1'
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "ell");
+}
+
+#[test]
+fn counts_the_characters_of_text_by_column_path() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "café"}'
+            | from json
+            | str length name
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "4");
+}
+
+#[test]
+fn checks_if_text_contains_pattern_by_column_path() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "NuShell"}'
+            | from json
+            | str contains --insensitive she name
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn expands_template_with_columns_of_each_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"first": "Ada", "last": "Lovelace"}'
+            | from json
+            | str expand "{first} {last}"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "Ada Lovelace");
+}
+
+#[test]
+fn expand_errors_naming_the_missing_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"first": "Ada"}'
+            | from json
+            | str expand "{first} {last}"
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown column last"));
+}
+
+#[test]
+fn converts_hexadecimal_to_integer() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "0xff"
+            | str to-int --radix 16
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "255");
+}
+
+#[test]
+fn errors_converting_text_that_is_not_an_integer() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "abc"
+            | str to-int
+        "#
+    ));
+
+    assert!(actual.err.contains("Could not convert 'abc' into an integer"));
+}
+
+#[test]
+fn converts_scientific_notation_to_decimal() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "1e2"
+            | str to-decimal
+            | = $it + 0.5
+        "#
+    ));
+
+    assert_eq!(actual.out, "100.5");
+}
+
+#[test]
+fn leaves_text_that_is_not_a_decimal_given_skip_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo ["n/a" "1e2"]
+            | str to-decimal --skip-errors
+            | first
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "n/a");
+}
+
+#[test]
+fn translates_several_patterns_in_order() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'abc'
+            | str translate $(echo '{"a": "x", "b": "y"}' | from json)
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "xyc");
+}
+
+#[test]
+fn translates_several_patterns_by_column_path() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "a-b_c"}'
+            | from json
+            | str translate ['-' ' ' '_' ' '] name
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "a b c");
+}
+
+#[test]
+fn hex_encodes_and_decodes_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'AB'
+            | str encode --hex
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "4142");
+
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'AB'
+            | str encode --hex
+            | str decode --hex
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "AB");
+}
+
+#[test]
+fn base64_encodes_and_decodes_by_column_path() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"secret": "nushell"}'
+            | from json
+            | str encode --base64 secret
+            | str decode --base64 secret
+            | get secret
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "nushell");
+}
+
+#[test]
+fn errors_decoding_odd_length_hex() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '414'
+            | str decode --hex
+        "#
+    ));
+
+    assert!(actual.err.contains("Could not decode hex"));
+}
+
+#[test]
+fn errors_without_an_encoding() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'AB'
+            | str encode
+        "#
+    ));
+
+    assert!(actual.err.contains("Missing encoding"));
+}
+
+#[test]
+fn captures_named_groups_into_columns() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2020-01"
+            | str capture '(?P<year>\d{4})-(?P<month>\d{2})'
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"{"year":"2020","month":"01"}"#);
+}
+
+#[test]
+fn capture_skips_strings_that_do_not_match() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo ["2020-01" "nope" "2021-12"]
+            | str capture '(?P<year>\d{4})-(?P<month>\d{2})'
+            | get year
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["2020","2021"]"#);
+}
+
+#[test]
+fn capture_errors_on_strings_that_do_not_match_when_strict() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo ["2020-01" "nope"]
+            | str capture --strict '(?P<year>\d{4})-(?P<month>\d{2})'
+            | to json
+        "#
+    ));
+
+    assert!(actual.err.contains("Could not capture from the input"));
+}
+
+#[test]
+fn joins_a_column_of_the_rows_with_a_separator() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "andres"}, {"name": "jonathan"}, {"name": "yehuda"}]'
+            | from json
+            | str join name --separator ", "
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "andres, jonathan, yehuda");
+}
+
+#[test]
+fn join_errors_on_values_that_are_not_strings_when_strict() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"id": "a"}, {"id": 1}]'
+            | from json
+            | str join id --strict
+        "#
+    ));
+
+    assert!(actual.err.contains("value is not string"));
+}
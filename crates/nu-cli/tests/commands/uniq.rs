@@ -140,3 +140,109 @@ fn uniq_when_keys_out_of_order() {
 
     assert_eq!(actual.out, "1");
 }
+
+#[test]
+fn uniq_counts_occurrences() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [1 1 2 3 3 3]
+            | uniq --count
+            | where value == 3
+            | get count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn uniq_only_repeated_values() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [1 1 2 3 3 3]
+            | uniq --repeated
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn uniq_every_duplicated_value_in_order() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [1 2 2 3 3 3]
+            | uniq --duplicated
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2,2,3,3,3]");
+}
+
+#[test]
+fn uniq_duplicated_rows_by_a_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "a", "type": "A"}, {"name": "b", "type": "B"}, {"name": "c", "type": "A"}]'
+            | from json
+            | uniq --duplicated --by type
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","c"]"#);
+}
+
+#[test]
+fn uniq_rows_by_a_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '[{"name": "a", "type": "A"}, {"name": "b", "type": "B"}, {"name": "c", "type": "A"}]'
+            | from json
+            | uniq --by type
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["a","b"]"#);
+}
+
+#[test]
+fn adjacent_only_collapses_runs() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 1 2 2 2 1]
+            | uniq --adjacent
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1,2,1]");
+}
+
+#[test]
+fn adjacent_counts_the_length_of_each_run() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 1 2 2 2 1]
+            | uniq --adjacent --count
+            | get count
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2,3,1]");
+}
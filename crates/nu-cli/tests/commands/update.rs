@@ -29,3 +29,35 @@ fn sets_the_column_from_a_block_run_output() {
 
     assert_eq!(actual.out, "0.7.0");
 }
+
+#[test]
+fn sets_the_column_per_row_from_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"item": "apple", "price": 10}, {"item": "pear", "price": 25}]'
+            | from json
+            | update price {= $it.price * 2}
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"[{"item":"apple","price":20},{"item":"pear","price":50}]"#
+    );
+}
+
+#[test]
+fn errors_if_the_column_is_missing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"item": "apple"}]'
+            | from json
+            | update price {= 10}
+        "#
+    ));
+
+    assert!(actual.err.contains("Unknown column"));
+}
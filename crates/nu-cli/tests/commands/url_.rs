@@ -0,0 +1,94 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn parses_a_url_into_its_parts() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'https://x.com/a?b=1#c'
+            | url parse
+            | to json
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"{"scheme":"https","username":null,"password":null,"host":"x.com","port":null,"path":"/a","query":{"b":"1"},"fragment":"c"}"#
+    );
+}
+
+#[test]
+fn joins_a_parsed_url_back_together() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'https://x.com/a?b=1#c'
+            | url parse
+            | url join
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "https://x.com/a?b=1#c");
+}
+
+#[test]
+fn round_trips_repeated_query_keys() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'https://x.com/?a=1&a=2&b=3'
+            | url parse
+            | url join
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "https://x.com/?a=1&a=2&b=3");
+}
+
+#[test]
+fn joins_a_url_after_changing_its_parts() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'https://x.com/a?b=1'
+            | url parse
+            | update host nushell.sh
+            | update query.b 2
+            | url join
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "https://nushell.sh/a?b=2");
+}
+
+#[test]
+fn parses_urls_by_column_path() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"link": "https://www.nushell.sh/book"}'
+            | from json
+            | url parse link
+            | get link.host
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "www.nushell.sh");
+}
+
+#[test]
+fn errors_parsing_malformed_urls() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 'x.com/a'
+            | url parse
+        "#
+    ));
+
+    assert!(actual.err.contains("Could not parse the url"));
+}
@@ -166,3 +166,45 @@ fn contains_operator() {
 
     assert_eq!(actual.out, "2");
 }
+
+#[test]
+fn filters_with_a_condition_in_braces() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        r#"echo [1 2 0 3] | where {$it > 1} | to json"#
+    );
+
+    assert_eq!(actual.out, "[2,3]");
+}
+
+#[test]
+fn filters_rows_with_shorthand_comparison() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Ann", "age": 20}, {"name": "Bob", "age": 10}, {"name": "Cid", "age": 42}]'
+            | from json
+            | where age > 18
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["Ann","Cid"]"#);
+}
+
+#[test]
+fn rows_missing_the_column_do_not_match() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"name": "Ann", "age": 20}, {"name": "Bob"}, {"name": "Cid", "age": 42}]'
+            | from json
+            | where age > 18
+            | get name
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["Ann","Cid"]"#);
+}
@@ -0,0 +1,44 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn windows_slide_one_row_at_a_time() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4]
+            | window 2
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn windows_skip_rows_given_a_stride_larger_than_the_size() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5 6 7]
+            | window 2 --stride 3
+            | nth 1
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[4,5]");
+}
+
+#[test]
+fn errors_given_a_window_size_of_zero() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4]
+            | window 0
+        "#
+    ));
+
+    assert!(actual.err.contains("Window size must be positive"));
+}
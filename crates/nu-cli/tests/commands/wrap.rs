@@ -61,3 +61,33 @@ fn wrap_rows_into_a_table() {
         assert_eq!(actual.out, "Katz");
     })
 }
+
+#[test]
+fn wrap_values_into_a_single_column_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | wrap num
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[{"num":1},{"num":2},{"num":3}]"#);
+}
+
+#[test]
+fn wrap_values_under_the_default_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | wrap
+            | get Column1
+            | sum
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "6");
+}
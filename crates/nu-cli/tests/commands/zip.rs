@@ -0,0 +1,57 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn pairs_the_input_with_a_list() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | zip [a b c]
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[[1,"a"],[2,"b"],[3,"c"]]"#);
+}
+
+#[test]
+fn stops_at_the_shorter_side() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | zip [a b]
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[[1,"a"],[2,"b"]]"#);
+}
+
+#[test]
+fn fills_the_shorter_side_with_nothing_given_longest() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1]
+            | zip [a b] --longest
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[[1,"a"],[null,"b"]]"#);
+}
+
+#[test]
+fn pairs_the_input_with_the_output_of_a_block() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2]
+            | zip { echo [x y] }
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[[1,"x"],[2,"y"]]"#);
+}
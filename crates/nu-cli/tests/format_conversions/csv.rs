@@ -214,3 +214,41 @@ fn from_csv_text_skipping_headers_to_table() {
         assert_eq!(actual.out, "3");
     })
 }
+
+#[test]
+fn from_csv_text_with_separator_and_quote_to_table() {
+    Playground::setup("filter_from_csv_test_6", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.txt",
+            r#"
+                first_name|last_name|motto
+                Andrés|Robalino|'one|two'
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.txt
+                | from csv --separator '|' --quote "'"
+                | get motto
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "one|two");
+    })
+}
+
+#[test]
+fn from_csv_errors_given_separator_longer_than_one_character() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "a;;b"
+            | from csv --separator ';;'
+        "#
+    ));
+
+    assert!(actual.err.contains("single character"));
+}
@@ -0,0 +1,88 @@
+use nu_test_support::fs::{file_contents, Stub::FileWithContentToBeTrimmed};
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_ini() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.ini
+            | get SectionTwo.key
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "new value");
+}
+
+#[test]
+fn from_ini_errors_on_duplicate_keys() {
+    Playground::setup("from_ini_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "duplicated.ini",
+            r#"
+                [server]
+                port = 80
+                port = 8080
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open duplicated.ini | echo $it"
+        );
+
+        assert!(actual.err.contains("duplicate key 'port'"));
+    })
+}
+
+#[test]
+fn table_to_ini_and_back_into_table() {
+    Playground::setup("to_ini_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "settings.ini",
+            r#"
+                name = nu
+
+                [server]
+                host = localhost
+                port = 8080
+            "#,
+        )]);
+
+        nu!(
+            cwd: dirs.test(),
+            "open settings.ini | save saved.ini"
+        );
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open saved.ini
+                | get server.port
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "8080");
+        assert_eq!(
+            file_contents(dirs.test().join("saved.ini")),
+            "name=nu\n\n[server]\nhost=localhost\nport=8080\n"
+        );
+    })
+}
+
+#[test]
+fn to_ini_errors_on_deeply_nested_structures() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo '{"server": {"tls": {"enabled": true}}}'
+            | from json
+            | to ini
+        "#
+    ));
+
+    assert!(actual.err.contains("one level of sections"));
+}
@@ -72,6 +72,52 @@ fn from_json_text_recognizing_objects_independently_to_table() {
     })
 }
 
+#[test]
+fn from_jsonl_file_skipping_blank_lines_to_table() {
+    Playground::setup("filter_from_json_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "katz.jsonl",
+            r#"
+                {"name":   "Yehuda", "rusty_luck": 1}
+
+                {"name": "Jonathan", "rusty_luck": 2}
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open katz.jsonl
+                | get rusty_luck
+                | sum
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "3");
+    })
+}
+
+#[test]
+fn from_json_objects_reports_the_unparseable_line() {
+    Playground::setup("filter_from_json_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "katz.txt",
+            r#"
+                {"name":   "Yehuda", "rusty_luck": 1}
+                {"name": "Jonathan", "rusty_luck":
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open katz.txt | from json --objects | to json"
+        );
+
+        assert!(actual.err.contains("line 2"));
+    })
+}
+
 #[test]
 fn table_to_json_text() {
     Playground::setup("filter_to_json_test", |dirs, sandbox| {
@@ -101,3 +147,52 @@ fn table_to_json_text() {
         assert_eq!(actual.out, "JonAndrehudaTZ");
     })
 }
+
+#[test]
+fn table_to_jsonl_text_and_from_jsonl_text_back_into_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": 1}, {"b": 2}]'
+            | from json
+            | to jsonl
+            | from jsonl
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"[{"a":1},{"b":2}]"#);
+}
+
+#[test]
+fn nested_lists_to_jsonl_text_keep_one_list_per_line() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[[1,2],[3,4]]'
+            | from json
+            | to jsonl
+            | from jsonl
+            | to json
+        "#
+    ));
+
+    assert_eq!(actual.out, "[[1,2],[3,4]]");
+}
+
+#[test]
+fn table_to_jsonl_text_escapes_newlines() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '[{"a": "one\ntwo"}, {"a": "three"}]'
+            | from json
+            | to jsonl
+            | lines
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "2");
+}
@@ -3,6 +3,7 @@ mod csv;
 mod eml;
 mod html;
 mod ics;
+mod ini;
 mod json;
 mod markdown;
 mod ods;
@@ -13,4 +14,5 @@ mod tsv;
 mod url;
 mod vcf;
 mod xlsx;
+mod xml;
 mod yaml;
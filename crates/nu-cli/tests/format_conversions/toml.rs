@@ -1,3 +1,5 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
 #[test]
@@ -15,3 +17,109 @@ fn table_to_toml_text_and_from_toml_text_back_into_table() {
 
     assert_eq!(actual.out, "nu");
 }
+
+#[test]
+fn from_toml_reads_datetimes_and_arrays_of_tables() {
+    Playground::setup("from_toml_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "releases.toml",
+            r#"
+                [[release]]
+                version = "0.15.0"
+                date = 2020-05-26T17:00:00Z
+
+                [[release]]
+                version = "0.16.0"
+                date = 2020-06-16T17:00:00Z
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open releases.toml
+                | get release
+                | where version == "0.16.0"
+                | get date
+                | date format "%Y-%m-%d"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "2020-06-16");
+    })
+}
+
+#[test]
+fn table_to_toml_text_keeps_nested_tables_dates_and_key_order() {
+    Playground::setup("to_toml_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "project.toml",
+            r#"
+                name = "nu"
+                released = 2019-08-23T18:00:00Z
+
+                [owner]
+                zeta = "last"
+                alpha = "first"
+
+                [[contributors]]
+                name = "andres"
+
+                [[contributors]]
+                name = "jonathan"
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open project.toml
+                | to toml
+                | from toml
+                | get owner
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"{"zeta":"last","alpha":"first"}"#);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open project.toml
+                | to toml
+                | from toml
+                | get contributors.name
+                | to json
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["andres","jonathan"]"#);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open project.toml
+                | to toml
+                | lines
+                | where $it =~ released
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "released = 2019-08-23T18:00:00+00:00");
+    })
+}
+
+#[test]
+fn to_toml_errors_on_a_list() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3] | to toml
+        "#
+    ));
+
+    assert!(actual.err.contains("Can not convert a list to TOML"));
+}
@@ -134,3 +134,54 @@ fn from_tsv_text_skipping_headers_to_table() {
         assert_eq!(actual.out, "3");
     })
 }
+
+#[test]
+fn from_tsv_text_with_no_headers_flag_to_table() {
+    Playground::setup("filter_from_tsv_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.txt",
+            r#"
+                Andrés	Robalino	1
+                Jonathan	Turner	1
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.txt
+                | from tsv --no-headers
+                | get Column2
+                | nth 1
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "Turner");
+    })
+}
+
+#[test]
+fn opens_tsv_file_with_quoted_tabs_into_table() {
+    Playground::setup("filter_from_tsv_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.tsv",
+            r#"
+                name	motto
+                Andrés	"one	two"
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.tsv
+                | get motto
+                | str length
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual.out, "7");
+    })
+}
@@ -0,0 +1,37 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn table_to_xml_text_and_from_xml_text_back_into_table() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open jonathan.xml
+            | to xml
+            | from xml
+            | get rss.children.channel.children
+            | get item.children
+            | get link.content
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "http://www.jonathanturner.org/2015/10/off-to-new-adventures.html"
+    );
+}
+
+#[test]
+fn string_to_xml_and_back() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo "<a>b</a>"
+            | from xml
+            | to xml
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual.out, "<a>b</a>");
+}
@@ -687,6 +687,28 @@ fn parse_arg(
                         Err(e) => return (garbage(lite_arg.span), Some(e)),
                     };
 
+                    if let SyntaxShape::Math = expected_type {
+                        if let Some(condition) = math_condition(&lite_block, registry) {
+                            // A condition like {$it > 0} is a math expression rather than a pipeline
+                            let tokens: Vec<Spanned<String>> =
+                                std::iter::once(condition.name.clone())
+                                    .chain(condition.args.iter().cloned())
+                                    .collect();
+
+                            let (_, expr, err) = parse_math_expression(0, &tokens, registry, true);
+
+                            let mut commands = hir::Commands::new(lite_arg.span);
+                            commands.push(ClassifiedCommand::Expr(Box::new(expr)));
+                            let mut block = hir::Block::new(lite_arg.span);
+                            block.push(commands);
+
+                            return (
+                                SpannedExpression::new(Expression::Block(block), lite_arg.span),
+                                err,
+                            );
+                        }
+                    }
+
                     let classified_block = classify_block(&lite_block, registry);
                     let error = classified_block.failed;
 
@@ -711,6 +733,23 @@ fn parse_arg(
     }
 }
 
+/// A literal block holds a condition when it is a single command that isn't one the registry knows
+/// (`=` blocks are already parsed as expressions when the block is classified)
+fn math_condition<'a>(
+    lite_block: &'a LiteBlock,
+    registry: &dyn SignatureRegistry,
+) -> Option<&'a LiteCommand> {
+    match lite_block.block.as_slice() {
+        [pipeline] => match pipeline.commands.as_slice() {
+            [command] if command.name.item != "=" && registry.get(&command.name.item).is_none() => {
+                Some(command)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 /// Match the available flags in a signature with what the user provided. This will check both long-form flags (--full) and shorthand flags (-f)
 /// This also allows users to provide a group of shorthand flags (-af) that correspond to multiple shorthand flags at once.
 fn get_flags_from_flag(
@@ -89,7 +89,7 @@ impl Primitive {
     pub fn is_empty(&self) -> bool {
         match self {
             Primitive::Nothing => true,
-            Primitive::String(s) => s.is_empty(),
+            Primitive::String(s) | Primitive::Line(s) => s.is_empty(),
             _ => false,
         }
     }